@@ -34,7 +34,7 @@ impl ExampleContract {
         let owner: Address =
             e.storage().instance().get(&DataKey::Owner).expect("owner should be set");
         owner.require_auth();
-        Consecutive::batch_mint(e, &to, amount)
+        Consecutive::batch_mint::<Consecutive>(e, &to, amount)
     }
 }
 
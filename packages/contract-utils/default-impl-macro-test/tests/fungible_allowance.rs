@@ -0,0 +1,64 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    allowance::{EnumerableAllowances, FungibleAllowance, FungibleEnumerableAllowances},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, owner: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+        Base::mint(e, &owner, 1000);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = EnumerableAllowances;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleAllowance for ExampleContract {}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleEnumerableAllowances for ExampleContract {}
+
+fn create_client<'a>(e: &Env, owner: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (owner,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_increase_decrease_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+    let client = create_client(&e, &owner);
+
+    client.approve(&owner, &spender1, &300, &100);
+    client.increase_allowance(&owner, &spender1, &50);
+    assert_eq!(client.allowance(&owner, &spender1), 350);
+    client.decrease_allowance(&owner, &spender1, &100);
+    assert_eq!(client.allowance(&owner, &spender1), 250);
+
+    client.approve(&owner, &spender2, &100, &100);
+    assert_eq!(client.spender_count(&owner), 2);
+    let allowances = client.allowances(&owner, &0, &10);
+    assert_eq!(allowances.len(), 2);
+    assert_eq!(allowances.get_unchecked(0).0, spender1);
+    assert_eq!(allowances.get_unchecked(0).1.amount, 250);
+
+    assert_eq!(client.revoke_all(&owner, &10), 0);
+    assert_eq!(client.allowance(&owner, &spender1), 0);
+    assert_eq!(client.allowance(&owner, &spender2), 0);
+    assert_eq!(client.spender_count(&owner), 0);
+}
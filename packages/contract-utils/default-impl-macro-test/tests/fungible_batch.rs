@@ -0,0 +1,54 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{batch::FungibleBatch, Base, FungibleToken};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, owner: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+        Base::mint(e, &owner, 1000);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleBatch for ExampleContract {}
+
+fn create_client<'a>(e: &Env, owner: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (owner,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_batch() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+    let client = create_client(&e, &owner);
+
+    client.batch_transfer(&owner, &vec![&e, (recipient1.clone(), 100), (recipient2.clone(), 200)]);
+    assert_eq!(client.balance(&owner), 700);
+    assert_eq!(client.balance(&recipient1), 100);
+    assert_eq!(client.balance(&recipient2), 200);
+
+    client.approve(&owner, &spender, &300, &100);
+    client.batch_transfer_from(
+        &spender,
+        &owner,
+        &vec![&e, (recipient1.clone(), 50), (recipient2.clone(), 50)],
+    );
+    assert_eq!(client.allowance(&owner, &spender), 200);
+    assert_eq!(client.balance(&owner), 600);
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    dividend::{Dividend, FungibleDividend},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, reward_token: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+        Dividend::add_reward_token(e, &reward_token);
+    }
+
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        Dividend::mint(e, &to, amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Dividend;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleDividend for ExampleContract {}
+
+#[test]
+fn default_impl_fungible_dividend() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let reward_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    StellarAssetClient::new(&e, &reward_token).mint(&payer, &1_000);
+    let address = e.register(ExampleContract, (reward_token.clone(),));
+    let client = ExampleContractClient::new(&e, &address);
+
+    client.mint(&owner, &100);
+    client.distribute(&reward_token, &payer, &100);
+    client.transfer(&owner, &recipient, &50);
+    client.distribute(&reward_token, &payer, &100);
+
+    assert_eq!(client.reward_tokens().len(), 1);
+    assert_eq!(client.withdrawable_dividend(&reward_token, &owner), 150);
+    assert_eq!(client.withdrawable_dividend(&reward_token, &recipient), 50);
+
+    assert_eq!(client.claim(&reward_token, &owner), 150);
+    assert_eq!(client.withdrawn_dividend(&reward_token, &owner), 150);
+    assert_eq!(TokenClient::new(&e, &reward_token).balance(&owner), 150);
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{muxed::FungibleMuxed, Base, FungibleToken};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, owner: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+        Base::mint(e, &owner, 1000);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleMuxed for ExampleContract {}
+
+fn create_client<'a>(e: &Env, owner: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (owner,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_muxed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let exchange = Address::generate(&e);
+    let client = create_client(&e, &owner);
+
+    client.transfer_muxed(&owner, &exchange, &123, &400);
+    assert_eq!(client.balance(&owner), 600);
+    assert_eq!(client.balance(&exchange), 400);
+}
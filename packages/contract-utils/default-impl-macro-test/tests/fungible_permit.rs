@@ -0,0 +1,69 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    permit::{FungiblePermit, PermitSignature},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+    }
+
+    pub fn permit_digest(
+        e: &Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        live_until_ledger: u32,
+        nonce: u32,
+        deadline: u32,
+    ) -> BytesN<32> {
+        Base::permit_digest(e, &owner, &spender, amount, live_until_ledger, nonce, deadline)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungiblePermit for ExampleContract {}
+
+fn create_client<'a>(e: &Env) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, ());
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_permit() {
+    let e = Env::default();
+    let client = create_client(&e);
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let public_key = BytesN::from_array(&e, key.verifying_key().as_bytes());
+
+    e.mock_all_auths();
+    client.set_permit_key(&owner, &Some(public_key.clone()));
+    assert_eq!(client.permit_key(&owner), Some(public_key.clone()));
+    assert_eq!(client.nonces(&owner), 0);
+
+    let digest = client.permit_digest(&owner, &spender, &100, &1000, &0, &10);
+    let signature = PermitSignature {
+        public_key,
+        signature: BytesN::from_array(&e, &key.sign(&digest.to_array()).to_bytes()),
+    };
+    client.permit(&owner, &spender, &100, &1000, &10, &signature);
+
+    assert_eq!(client.allowance(&owner, &spender), 100);
+    assert_eq!(client.nonces(&owner), 1);
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    snapshot::{FungibleSnapshot, Snapshot},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+    }
+
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        Snapshot::mint(e, &to, amount);
+    }
+
+    pub fn snapshot(e: &Env) -> u32 {
+        Snapshot::snapshot(e)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Snapshot;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleSnapshot for ExampleContract {}
+
+fn create_client<'a>(e: &Env) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, ());
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_snapshot() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = create_client(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.mint(&owner, &100);
+    assert_eq!(client.snapshot(), 1);
+    assert_eq!(client.current_snapshot_id(), 1);
+
+    client.transfer(&owner, &recipient, &40);
+    assert_eq!(client.balance(&owner), 60);
+    assert_eq!(client.balance_of_at(&owner, &1), 100);
+    assert_eq!(client.balance_of_at(&recipient, &1), 0);
+    assert_eq!(client.total_supply_at(&1), 100);
+}
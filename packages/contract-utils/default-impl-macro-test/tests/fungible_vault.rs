@@ -0,0 +1,58 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    vault::{FungibleVault, Vault},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, asset: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Vault"), String::from_str(e, "VLT"));
+        Vault::set_asset(e, &asset);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleVault for ExampleContract {}
+
+fn create_client<'a>(e: &Env, asset: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (asset,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_vault() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let owner = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&owner, &1000);
+    let client = create_client(&e, &asset);
+
+    assert_eq!(client.query_asset(), asset);
+    assert_eq!(client.preview_deposit(&100), 100);
+    assert_eq!(client.deposit(&100, &owner, &owner), 100);
+    assert_eq!(client.balance(&owner), 100);
+    assert_eq!(client.total_assets(), 100);
+
+    assert_eq!(client.redeem(&40, &receiver, &owner, &owner), 40);
+    assert_eq!(client.balance(&owner), 60);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&receiver), 40);
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    votes::{FungibleVotes, Votes},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env) {
+        Base::set_metadata(e, 7, String::from_str(e, "My Token"), String::from_str(e, "TKN"));
+    }
+
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        Votes::mint(e, &to, amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Votes;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleVotes for ExampleContract {}
+
+fn create_client<'a>(e: &Env) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, ());
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_votes_delegate() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let client = create_client(&e);
+
+    e.mock_all_auths();
+    client.mint(&owner, &100);
+    client.delegate(&owner, &delegatee);
+    assert_eq!(client.delegates(&owner), Some(delegatee.clone()));
+    assert_eq!(client.get_votes(&delegatee), 100);
+}
+
+#[test]
+fn default_impl_fungible_votes_transfer_and_past_votes() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e);
+
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    client.mint(&owner, &100);
+    client.delegate(&owner, &owner);
+
+    e.ledger().set_sequence_number(20);
+    client.transfer(&owner, &recipient, &40);
+
+    e.ledger().set_sequence_number(30);
+    assert_eq!(client.get_votes(&owner), 60);
+    assert_eq!(client.get_past_votes(&owner, &15), 100);
+    assert_eq!(client.get_past_votes(&owner, &25), 60);
+    assert_eq!(client.get_past_total_supply(&25), 100);
+}
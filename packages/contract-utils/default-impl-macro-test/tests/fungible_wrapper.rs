@@ -0,0 +1,57 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_fungible::{
+    wrapper::{FungibleWrapper, Wrapper},
+    Base, FungibleToken,
+};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, underlying: Address) {
+        Base::set_metadata(e, 7, String::from_str(e, "Wrapped Token"), String::from_str(e, "WTKN"));
+        Wrapper::set_underlying(e, &underlying);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleWrapper for ExampleContract {}
+
+fn create_client<'a>(e: &Env, underlying: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (underlying,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_fungible_wrapper() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let underlying = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    StellarAssetClient::new(&e, &underlying).mint(&owner, &1000);
+    let client = create_client(&e, &underlying);
+
+    assert_eq!(client.underlying(), underlying);
+    client.deposit_for(&owner, &owner, &100);
+    assert_eq!(client.balance(&owner), 100);
+
+    client.withdraw_to(&owner, &recipient, &40);
+    assert_eq!(client.balance(&owner), 60);
+    assert_eq!(client.total_supply(), 60);
+    assert_eq!(TokenClient::new(&e, &underlying).balance(&recipient), 40);
+}
@@ -0,0 +1,68 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, String};
+use stellar_default_impl_macro::default_impl;
+use stellar_multi_token::{burnable::MultiTokenBurnable, Base, MultiToken};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env) {
+        Base::set_base_uri(e, String::from_str(e, "https://game.io/items/"));
+    }
+
+    pub fn mint(e: &Env, to: Address, id: u32, amount: i128) {
+        Base::mint(e, &to, id, amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl MultiToken for ExampleContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl MultiTokenBurnable for ExampleContract {}
+
+fn create_client<'a>(e: &Env) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, ());
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_multi_token_transfers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e);
+
+    client.mint(&owner, &1, &100);
+    client.mint(&owner, &2, &1);
+    client.transfer(&owner, &recipient, &1, &10);
+    client.approve_for_all(&owner, &operator, &1000);
+    assert!(client.is_approved_for_all(&owner, &operator));
+    client.batch_transfer_from(&operator, &owner, &recipient, &vec![&e, 1, 2], &vec![&e, 10, 1]);
+
+    let balances =
+        client.balance_of_batch(&vec![&e, owner.clone(), recipient.clone()], &vec![&e, 1, 2]);
+    assert_eq!(balances, vec![&e, 80, 1]);
+    assert_eq!(client.total_supply(&1), 100);
+    assert_eq!(client.uri(&2), String::from_str(&e, "https://game.io/items/2"));
+}
+
+#[test]
+fn default_impl_multi_token_burnable() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let client = create_client(&e);
+
+    client.mint(&owner, &1, &100);
+    client.burn(&owner, &1, &30);
+    assert_eq!(client.balance(&owner, &1), 70);
+    assert_eq!(client.total_supply(&1), 70);
+}
@@ -0,0 +1,44 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Vec,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_payment_splitter::{set_payees, PaymentSplitter};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, payees: Vec<(Address, u32)>) {
+        set_payees(e, &payees);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl PaymentSplitter for ExampleContract {}
+
+#[test]
+fn default_impl_payment_splitter() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let payees: Vec<(Address, u32)> = vec![&e, (alice.clone(), 2), (bob.clone(), 3)];
+    let address = e.register(ExampleContract, (payees,));
+    let client = ExampleContractClient::new(&e, &address);
+    let token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    StellarAssetClient::new(&e, &token).mint(&address, &1_000);
+
+    assert_eq!(client.payees(), vec![&e, alice.clone(), bob.clone()]);
+    assert_eq!(client.shares(&alice), 2);
+    assert_eq!(client.total_shares(), 5);
+    assert_eq!(client.releasable(&token, &bob), 600);
+    assert_eq!(client.release(&token, &alice), 400);
+    assert_eq!(client.released(&token, &alice), 400);
+    assert_eq!(client.total_released(&token), 400);
+    assert_eq!(TokenClient::new(&e, &token).balance(&alice), 400);
+}
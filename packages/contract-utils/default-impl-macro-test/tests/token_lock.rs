@@ -0,0 +1,37 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_token_lock::{ReleaseTime, TokenLock};
+
+#[contract]
+pub struct ExampleContract;
+
+#[default_impl]
+#[contractimpl]
+impl TokenLock for ExampleContract {}
+
+#[test]
+fn default_impl_token_lock() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let address = e.register(ExampleContract, ());
+    let client = ExampleContractClient::new(&e, &address);
+    let token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    StellarAssetClient::new(&e, &token).mint(&depositor, &1_000);
+
+    let lock_id = client.lock(&depositor, &token, &beneficiary, &400, &ReleaseTime::Ledger(50));
+    assert_eq!(client.get_lock(&lock_id).amount, 400);
+    assert_eq!(client.lock_count(&beneficiary), 1);
+    assert_eq!(client.locks_of(&beneficiary, &0, &10), vec![&e, lock_id]);
+
+    e.ledger().set_sequence_number(50);
+    assert_eq!(client.unlock(&lock_id), 400);
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 400);
+    assert_eq!(client.lock_count(&beneficiary), 0);
+}
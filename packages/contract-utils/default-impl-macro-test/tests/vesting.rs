@@ -0,0 +1,53 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_default_impl_macro::default_impl;
+use stellar_ownable::{set_owner, Ownable};
+use stellar_vesting::{set_schedule, VestingClock, VestingSchedule, VestingWallet};
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, beneficiary: Address, schedule: VestingSchedule) {
+        set_owner(e, &beneficiary);
+        set_schedule(e, &schedule);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl VestingWallet for ExampleContract {}
+
+#[default_impl]
+#[contractimpl]
+impl Ownable for ExampleContract {}
+
+fn create_client<'a>(e: &Env, beneficiary: &Address) -> ExampleContractClient<'a> {
+    let schedule =
+        VestingSchedule { clock: VestingClock::Ledger, start: 0, cliff: 0, duration: 100 };
+    let address = e.register(ExampleContract, (beneficiary, schedule));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn default_impl_vesting_wallet() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let client = create_client(&e, &beneficiary);
+    let token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    StellarAssetClient::new(&e, &token).mint(&client.address, &1_000);
+
+    e.ledger().set_sequence_number(40);
+    assert_eq!(client.vesting_schedule().duration, 100);
+    assert_eq!(client.releasable(&token), 400);
+    assert_eq!(client.release(&token), 400);
+    assert_eq!(client.released(&token), 400);
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 400);
+    assert_eq!(client.get_owner(), Some(beneficiary));
+}
@@ -113,6 +113,30 @@ fn get_default_methods(trait_name: &str) -> Vec<syn::ImplItem> {
                 }
             },
         ],
+        "FungibleAllowance" => vec![
+            syn::parse_quote! {
+                fn increase_allowance(e: &soroban_sdk::Env, owner: soroban_sdk::Address, spender: soroban_sdk::Address, amount: i128) {
+                    Self::ContractType::increase_allowance(e, &owner, &spender, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn decrease_allowance(e: &soroban_sdk::Env, owner: soroban_sdk::Address, spender: soroban_sdk::Address, amount: i128) {
+                    Self::ContractType::decrease_allowance(e, &owner, &spender, amount);
+                }
+            },
+        ],
+        "FungibleBatch" => vec![
+            syn::parse_quote! {
+                fn batch_transfer(e: &soroban_sdk::Env, from: soroban_sdk::Address, recipients: soroban_sdk::Vec<(soroban_sdk::Address, i128)>) {
+                    stellar_fungible::batch::Batch::batch_transfer::<Self::ContractType>(e, &from, &recipients);
+                }
+            },
+            syn::parse_quote! {
+                fn batch_transfer_from(e: &soroban_sdk::Env, spender: soroban_sdk::Address, from: soroban_sdk::Address, recipients: soroban_sdk::Vec<(soroban_sdk::Address, i128)>) {
+                    stellar_fungible::batch::Batch::batch_transfer_from::<Self::ContractType>(e, &spender, &from, &recipients);
+                }
+            },
+        ],
         "FungibleBurnable" => vec![
             syn::parse_quote! {
                 fn burn(e: &soroban_sdk::Env, from: soroban_sdk::Address, amount: i128) {
@@ -125,6 +149,294 @@ fn get_default_methods(trait_name: &str) -> Vec<syn::ImplItem> {
                 }
             },
         ],
+        "FungibleDividend" => vec![
+            syn::parse_quote! {
+                fn reward_tokens(e: &soroban_sdk::Env) -> soroban_sdk::Vec<soroban_sdk::Address> {
+                    stellar_fungible::dividend::Dividend::reward_tokens(e)
+                }
+            },
+            syn::parse_quote! {
+                fn withdrawable_dividend(e: &soroban_sdk::Env, reward_token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::dividend::Dividend::withdrawable_dividend(e, &reward_token, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn withdrawn_dividend(e: &soroban_sdk::Env, reward_token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::dividend::Dividend::withdrawn_dividend(e, &reward_token, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn distribute(e: &soroban_sdk::Env, reward_token: soroban_sdk::Address, from: soroban_sdk::Address, amount: i128) {
+                    stellar_fungible::dividend::Dividend::distribute(e, &reward_token, &from, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn claim(e: &soroban_sdk::Env, reward_token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::dividend::Dividend::claim(e, &reward_token, &account)
+                }
+            },
+        ],
+        "FungibleEnumerableAllowances" => vec![
+            syn::parse_quote! {
+                fn spender_count(e: &soroban_sdk::Env, owner: soroban_sdk::Address) -> u32 {
+                    stellar_fungible::allowance::EnumerableAllowances::spender_count(e, &owner)
+                }
+            },
+            syn::parse_quote! {
+                fn allowances(e: &soroban_sdk::Env, owner: soroban_sdk::Address, start: u32, limit: u32) -> soroban_sdk::Vec<(soroban_sdk::Address, stellar_fungible::AllowanceData)> {
+                    stellar_fungible::allowance::EnumerableAllowances::allowances(e, &owner, start, limit)
+                }
+            },
+            syn::parse_quote! {
+                fn revoke_all(e: &soroban_sdk::Env, owner: soroban_sdk::Address, limit: u32) -> u32 {
+                    stellar_fungible::allowance::EnumerableAllowances::revoke_all(e, &owner, limit)
+                }
+            },
+        ],
+        "FungibleMuxed" => vec![syn::parse_quote! {
+            fn transfer_muxed(e: &soroban_sdk::Env, from: soroban_sdk::Address, to: soroban_sdk::Address, to_muxed_id: u64, amount: i128) {
+                Self::ContractType::transfer_muxed(e, &from, &to, to_muxed_id, amount);
+            }
+        }],
+        "FungiblePermit" => vec![
+            syn::parse_quote! {
+                fn nonces(e: &soroban_sdk::Env, owner: soroban_sdk::Address) -> u32 {
+                    stellar_fungible::Base::nonces(e, &owner)
+                }
+            },
+            syn::parse_quote! {
+                fn permit_key(e: &soroban_sdk::Env, owner: soroban_sdk::Address) -> Option<soroban_sdk::BytesN<32>> {
+                    stellar_fungible::Base::permit_key(e, &owner)
+                }
+            },
+            syn::parse_quote! {
+                fn set_permit_key(e: &soroban_sdk::Env, owner: soroban_sdk::Address, public_key: Option<soroban_sdk::BytesN<32>>) {
+                    stellar_fungible::Base::set_permit_key(e, &owner, &public_key);
+                }
+            },
+            syn::parse_quote! {
+                fn permit(e: &soroban_sdk::Env, owner: soroban_sdk::Address, spender: soroban_sdk::Address, amount: i128, live_until_ledger: u32, deadline: u32, signature: stellar_fungible::permit::PermitSignature) {
+                    stellar_fungible::Base::permit::<Self::ContractType>(e, &owner, &spender, amount, live_until_ledger, deadline, &signature);
+                }
+            },
+        ],
+        "FungibleSnapshot" => vec![
+            syn::parse_quote! {
+                fn current_snapshot_id(e: &soroban_sdk::Env) -> u32 {
+                    stellar_fungible::snapshot::Snapshot::current_snapshot_id(e)
+                }
+            },
+            syn::parse_quote! {
+                fn balance_of_at(e: &soroban_sdk::Env, account: soroban_sdk::Address, snapshot_id: u32) -> i128 {
+                    stellar_fungible::snapshot::Snapshot::balance_of_at(e, &account, snapshot_id)
+                }
+            },
+            syn::parse_quote! {
+                fn total_supply_at(e: &soroban_sdk::Env, snapshot_id: u32) -> i128 {
+                    stellar_fungible::snapshot::Snapshot::total_supply_at(e, snapshot_id)
+                }
+            },
+        ],
+        "FungibleVault" => vec![
+            syn::parse_quote! {
+                fn query_asset(e: &soroban_sdk::Env) -> soroban_sdk::Address {
+                    stellar_fungible::vault::Vault::query_asset(e)
+                }
+            },
+            syn::parse_quote! {
+                fn total_assets(e: &soroban_sdk::Env) -> i128 {
+                    stellar_fungible::vault::Vault::total_assets(e)
+                }
+            },
+            syn::parse_quote! {
+                fn convert_to_shares(e: &soroban_sdk::Env, assets: i128) -> i128 {
+                    stellar_fungible::vault::Vault::convert_to_shares(e, assets)
+                }
+            },
+            syn::parse_quote! {
+                fn convert_to_assets(e: &soroban_sdk::Env, shares: i128) -> i128 {
+                    stellar_fungible::vault::Vault::convert_to_assets(e, shares)
+                }
+            },
+            syn::parse_quote! {
+                fn max_deposit(e: &soroban_sdk::Env, receiver: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::max_deposit(e, &receiver)
+                }
+            },
+            syn::parse_quote! {
+                fn preview_deposit(e: &soroban_sdk::Env, assets: i128) -> i128 {
+                    stellar_fungible::vault::Vault::preview_deposit(e, assets)
+                }
+            },
+            syn::parse_quote! {
+                fn deposit(e: &soroban_sdk::Env, assets: i128, receiver: soroban_sdk::Address, from: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::deposit::<Self::ContractType>(e, assets, &receiver, &from)
+                }
+            },
+            syn::parse_quote! {
+                fn max_mint(e: &soroban_sdk::Env, receiver: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::max_mint(e, &receiver)
+                }
+            },
+            syn::parse_quote! {
+                fn preview_mint(e: &soroban_sdk::Env, shares: i128) -> i128 {
+                    stellar_fungible::vault::Vault::preview_mint(e, shares)
+                }
+            },
+            syn::parse_quote! {
+                fn mint(e: &soroban_sdk::Env, shares: i128, receiver: soroban_sdk::Address, from: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::mint::<Self::ContractType>(e, shares, &receiver, &from)
+                }
+            },
+            syn::parse_quote! {
+                fn max_withdraw(e: &soroban_sdk::Env, owner: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::max_withdraw(e, &owner)
+                }
+            },
+            syn::parse_quote! {
+                fn preview_withdraw(e: &soroban_sdk::Env, assets: i128) -> i128 {
+                    stellar_fungible::vault::Vault::preview_withdraw(e, assets)
+                }
+            },
+            syn::parse_quote! {
+                fn withdraw(e: &soroban_sdk::Env, assets: i128, receiver: soroban_sdk::Address, owner: soroban_sdk::Address, operator: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::withdraw::<Self::ContractType>(e, assets, &receiver, &owner, &operator)
+                }
+            },
+            syn::parse_quote! {
+                fn max_redeem(e: &soroban_sdk::Env, owner: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::max_redeem(e, &owner)
+                }
+            },
+            syn::parse_quote! {
+                fn preview_redeem(e: &soroban_sdk::Env, shares: i128) -> i128 {
+                    stellar_fungible::vault::Vault::preview_redeem(e, shares)
+                }
+            },
+            syn::parse_quote! {
+                fn redeem(e: &soroban_sdk::Env, shares: i128, receiver: soroban_sdk::Address, owner: soroban_sdk::Address, operator: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::vault::Vault::redeem::<Self::ContractType>(e, shares, &receiver, &owner, &operator)
+                }
+            },
+        ],
+        "FungibleVotes" => vec![
+            syn::parse_quote! {
+                fn get_votes(e: &soroban_sdk::Env, account: soroban_sdk::Address) -> i128 {
+                    stellar_fungible::votes::Votes::get_votes(e, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn get_past_votes(e: &soroban_sdk::Env, account: soroban_sdk::Address, ledger: u32) -> i128 {
+                    stellar_fungible::votes::Votes::get_past_votes(e, &account, ledger)
+                }
+            },
+            syn::parse_quote! {
+                fn get_past_total_supply(e: &soroban_sdk::Env, ledger: u32) -> i128 {
+                    stellar_fungible::votes::Votes::get_past_total_supply(e, ledger)
+                }
+            },
+            syn::parse_quote! {
+                fn delegates(e: &soroban_sdk::Env, account: soroban_sdk::Address) -> Option<soroban_sdk::Address> {
+                    stellar_fungible::votes::Votes::delegates(e, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn delegate(e: &soroban_sdk::Env, account: soroban_sdk::Address, delegatee: soroban_sdk::Address) {
+                    stellar_fungible::votes::Votes::delegate(e, &account, &delegatee);
+                }
+            },
+        ],
+        "FungibleWrapper" => vec![
+            syn::parse_quote! {
+                fn underlying(e: &soroban_sdk::Env) -> soroban_sdk::Address {
+                    stellar_fungible::wrapper::Wrapper::underlying(e)
+                }
+            },
+            syn::parse_quote! {
+                fn deposit_for(e: &soroban_sdk::Env, from: soroban_sdk::Address, account: soroban_sdk::Address, amount: i128) {
+                    stellar_fungible::wrapper::Wrapper::deposit_for::<Self::ContractType>(e, &from, &account, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn withdraw_to(e: &soroban_sdk::Env, from: soroban_sdk::Address, account: soroban_sdk::Address, amount: i128) {
+                    stellar_fungible::wrapper::Wrapper::withdraw_to::<Self::ContractType>(e, &from, &account, amount);
+                }
+            },
+        ],
+        "MultiToken" => vec![
+            syn::parse_quote! {
+                fn balance(e: &soroban_sdk::Env, account: soroban_sdk::Address, id: u32) -> i128 {
+                    Self::ContractType::balance(e, &account, id)
+                }
+            },
+            syn::parse_quote! {
+                fn balance_of_batch(e: &soroban_sdk::Env, accounts: soroban_sdk::Vec<soroban_sdk::Address>, ids: soroban_sdk::Vec<u32>) -> soroban_sdk::Vec<i128> {
+                    Self::ContractType::balance_of_batch(e, &accounts, &ids)
+                }
+            },
+            syn::parse_quote! {
+                fn total_supply(e: &soroban_sdk::Env, id: u32) -> i128 {
+                    Self::ContractType::total_supply(e, id)
+                }
+            },
+            syn::parse_quote! {
+                fn transfer(e: &soroban_sdk::Env, from: soroban_sdk::Address, to: soroban_sdk::Address, id: u32, amount: i128) {
+                    Self::ContractType::transfer(e, &from, &to, id, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn transfer_from(e: &soroban_sdk::Env, spender: soroban_sdk::Address, from: soroban_sdk::Address, to: soroban_sdk::Address, id: u32, amount: i128) {
+                    Self::ContractType::transfer_from(e, &spender, &from, &to, id, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn batch_transfer(e: &soroban_sdk::Env, from: soroban_sdk::Address, to: soroban_sdk::Address, ids: soroban_sdk::Vec<u32>, amounts: soroban_sdk::Vec<i128>) {
+                    Self::ContractType::batch_transfer(e, &from, &to, &ids, &amounts);
+                }
+            },
+            syn::parse_quote! {
+                fn batch_transfer_from(e: &soroban_sdk::Env, spender: soroban_sdk::Address, from: soroban_sdk::Address, to: soroban_sdk::Address, ids: soroban_sdk::Vec<u32>, amounts: soroban_sdk::Vec<i128>) {
+                    Self::ContractType::batch_transfer_from(e, &spender, &from, &to, &ids, &amounts);
+                }
+            },
+            syn::parse_quote! {
+                fn approve_for_all(e: &soroban_sdk::Env, owner: soroban_sdk::Address, operator: soroban_sdk::Address, live_until_ledger: u32) {
+                    Self::ContractType::approve_for_all(e, &owner, &operator, live_until_ledger);
+                }
+            },
+            syn::parse_quote! {
+                fn is_approved_for_all(e: &soroban_sdk::Env, owner: soroban_sdk::Address, operator: soroban_sdk::Address) -> bool {
+                    Self::ContractType::is_approved_for_all(e, &owner, &operator)
+                }
+            },
+            syn::parse_quote! {
+                fn uri(e: &soroban_sdk::Env, id: u32) -> soroban_sdk::String {
+                    Self::ContractType::uri(e, id)
+                }
+            },
+        ],
+        "MultiTokenBurnable" => vec![
+            syn::parse_quote! {
+                fn burn(e: &soroban_sdk::Env, from: soroban_sdk::Address, id: u32, amount: i128) {
+                    Self::ContractType::burn(e, &from, id, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn burn_from(e: &soroban_sdk::Env, spender: soroban_sdk::Address, from: soroban_sdk::Address, id: u32, amount: i128) {
+                    Self::ContractType::burn_from(e, &spender, &from, id, amount);
+                }
+            },
+            syn::parse_quote! {
+                fn burn_batch(e: &soroban_sdk::Env, from: soroban_sdk::Address, ids: soroban_sdk::Vec<u32>, amounts: soroban_sdk::Vec<i128>) {
+                    Self::ContractType::burn_batch(e, &from, &ids, &amounts);
+                }
+            },
+            syn::parse_quote! {
+                fn burn_batch_from(e: &soroban_sdk::Env, spender: soroban_sdk::Address, from: soroban_sdk::Address, ids: soroban_sdk::Vec<u32>, amounts: soroban_sdk::Vec<i128>) {
+                    Self::ContractType::burn_batch_from(e, &spender, &from, &ids, &amounts);
+                }
+            },
+        ],
         "NonFungibleToken" => vec![
             syn::parse_quote! {
                 fn balance(e: &soroban_sdk::Env, account: soroban_sdk::Address) -> u32 {
@@ -233,6 +545,97 @@ fn get_default_methods(trait_name: &str) -> Vec<syn::ImplItem> {
                 }
             },
         ],
+        "VestingWallet" => vec![
+            syn::parse_quote! {
+                fn vesting_schedule(e: &soroban_sdk::Env) -> stellar_vesting::VestingSchedule {
+                    stellar_vesting::vesting_schedule(e)
+                }
+            },
+            syn::parse_quote! {
+                fn released(e: &soroban_sdk::Env, token: soroban_sdk::Address) -> i128 {
+                    stellar_vesting::released(e, &token)
+                }
+            },
+            syn::parse_quote! {
+                fn releasable(e: &soroban_sdk::Env, token: soroban_sdk::Address) -> i128 {
+                    stellar_vesting::releasable(e, &token)
+                }
+            },
+            syn::parse_quote! {
+                fn release(e: &soroban_sdk::Env, token: soroban_sdk::Address) -> i128 {
+                    stellar_vesting::release(e, &token)
+                }
+            },
+        ],
+        "PaymentSplitter" => vec![
+            syn::parse_quote! {
+                fn payees(e: &soroban_sdk::Env) -> soroban_sdk::Vec<soroban_sdk::Address> {
+                    stellar_payment_splitter::payees(e)
+                }
+            },
+            syn::parse_quote! {
+                fn shares(e: &soroban_sdk::Env, account: soroban_sdk::Address) -> u32 {
+                    stellar_payment_splitter::shares(e, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn total_shares(e: &soroban_sdk::Env) -> u32 {
+                    stellar_payment_splitter::total_shares(e)
+                }
+            },
+            syn::parse_quote! {
+                fn total_released(e: &soroban_sdk::Env, token: soroban_sdk::Address) -> i128 {
+                    stellar_payment_splitter::total_released(e, &token)
+                }
+            },
+            syn::parse_quote! {
+                fn released(e: &soroban_sdk::Env, token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_payment_splitter::released(e, &token, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn releasable(e: &soroban_sdk::Env, token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_payment_splitter::releasable(e, &token, &account)
+                }
+            },
+            syn::parse_quote! {
+                fn release(e: &soroban_sdk::Env, token: soroban_sdk::Address, account: soroban_sdk::Address) -> i128 {
+                    stellar_payment_splitter::release(e, &token, &account)
+                }
+            },
+        ],
+        "TokenLock" => vec![
+            syn::parse_quote! {
+                fn get_lock(e: &soroban_sdk::Env, lock_id: u32) -> stellar_token_lock::TokenLockData {
+                    stellar_token_lock::get_lock(e, lock_id)
+                }
+            },
+            syn::parse_quote! {
+                fn lock_count(e: &soroban_sdk::Env, beneficiary: soroban_sdk::Address) -> u32 {
+                    stellar_token_lock::lock_count(e, &beneficiary)
+                }
+            },
+            syn::parse_quote! {
+                fn locks_of(e: &soroban_sdk::Env, beneficiary: soroban_sdk::Address, start: u32, limit: u32) -> soroban_sdk::Vec<u32> {
+                    stellar_token_lock::locks_of(e, &beneficiary, start, limit)
+                }
+            },
+            syn::parse_quote! {
+                fn lock(e: &soroban_sdk::Env, depositor: soroban_sdk::Address, token: soroban_sdk::Address, beneficiary: soroban_sdk::Address, amount: i128, release: stellar_token_lock::ReleaseTime) -> u32 {
+                    stellar_token_lock::lock(e, &depositor, &token, &beneficiary, amount, release)
+                }
+            },
+            syn::parse_quote! {
+                fn unlock(e: &soroban_sdk::Env, lock_id: u32) -> i128 {
+                    stellar_token_lock::unlock(e, lock_id)
+                }
+            },
+            syn::parse_quote! {
+                fn early_release(e: &soroban_sdk::Env, caller: soroban_sdk::Address, lock_id: u32) -> i128 {
+                    stellar_token_lock::early_release(e, &caller, lock_id)
+                }
+            },
+        ],
 
         not_supported => {
             panic!("Trait {not_supported} is not supported by #[default_impl]")
@@ -275,8 +678,8 @@ pub fn generate_default_impl(item: TokenStream) -> TokenStream {
     // `existing_items` now contains the merged items
     let new_impl = ItemImpl { items: existing_items, ..input };
 
-    // Import the necessary trait if the trait is `NonFungibleToken` or
-    // `FungibleToken`
+    // Import the necessary trait if the trait is `NonFungibleToken`,
+    // `FungibleToken` or `MultiToken`
     let expanded = if trait_name == "NonFungibleToken" {
         quote! {
             use stellar_non_fungible::ContractOverrides;
@@ -287,6 +690,11 @@ pub fn generate_default_impl(item: TokenStream) -> TokenStream {
             use stellar_fungible::ContractOverrides;
             #new_impl
         }
+    } else if trait_name == "MultiToken" {
+        quote! {
+            use stellar_multi_token::ContractOverrides;
+            #new_impl
+        }
     } else {
         quote! { #new_impl }
     };
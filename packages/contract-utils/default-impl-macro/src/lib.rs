@@ -43,18 +43,39 @@ mod helper;
 ///
 /// This macro works for the following traits:
 /// - `FungibleToken`
+/// - `FungibleAllowance`
+/// - `FungibleBatch`
 /// - `FungibleBurnable`
+/// - `FungibleDividend`
+/// - `FungibleEnumerableAllowances`
+/// - `FungibleMuxed`
+/// - `FungiblePermit`
+/// - `FungibleSnapshot`
+/// - `FungibleVault`
+/// - `FungibleVotes`
+/// - `FungibleWrapper`
+/// - `MultiToken`
+/// - `MultiTokenBurnable`
 /// - `NonFungibleToken`
 /// - `NonFungibleBurnable`
 /// - `NonFungibleEnumerable`
 /// - `AccessControl`
 /// - `Ownable`
+/// - `VestingWallet`
+/// - `TokenLock`
+/// - `PaymentSplitter`
 ///
 /// # Notes
 ///
 /// This macro does not support the below traits on purpose:
 /// - `FungibleAllowList`
 /// - `FungibleBlockList`
+/// - `FungibleClawback`
+/// - `FungibleFee`
+/// - `FungibleFreezable`
+/// - `FungibleMaxBalance`
+/// - `FungibleMintable`
+/// - `FungiblePartition`
 /// - `NonFungibleRoyalties`
 ///
 /// Because, there are no default implementation to enforce how the
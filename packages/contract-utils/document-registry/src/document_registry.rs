@@ -0,0 +1,143 @@
+use soroban_sdk::{contracterror, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::Document;
+
+pub trait DocumentRegistry {
+    /// Returns the document registered under `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `name` - The name of the document.
+    ///
+    /// # Errors
+    ///
+    /// * [`DocumentRegistryError::DocumentNotFound`] - When no document is
+    ///   registered under `name`.
+    fn document(e: &Env, name: Symbol) -> Document {
+        crate::document(e, &name)
+    }
+
+    /// Returns the names of all the registered documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    fn document_names(e: &Env) -> Vec<Symbol> {
+        crate::document_names(e)
+    }
+
+    /// Registers a document under `name`, replacing the document registered
+    /// under the same name, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `name` - The name of the document.
+    /// * `uri` - The URI where the document can be retrieved.
+    /// * `document_hash` - The hash of the content of the document.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["document_updated", name: Symbol]`
+    /// * data - `[uri: String, document_hash: BytesN<32>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`crate::set_document()`] when implementing this
+    /// function.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The base implementation of [`crate::set_document()`]
+    /// intentionally lacks authorization controls. If you want to restrict
+    /// who can set documents, you MUST implement proper authorization in your
+    /// contract.
+    fn set_document(
+        e: &Env,
+        name: Symbol,
+        uri: String,
+        document_hash: BytesN<32>,
+        operator: Address,
+    );
+
+    /// Removes the document registered under `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `name` - The name of the document.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`DocumentRegistryError::DocumentNotFound`] - When no document is
+    ///   registered under `name`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["document_removed", name: Symbol]`
+    /// * data - `[uri: String, document_hash: BytesN<32>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`crate::remove_document()`] when implementing this
+    /// function.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The base implementation of [`crate::remove_document()`]
+    /// intentionally lacks authorization controls. If you want to restrict
+    /// who can remove documents, you MUST implement proper authorization in
+    /// your contract.
+    fn remove_document(e: &Env, name: Symbol, operator: Address);
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DocumentRegistryError {
+    /// No document is registered under the name.
+    DocumentNotFound = 1900,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a document is registered or updated.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `name` - The name of the document.
+/// * `uri` - The URI where the document can be retrieved.
+/// * `document_hash` - The hash of the content of the document.
+///
+/// # Events
+///
+/// * topics - `["document_updated", name: Symbol]`
+/// * data - `[uri: String, document_hash: BytesN<32>]`
+pub fn emit_document_updated(e: &Env, name: &Symbol, uri: &String, document_hash: &BytesN<32>) {
+    let topics = (Symbol::new(e, "document_updated"), name.clone());
+    e.events().publish(topics, (uri.clone(), document_hash.clone()))
+}
+
+/// Emits an event when a document is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `name` - The name of the document.
+/// * `uri` - The URI of the removed document.
+/// * `document_hash` - The hash of the content of the removed document.
+///
+/// # Events
+///
+/// * topics - `["document_removed", name: Symbol]`
+/// * data - `[uri: String, document_hash: BytesN<32>]`
+pub fn emit_document_removed(e: &Env, name: &Symbol, uri: &String, document_hash: &BytesN<32>) {
+    let topics = (Symbol::new(e, "document_removed"), name.clone());
+    e.events().publish(topics, (uri.clone(), document_hash.clone()))
+}
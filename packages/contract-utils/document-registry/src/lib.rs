@@ -0,0 +1,64 @@
+//! # Document Registry
+//!
+//! This module lets a contract reference documents, e.g. the prospectus or the
+//! terms and conditions of a regulated token, similar to ERC-1643.
+//!
+//! Every document is registered under a unique name, and consists of the URI
+//! where it can be retrieved and of the hash of its content, so that anyone
+//! can verify that the retrieved document is the one referenced by the
+//! contract.
+//!
+//! ## Implementation Notes
+//!
+//! - **Token agnostic**: The registry does not depend on any token standard, so
+//!   it can be used next to `FungibleToken`, `NonFungibleToken` or any other
+//!   contract.
+//! - **Updates**: Setting a document under an existing name replaces it, and
+//!   records the ledger timestamp of the last update.
+//! - **Enumeration**: The names of the registered documents can be enumerated
+//!   with [`document_names()`].
+//!
+//! By implementing the trait [`DocumentRegistry`] for your contract, the
+//! queries come with default implementations, while the setters take an
+//! additional `operator: Address` parameter, so that they can be guarded by
+//! the access control helpers. We expect you to utilize the functions below
+//! (`storage::*`) for implementing them.
+//!
+//! ```ignore,rust
+//! use stellar_access_control_macros::only_role;
+//!
+//! #[contractimpl]
+//! impl DocumentRegistry for MyToken {
+//!     #[only_role(operator, "document_manager")]
+//!     fn set_document(
+//!         e: &Env,
+//!         name: Symbol,
+//!         uri: String,
+//!         document_hash: BytesN<32>,
+//!         operator: Address,
+//!     ) {
+//!         stellar_document_registry::set_document(e, &name, &uri, &document_hash);
+//!     }
+//!
+//!     #[only_role(operator, "document_manager")]
+//!     fn remove_document(e: &Env, name: Symbol, operator: Address) {
+//!         stellar_document_registry::remove_document(e, &name);
+//!     }
+//! }
+//! ```
+
+#![no_std]
+
+mod document_registry;
+mod storage;
+mod test;
+
+pub use crate::{
+    document_registry::{
+        emit_document_removed, emit_document_updated, DocumentRegistry, DocumentRegistryError,
+    },
+    storage::{
+        document, document_names, remove_document, set_document, Document,
+        DocumentRegistryStorageKey,
+    },
+};
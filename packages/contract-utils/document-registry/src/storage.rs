@@ -0,0 +1,145 @@
+use soroban_sdk::{contracttype, panic_with_error, BytesN, Env, String, Symbol, Vec};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{emit_document_removed, emit_document_updated, DocumentRegistryError};
+
+/// Storage container for a document.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Document {
+    /// The URI where the document can be retrieved.
+    pub uri: String,
+    /// The hash of the content of the document.
+    pub document_hash: BytesN<32>,
+    /// The ledger timestamp of the last update of the document.
+    pub timestamp: u64,
+}
+
+/// Storage keys for the data associated with the document registry
+#[contracttype]
+pub enum DocumentRegistryStorageKey {
+    /// Stores the document registered under a name
+    Document(Symbol),
+    /// Stores the names of the registered documents
+    Names,
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the document registered under `name`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `name` - The name of the document.
+///
+/// # Errors
+///
+/// * [`DocumentRegistryError::DocumentNotFound`] - When no document is
+///   registered under `name`.
+pub fn document(e: &Env, name: &Symbol) -> Document {
+    let key = DocumentRegistryStorageKey::Document(name.clone());
+    let Some(document) = e.storage().persistent().get::<_, Document>(&key) else {
+        panic_with_error!(e, DocumentRegistryError::DocumentNotFound);
+    };
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+    document
+}
+
+/// Returns the names of all the registered documents, in the order in which
+/// they were first registered.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn document_names(e: &Env) -> Vec<Symbol> {
+    let key = DocumentRegistryStorageKey::Names;
+    if let Some(names) = e.storage().persistent().get::<_, Vec<Symbol>>(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        names
+    } else {
+        Vec::new(e)
+    }
+}
+
+// ################## CHANGE STATE ##################
+
+/// Registers a document under `name`, replacing the document registered under
+/// the same name, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `name` - The name of the document.
+/// * `uri` - The URI where the document can be retrieved.
+/// * `document_hash` - The hash of the content of the document.
+///
+/// # Events
+///
+/// * topics - `["document_updated", name: Symbol]`
+/// * data - `[uri: String, document_hash: BytesN<32>]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization checks and should only
+/// be used in admin functions that implement their own authorization logic.
+pub fn set_document(e: &Env, name: &Symbol, uri: &String, document_hash: &BytesN<32>) {
+    let key = DocumentRegistryStorageKey::Document(name.clone());
+    if !e.storage().persistent().has(&key) {
+        let mut names = document_names(e);
+        names.push_back(name.clone());
+        e.storage().persistent().set(&DocumentRegistryStorageKey::Names, &names);
+    }
+
+    let document = Document {
+        uri: uri.clone(),
+        document_hash: document_hash.clone(),
+        timestamp: e.ledger().timestamp(),
+    };
+    e.storage().persistent().set(&key, &document);
+
+    emit_document_updated(e, name, uri, document_hash);
+}
+
+/// Removes the document registered under `name`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `name` - The name of the document.
+///
+/// # Errors
+///
+/// * [`DocumentRegistryError::DocumentNotFound`] - When no document is
+///   registered under `name`.
+///
+/// # Events
+///
+/// * topics - `["document_removed", name: Symbol]`
+/// * data - `[uri: String, document_hash: BytesN<32>]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization checks and should only
+/// be used in admin functions that implement their own authorization logic.
+pub fn remove_document(e: &Env, name: &Symbol) {
+    let removed = document(e, name);
+    e.storage().persistent().remove(&DocumentRegistryStorageKey::Document(name.clone()));
+
+    let mut names = document_names(e);
+    if let Some(index) = names.first_index_of(name) {
+        names.remove(index);
+    }
+    let key = DocumentRegistryStorageKey::Names;
+    if names.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &names);
+    }
+
+    emit_document_removed(e, name, &removed.uri, &removed.document_hash);
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, symbol_short,
+    testutils::{Events, Ledger},
+    vec, BytesN, Env, IntoVal, String, Symbol,
+};
+
+use crate::{document, document_names, remove_document, set_document};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn set_document_works() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+    let address = e.register(MockContract, ());
+    let name = symbol_short!("terms");
+    let uri = String::from_str(&e, "ipfs://terms");
+    let document_hash = BytesN::from_array(&e, &[1; 32]);
+
+    e.as_contract(&address, || {
+        set_document(&e, &name, &uri, &document_hash);
+
+        let stored = document(&e, &name);
+        assert_eq!(stored.uri, uri);
+        assert_eq!(stored.document_hash, document_hash);
+        assert_eq!(stored.timestamp, 1000);
+        assert_eq!(document_names(&e), vec![&e, name.clone()]);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    (Symbol::new(&e, "document_updated"), name.clone()).into_val(&e),
+                    (uri.clone(), document_hash.clone()).into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn update_document_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let terms = symbol_short!("terms");
+    let prospectus = symbol_short!("prospect");
+
+    e.as_contract(&address, || {
+        set_document(
+            &e,
+            &terms,
+            &String::from_str(&e, "ipfs://terms-v1"),
+            &BytesN::from_array(&e, &[1; 32]),
+        );
+        set_document(
+            &e,
+            &prospectus,
+            &String::from_str(&e, "ipfs://prospectus"),
+            &BytesN::from_array(&e, &[2; 32]),
+        );
+
+        e.ledger().set_timestamp(2000);
+        set_document(
+            &e,
+            &terms,
+            &String::from_str(&e, "ipfs://terms-v2"),
+            &BytesN::from_array(&e, &[3; 32]),
+        );
+
+        let stored = document(&e, &terms);
+        assert_eq!(stored.uri, String::from_str(&e, "ipfs://terms-v2"));
+        assert_eq!(stored.document_hash, BytesN::from_array(&e, &[3; 32]));
+        assert_eq!(stored.timestamp, 2000);
+        // the name is listed once
+        assert_eq!(document_names(&e), vec![&e, terms.clone(), prospectus.clone()]);
+    });
+}
+
+#[test]
+fn remove_document_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let terms = symbol_short!("terms");
+    let prospectus = symbol_short!("prospect");
+    let uri = String::from_str(&e, "ipfs://terms");
+    let document_hash = BytesN::from_array(&e, &[1; 32]);
+
+    e.as_contract(&address, || {
+        set_document(&e, &terms, &uri, &document_hash);
+        set_document(
+            &e,
+            &prospectus,
+            &String::from_str(&e, "ipfs://prospectus"),
+            &BytesN::from_array(&e, &[2; 32]),
+        );
+    });
+
+    e.as_contract(&address, || {
+        remove_document(&e, &terms);
+        assert_eq!(document_names(&e), vec![&e, prospectus.clone()]);
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    (Symbol::new(&e, "document_removed"), terms.clone()).into_val(&e),
+                    (uri.clone(), document_hash.clone()).into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1900)")]
+fn removed_document_cannot_be_queried() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let name = symbol_short!("terms");
+
+    e.as_contract(&address, || {
+        set_document(
+            &e,
+            &name,
+            &String::from_str(&e, "ipfs://terms"),
+            &BytesN::from_array(&e, &[1; 32]),
+        );
+        remove_document(&e, &name);
+        assert_eq!(document_names(&e).len(), 0);
+        document(&e, &name);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1900)")]
+fn remove_unknown_document_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        remove_document(&e, &symbol_short!("terms"));
+    });
+}
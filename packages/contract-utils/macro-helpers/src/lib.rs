@@ -62,6 +62,7 @@ fn check_is_env(path: &TypePath, fn_name: &Ident) {
 }
 
 /// Find the first parameter of type Address or &Address
+#[allow(clippy::collapsible_match)]
 pub fn find_address_param(func: &ItemFn) -> Option<(proc_macro2::TokenStream, bool)> {
     for arg in &func.sig.inputs {
         if let FnArg::Typed(PatType { pat, ty, .. }) = arg {
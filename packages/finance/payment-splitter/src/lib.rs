@@ -0,0 +1,48 @@
+//! # Payment Splitter Contract Module.
+//!
+//! This module implements a payment splitter: a contract that receives SEP-41
+//! tokens (including `FungibleToken` contracts and Stellar Asset Contracts)
+//! and releases them to a fixed set of payees, pro-rata to their shares, e.g.
+//! to split the royalties of an NFT collection among its creators.
+//!
+//! The `PaymentSplitter` trait exposes methods for:
+//! - Getting the payees and their shares
+//! - Getting the amount of a token already released, in total and per payee
+//! - Getting the amount of a token releasable to a payee
+//! - Releasing the tokens owed to a payee
+//!
+//! ## Implementation Notes
+//!
+//! - **Payees**: The payees and their shares are set once, usually in the
+//!   constructor, with [`set_payees`], and cannot be changed afterwards.
+//! - **Pull payments**: The tokens are not pushed to the payees when they are
+//!   received. Every payee gets, for every token, its share of all the tokens
+//!   ever received by the contract, i.e. its balance and the released tokens,
+//!   minus what was already released to it.
+//! - **Releasing**: Releasing does not require any authorization, as the tokens
+//!   are always sent to the payee.
+//!
+//! ```ignore
+//! #[contractimpl]
+//! impl ExampleContract {
+//!     pub fn __constructor(e: &Env, payees: Vec<(Address, u32)>) {
+//!         set_payees(e, &payees);
+//!     }
+//! }
+//! ```
+#![no_std]
+
+mod payment_splitter;
+mod storage;
+
+pub use crate::{
+    payment_splitter::{
+        emit_payee_added, emit_payment_released, PaymentSplitter, PaymentSplitterError,
+    },
+    storage::{
+        payees, releasable, release, released, set_payees, shares, total_released, total_shares,
+        PaymentSplitterStorageKey,
+    },
+};
+
+mod test;
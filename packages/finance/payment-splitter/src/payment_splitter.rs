@@ -0,0 +1,150 @@
+use soroban_sdk::{contracterror, Address, Env, Symbol, Vec};
+
+/// A trait for a contract that releases the SEP-41 tokens it receives to a
+/// fixed set of payees, pro-rata to their shares.
+pub trait PaymentSplitter {
+    /// Returns the payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+    fn payees(e: &Env) -> Vec<Address>;
+
+    /// Returns the shares of `account`, `0` if it is not a payee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the payee.
+    fn shares(e: &Env, account: Address) -> u32;
+
+    /// Returns the sum of the shares of all the payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+    fn total_shares(e: &Env) -> u32;
+
+    /// Returns the amount of `token` already released to all the payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    fn total_released(e: &Env, token: Address) -> i128;
+
+    /// Returns the amount of `token` already released to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    /// * `account` - The address of the payee.
+    fn released(e: &Env, token: Address, account: Address) -> i128;
+
+    /// Returns the amount of `token` owed to `account` and not released yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    /// * `account` - The address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+    fn releasable(e: &Env, token: Address, account: Address) -> i128;
+
+    /// Releases the amount of `token` owed to `account` and returns the
+    /// released amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    /// * `account` - The address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+    /// * [`PaymentSplitterError::AccountHasNoShares`] - If `account` is not a
+    ///   payee.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["payment_released", token: Address]`
+    /// * data - `[account: Address, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// No authorization is required, as the tokens can only be sent to the
+    /// payee.
+    fn release(e: &Env, token: Address, account: Address) -> i128;
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentSplitterError {
+    /// The payees are not set.
+    PayeesNotSet = 1700,
+    /// The payees are already set.
+    PayeesAlreadySet = 1701,
+    /// The list of payees is empty.
+    NoPayees = 1702,
+    /// A payee has no shares.
+    ZeroShares = 1703,
+    /// A payee is listed more than once.
+    DuplicatePayee = 1704,
+    /// The sum of the shares overflows.
+    MathOverflow = 1705,
+    /// The account is not a payee.
+    AccountHasNoShares = 1706,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a payee is added.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address of the payee.
+/// * `shares` - The shares of the payee.
+///
+/// # Events
+///
+/// * topics - `["payee_added", account: Address]`
+/// * data - `[shares: u32]`
+pub fn emit_payee_added(e: &Env, account: &Address, shares: u32) {
+    let topics = (Symbol::new(e, "payee_added"), account);
+    e.events().publish(topics, shares)
+}
+
+/// Emits an event when tokens are released to a payee.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token` - The address of the released token.
+/// * `account` - The address of the payee.
+/// * `amount` - The released amount.
+///
+/// # Events
+///
+/// * topics - `["payment_released", token: Address]`
+/// * data - `[account: Address, amount: i128]`
+pub fn emit_payment_released(e: &Env, token: &Address, account: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "payment_released"), token);
+    e.events().publish(topics, (account, amount))
+}
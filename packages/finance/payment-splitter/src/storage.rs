@@ -0,0 +1,250 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env, Vec};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::payment_splitter::{emit_payee_added, emit_payment_released, PaymentSplitterError};
+
+/// Storage keys for the data associated with the payment splitter
+#[contracttype]
+pub enum PaymentSplitterStorageKey {
+    /// Stores the payees
+    Payees,
+    /// Stores the sum of the shares of all the payees
+    TotalShares,
+    /// Stores the shares of a payee
+    Shares(Address),
+    /// Stores the amount of a token released to all the payees
+    TotalReleased(Address /* token */),
+    /// Stores the amount of a token released to a payee
+    Released(Address /* token */, Address /* account */),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the payees.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+pub fn payees(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&PaymentSplitterStorageKey::Payees)
+        .unwrap_or_else(|| panic_with_error!(e, PaymentSplitterError::PayeesNotSet))
+}
+
+/// Returns the shares of `account`, `0` if it is not a payee.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address of the payee.
+pub fn shares(e: &Env, account: &Address) -> u32 {
+    let key = PaymentSplitterStorageKey::Shares(account.clone());
+    if let Some(shares) = e.storage().persistent().get::<_, u32>(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        shares
+    } else {
+        0
+    }
+}
+
+/// Returns the sum of the shares of all the payees.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::PayeesNotSet`] - If the payees are not set.
+pub fn total_shares(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&PaymentSplitterStorageKey::TotalShares)
+        .unwrap_or_else(|| panic_with_error!(e, PaymentSplitterError::PayeesNotSet))
+}
+
+/// Returns the amount of `token` already released to all the payees.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+pub fn total_released(e: &Env, token: &Address) -> i128 {
+    read_amount(e, &PaymentSplitterStorageKey::TotalReleased(token.clone()))
+}
+
+/// Returns the amount of `token` already released to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+/// * `account` - The address of the payee.
+pub fn released(e: &Env, token: &Address, account: &Address) -> i128 {
+    read_amount(e, &PaymentSplitterStorageKey::Released(token.clone(), account.clone()))
+}
+
+/// Returns the amount of `token` owed to `account` and not released yet, i.e.
+/// its share of all the tokens ever received by the contract (its balance and
+/// the released tokens), minus the amount already released to it. Returns `0`
+/// if more has been released to `account` than it is owed, which can happen
+/// when the balance of the contract drops (e.g. the token is clawed back).
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+/// * `account` - The address of the payee.
+///
+/// # Errors
+///
+/// * refer to [`total_shares`] errors.
+pub fn releasable(e: &Env, token: &Address, account: &Address) -> i128 {
+    let total_shares = total_shares(e) as u128;
+    let shares = shares(e, account) as u128;
+
+    let balance = TokenClient::new(e, token).balance(&e.current_contract_address());
+    let total_received = (balance + total_released(e, token)) as u128;
+
+    // the split multiplication cannot overflow
+    let owed = total_received / total_shares * shares
+        + total_received % total_shares * shares / total_shares;
+    (owed as i128 - released(e, token, account)).max(0)
+}
+
+// ################## CHANGE STATE ##################
+
+/// Sets the payees and their shares.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `payees` - The addresses of the payees and their shares.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::PayeesAlreadySet`] - If the payees are already
+///   set.
+/// * [`PaymentSplitterError::NoPayees`] - If `payees` is empty.
+/// * [`PaymentSplitterError::ZeroShares`] - If a payee has no shares.
+/// * [`PaymentSplitterError::DuplicatePayee`] - If a payee is listed more than
+///   once.
+/// * [`PaymentSplitterError::MathOverflow`] - If the sum of the shares
+///   overflows.
+///
+/// # Events
+///
+/// For each payee:
+///
+/// * topics - `["payee_added", account: Address]`
+/// * data - `[shares: u32]`
+///
+/// **IMPORTANT**: this function lacks authorization checks.
+/// It is expected to call this function only in the constructor!
+pub fn set_payees(e: &Env, payees: &Vec<(Address, u32)>) {
+    if e.storage().instance().has(&PaymentSplitterStorageKey::Payees) {
+        panic_with_error!(e, PaymentSplitterError::PayeesAlreadySet);
+    }
+    if payees.is_empty() {
+        panic_with_error!(e, PaymentSplitterError::NoPayees);
+    }
+
+    let mut accounts = Vec::new(e);
+    let mut total_shares: u32 = 0;
+    for (account, account_shares) in payees.iter() {
+        if account_shares == 0 {
+            panic_with_error!(e, PaymentSplitterError::ZeroShares);
+        }
+        if accounts.contains(&account) {
+            panic_with_error!(e, PaymentSplitterError::DuplicatePayee);
+        }
+        let Some(sum) = total_shares.checked_add(account_shares) else {
+            panic_with_error!(e, PaymentSplitterError::MathOverflow);
+        };
+        total_shares = sum;
+
+        e.storage()
+            .persistent()
+            .set(&PaymentSplitterStorageKey::Shares(account.clone()), &account_shares);
+        emit_payee_added(e, &account, account_shares);
+        accounts.push_back(account);
+    }
+
+    e.storage().instance().set(&PaymentSplitterStorageKey::Payees, &accounts);
+    e.storage().instance().set(&PaymentSplitterStorageKey::TotalShares, &total_shares);
+}
+
+/// Releases the amount of `token` owed to `account` and returns the released
+/// amount.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+/// * `account` - The address of the payee.
+///
+/// # Errors
+///
+/// * refer to [`releasable`] errors.
+/// * [`PaymentSplitterError::AccountHasNoShares`] - If `account` is not a
+///   payee.
+///
+/// # Events
+///
+/// * topics - `["payment_released", token: Address]`
+/// * data - `[account: Address, amount: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the tokens can only be sent to the
+/// payee.
+pub fn release(e: &Env, token: &Address, account: &Address) -> i128 {
+    if shares(e, account) == 0 {
+        panic_with_error!(e, PaymentSplitterError::AccountHasNoShares);
+    }
+    let amount = releasable(e, token, account);
+    if amount > 0 {
+        e.storage().persistent().set(
+            &PaymentSplitterStorageKey::Released(token.clone(), account.clone()),
+            &(released(e, token, account) + amount),
+        );
+        e.storage().persistent().set(
+            &PaymentSplitterStorageKey::TotalReleased(token.clone()),
+            &(total_released(e, token) + amount),
+        );
+
+        TokenClient::new(e, token).transfer(&e.current_contract_address(), account, &amount);
+        emit_payment_released(e, token, account, amount);
+    }
+    amount
+}
+
+// ################## LOW-LEVEL HELPERS ##################
+
+/// Returns the amount stored under `key`, `0` if there is none.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `key` - The storage key of the amount.
+fn read_amount(e: &Env, key: &PaymentSplitterStorageKey) -> i128 {
+    if let Some(amount) = e.storage().persistent().get::<_, i128>(key) {
+        e.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        amount
+    } else {
+        0
+    }
+}
@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    payees, releasable, release, released, set_payees, shares, total_released, total_shares,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, alice: &Address, bob: &Address) -> (Address, Address) {
+    let splitter = e.register(MockContract, ());
+    let token = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+
+    e.as_contract(&splitter, || {
+        set_payees(e, &vec![e, (alice.clone(), 1), (bob.clone(), 3)]);
+    });
+    (splitter, token)
+}
+
+#[test]
+fn set_payees_works() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e, (alice.clone(), 1), (bob.clone(), 3)]);
+
+        assert_eq!(payees(&e), vec![&e, alice.clone(), bob.clone()]);
+        assert_eq!(shares(&e, &alice), 1);
+        assert_eq!(shares(&e, &bob), 3);
+        assert_eq!(shares(&e, &Address::generate(&e)), 0);
+        assert_eq!(total_shares(&e), 4);
+
+        let event_assert = EventAssertion::new(&e, splitter.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn release_splits_pro_rata() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (splitter, token) = setup(&e, &alice, &bob);
+    let client = TokenClient::new(&e, &token);
+    StellarAssetClient::new(&e, &token).mint(&splitter, &1_000);
+
+    e.as_contract(&splitter, || {
+        assert_eq!(releasable(&e, &token, &alice), 250);
+        assert_eq!(releasable(&e, &token, &bob), 750);
+        assert_eq!(release(&e, &token, &alice), 250);
+        assert_eq!(released(&e, &token, &alice), 250);
+        assert_eq!(total_released(&e, &token), 250);
+        assert_eq!(releasable(&e, &token, &alice), 0);
+
+        let event_assert = EventAssertion::new(&e, splitter.clone());
+        // token transfer + payment_released
+        event_assert.assert_event_count(2);
+    });
+    assert_eq!(client.balance(&alice), 250);
+
+    // payments received after a release are split as well
+    StellarAssetClient::new(&e, &token).mint(&splitter, &1_000);
+
+    e.as_contract(&splitter, || {
+        assert_eq!(releasable(&e, &token, &alice), 250);
+        assert_eq!(releasable(&e, &token, &bob), 1_500);
+        assert_eq!(release(&e, &token, &bob), 1_500);
+    });
+    e.as_contract(&splitter, || {
+        assert_eq!(release(&e, &token, &alice), 250);
+        assert_eq!(total_released(&e, &token), 2_000);
+    });
+    assert_eq!(client.balance(&alice), 500);
+    assert_eq!(client.balance(&bob), 1_500);
+    assert_eq!(client.balance(&splitter), 0);
+}
+
+#[test]
+fn release_nothing_owed_returns_zero() {
+    let e = Env::default();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (splitter, token) = setup(&e, &alice, &bob);
+
+    e.as_contract(&splitter, || {
+        assert_eq!(release(&e, &token, &alice), 0);
+
+        let event_assert = EventAssertion::new(&e, splitter.clone());
+        event_assert.assert_event_count(0);
+    });
+}
+
+#[test]
+fn releasable_after_balance_drop_is_zero() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (splitter, token) = setup(&e, &alice, &bob);
+    let client = TokenClient::new(&e, &token);
+    StellarAssetClient::new(&e, &token).mint(&splitter, &1_000);
+
+    e.as_contract(&splitter, || {
+        assert_eq!(release(&e, &token, &bob), 750);
+    });
+
+    // the balance of the splitter drops, e.g. by a clawback
+    client.transfer(&splitter, &Address::generate(&e), &250);
+
+    e.as_contract(&splitter, || {
+        // bob is owed 3/4 of 750, less than the 750 already released to him
+        assert_eq!(releasable(&e, &token, &bob), 0);
+        assert_eq!(release(&e, &token, &bob), 0);
+        assert_eq!(releasable(&e, &token, &alice), 187);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1706)")]
+fn release_to_non_payee_panics() {
+    let e = Env::default();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (splitter, token) = setup(&e, &alice, &bob);
+
+    e.as_contract(&splitter, || {
+        release(&e, &token, &Address::generate(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1701)")]
+fn set_payees_twice_panics() {
+    let e = Env::default();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (splitter, _) = setup(&e, &alice, &bob);
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e, (alice.clone(), 1)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1700)")]
+fn payees_not_set_panics() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+
+    e.as_contract(&splitter, || {
+        total_shares(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1702)")]
+fn set_no_payees_panics() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1703)")]
+fn set_payee_with_zero_shares_panics() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e, (alice.clone(), 0)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1704)")]
+fn set_duplicate_payee_panics() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e, (alice.clone(), 1), (alice.clone(), 2)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1705)")]
+fn set_payees_shares_overflow_panics() {
+    let e = Env::default();
+    let splitter = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&splitter, || {
+        set_payees(&e, &vec![&e, (alice.clone(), u32::MAX), (bob.clone(), 1)]);
+    });
+}
@@ -0,0 +1,45 @@
+//! # Token Lock Contract Module.
+//!
+//! This module implements a token lock: a contract that holds SEP-41 tokens
+//! (including `FungibleToken` contracts and Stellar Asset Contracts) for a
+//! beneficiary until a release ledger or timestamp, e.g. to guarantee that
+//! liquidity or team tokens cannot be moved before a given date.
+//!
+//! The `TokenLock` trait exposes methods for:
+//! - Locking tokens for a beneficiary
+//! - Getting the locks of a beneficiary
+//! - Unlocking the tokens once the release point is reached
+//! - Releasing the tokens early, by an account with the [`RELEASER_ROLE`]
+//!
+//! ## Implementation Notes
+//!
+//! - **Multiple locks**: Every call to `lock` creates a new lock with its own
+//!   id, token, amount and release point, so a beneficiary can have any number
+//!   of locks.
+//! - **Enumeration**: Anyone can create locks for any beneficiary, so the ids
+//!   of the locks of a beneficiary are stored one entry per lock and returned
+//!   page by page with `locks_of`, and the cost of a lock or an unlock does not
+//!   depend on the number of locks of the beneficiary.
+//! - **Unlocking**: Once the release point is reached, anyone can unlock the
+//!   tokens, as they are always sent to the beneficiary.
+//! - **Early release**: Accounts with the [`RELEASER_ROLE`] of
+//!   `stellar_access_control` can release a lock before its release point. The
+//!   admin of the contract grants the role, and contracts that promise an
+//!   irrevocable lock should never grant it.
+#![no_std]
+
+mod storage;
+mod token_lock;
+
+pub use crate::{
+    storage::{
+        early_release, get_lock, is_unlocked, lock, lock_count, locks_of, unlock,
+        BeneficiaryLockKey, TokenLockStorageKey,
+    },
+    token_lock::{
+        emit_early_release, emit_lock, emit_unlock, ReleaseTime, TokenLock, TokenLockData,
+        TokenLockError, RELEASER_ROLE,
+    },
+};
+
+mod test;
@@ -0,0 +1,329 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env, Vec};
+use stellar_access_control::ensure_role;
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::token_lock::{
+    emit_early_release, emit_lock, emit_unlock, ReleaseTime, TokenLockData, TokenLockError,
+    RELEASER_ROLE,
+};
+
+/// Storage key for the id of the lock of a beneficiary at an index
+#[contracttype]
+pub struct BeneficiaryLockKey {
+    pub beneficiary: Address,
+    pub index: u32,
+}
+
+/// Storage keys for the data associated with the token lock
+#[contracttype]
+pub enum TokenLockStorageKey {
+    /// Stores the id of the next lock
+    NextId,
+    /// Stores a lock by id
+    Lock(u32),
+    /// Stores the number of locks of a beneficiary
+    LockCount(Address),
+    /// Stores the id of the lock of a beneficiary at an index
+    BeneficiaryLock(BeneficiaryLockKey),
+    /// Stores the index of a lock among the locks of its beneficiary
+    BeneficiaryLockIndex(/* lock_id */ u32),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the lock with id `lock_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `lock_id` - The id of the lock.
+///
+/// # Errors
+///
+/// * [`TokenLockError::LockNotFound`] - If the lock does not exist.
+pub fn get_lock(e: &Env, lock_id: u32) -> TokenLockData {
+    let key = TokenLockStorageKey::Lock(lock_id);
+    let Some(lock) = e.storage().persistent().get::<_, TokenLockData>(&key) else {
+        panic_with_error!(e, TokenLockError::LockNotFound);
+    };
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+    lock
+}
+
+/// Returns the number of locks of `beneficiary` that are not unlocked yet.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `beneficiary` - The address receiving the tokens.
+pub fn lock_count(e: &Env, beneficiary: &Address) -> u32 {
+    let key = TokenLockStorageKey::LockCount(beneficiary.clone());
+    if let Some(count) = e.storage().persistent().get::<_, u32>(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        count
+    } else {
+        0
+    }
+}
+
+/// Returns the ids of at most `limit` locks of `beneficiary` that are not
+/// unlocked yet, starting at index `start`. Use along with [`lock_count`] to
+/// enumerate all the locks of `beneficiary`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `beneficiary` - The address receiving the tokens.
+/// * `start` - The index of the first lock to return.
+/// * `limit` - The maximum number of locks to return.
+///
+/// # Notes
+///
+/// Unlocking a lock moves the last lock of the beneficiary to its index, so
+/// the order of the locks is not preserved between pages.
+pub fn locks_of(e: &Env, beneficiary: &Address, start: u32, limit: u32) -> Vec<u32> {
+    let end = lock_count(e, beneficiary).min(start.saturating_add(limit));
+    let mut locks = Vec::new(e);
+    for index in start..end {
+        locks.push_back(lock_of(e, beneficiary, index));
+    }
+    locks
+}
+
+/// Returns whether the release point of the lock with id `lock_id` is
+/// reached.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `lock_id` - The id of the lock.
+///
+/// # Errors
+///
+/// * refer to [`get_lock`] errors.
+pub fn is_unlocked(e: &Env, lock_id: u32) -> bool {
+    match get_lock(e, lock_id).release {
+        ReleaseTime::Ledger(ledger) => e.ledger().sequence() >= ledger,
+        ReleaseTime::Timestamp(timestamp) => e.ledger().timestamp() >= timestamp,
+    }
+}
+
+// ################## CHANGE STATE ##################
+
+/// Locks `amount` of `token` from `depositor` for `beneficiary` until
+/// `release`, and returns the id of the new lock.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `depositor` - The address providing the tokens.
+/// * `token` - The address of the SEP-41 token.
+/// * `beneficiary` - The address receiving the tokens when they are unlocked.
+/// * `amount` - The amount of tokens to lock.
+/// * `release` - The point from which the tokens can be unlocked.
+///
+/// # Errors
+///
+/// * [`TokenLockError::InvalidAmount`] - If `amount` is not positive.
+///
+/// # Events
+///
+/// * topics - `["lock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128, release: ReleaseTime]`
+///
+/// # Notes
+///
+/// * Authorization for `depositor` is required.
+pub fn lock(
+    e: &Env,
+    depositor: &Address,
+    token: &Address,
+    beneficiary: &Address,
+    amount: i128,
+    release: ReleaseTime,
+) -> u32 {
+    depositor.require_auth();
+    if amount <= 0 {
+        panic_with_error!(e, TokenLockError::InvalidAmount);
+    }
+
+    let lock_id: u32 = e.storage().instance().get(&TokenLockStorageKey::NextId).unwrap_or(0);
+    e.storage().instance().set(&TokenLockStorageKey::NextId, &(lock_id + 1));
+
+    let lock =
+        TokenLockData { token: token.clone(), beneficiary: beneficiary.clone(), amount, release };
+    e.storage().persistent().set(&TokenLockStorageKey::Lock(lock_id), &lock);
+
+    add_to_beneficiary_locks(e, beneficiary, lock_id);
+
+    TokenClient::new(e, token).transfer(depositor, &e.current_contract_address(), &amount);
+    emit_lock(e, lock_id, &lock);
+    lock_id
+}
+
+/// Sends the tokens of the lock with id `lock_id` to its beneficiary, once the
+/// release point is reached. Returns the amount of unlocked tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `lock_id` - The id of the lock.
+///
+/// # Errors
+///
+/// * refer to [`get_lock`] errors.
+/// * [`TokenLockError::StillLocked`] - If the release point is not reached.
+///
+/// # Events
+///
+/// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the tokens can only be sent to the
+/// beneficiary.
+pub fn unlock(e: &Env, lock_id: u32) -> i128 {
+    if !is_unlocked(e, lock_id) {
+        panic_with_error!(e, TokenLockError::StillLocked);
+    }
+    release_lock(e, lock_id)
+}
+
+/// Sends the tokens of the lock with id `lock_id` to its beneficiary before
+/// the release point. Returns the amount of unlocked tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `caller` - The address releasing the lock.
+/// * `lock_id` - The id of the lock.
+///
+/// # Errors
+///
+/// * refer to [`get_lock`] errors.
+/// * [`stellar_access_control::AccessControlError::Unauthorized`] - If `caller`
+///   does not have the [`RELEASER_ROLE`].
+///
+/// # Events
+///
+/// * topics - `["early_release", lock_id: u32, caller: Address]`
+/// * data - `[]`
+/// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `caller` is required.
+pub fn early_release(e: &Env, caller: &Address, lock_id: u32) -> i128 {
+    caller.require_auth();
+    ensure_role(e, caller, &RELEASER_ROLE);
+    emit_early_release(e, lock_id, caller);
+    release_lock(e, lock_id)
+}
+
+// ################## LOW-LEVEL HELPERS ##################
+
+/// Removes the lock with id `lock_id` and sends its tokens to its beneficiary.
+/// Returns the amount of unlocked tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `lock_id` - The id of the lock.
+///
+/// # Errors
+///
+/// * refer to [`get_lock`] errors.
+///
+/// # Events
+///
+/// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128]`
+///
+/// # Notes
+///
+/// This function does not check the release point nor enforce authorization.
+/// Ensure that both are handled at a higher level.
+fn release_lock(e: &Env, lock_id: u32) -> i128 {
+    let lock = get_lock(e, lock_id);
+    e.storage().persistent().remove(&TokenLockStorageKey::Lock(lock_id));
+    remove_from_beneficiary_locks(e, &lock.beneficiary, lock_id);
+
+    TokenClient::new(e, &lock.token).transfer(
+        &e.current_contract_address(),
+        &lock.beneficiary,
+        &lock.amount,
+    );
+    emit_unlock(e, lock_id, &lock);
+    lock.amount
+}
+
+/// Returns the id of the lock of `beneficiary` at `index`.
+fn lock_of(e: &Env, beneficiary: &Address, index: u32) -> u32 {
+    let key = TokenLockStorageKey::BeneficiaryLock(BeneficiaryLockKey {
+        beneficiary: beneficiary.clone(),
+        index,
+    });
+    let Some(lock_id) = e.storage().persistent().get::<_, u32>(&key) else {
+        panic_with_error!(e, TokenLockError::LockNotFound);
+    };
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+    lock_id
+}
+
+/// Appends `lock_id` to the locks of `beneficiary`.
+fn add_to_beneficiary_locks(e: &Env, beneficiary: &Address, lock_id: u32) {
+    let index = lock_count(e, beneficiary);
+    e.storage().persistent().set(
+        &TokenLockStorageKey::BeneficiaryLock(BeneficiaryLockKey {
+            beneficiary: beneficiary.clone(),
+            index,
+        }),
+        &lock_id,
+    );
+    e.storage().persistent().set(&TokenLockStorageKey::BeneficiaryLockIndex(lock_id), &index);
+    e.storage()
+        .persistent()
+        .set(&TokenLockStorageKey::LockCount(beneficiary.clone()), &(index + 1));
+}
+
+/// Removes `lock_id` from the locks of `beneficiary` by moving the last lock
+/// of `beneficiary` to its index.
+fn remove_from_beneficiary_locks(e: &Env, beneficiary: &Address, lock_id: u32) {
+    let index_key = TokenLockStorageKey::BeneficiaryLockIndex(lock_id);
+    let Some(index) = e.storage().persistent().get::<_, u32>(&index_key) else {
+        panic_with_error!(e, TokenLockError::LockNotFound);
+    };
+    let last_index = lock_count(e, beneficiary) - 1;
+
+    if index != last_index {
+        let last_lock_id = lock_of(e, beneficiary, last_index);
+        e.storage().persistent().set(
+            &TokenLockStorageKey::BeneficiaryLock(BeneficiaryLockKey {
+                beneficiary: beneficiary.clone(),
+                index,
+            }),
+            &last_lock_id,
+        );
+        e.storage()
+            .persistent()
+            .set(&TokenLockStorageKey::BeneficiaryLockIndex(last_lock_id), &index);
+    }
+
+    e.storage().persistent().remove(&TokenLockStorageKey::BeneficiaryLock(BeneficiaryLockKey {
+        beneficiary: beneficiary.clone(),
+        index: last_index,
+    }));
+    e.storage().persistent().remove(&index_key);
+    let count_key = TokenLockStorageKey::LockCount(beneficiary.clone());
+    if last_index == 0 {
+        e.storage().persistent().remove(&count_key);
+    } else {
+        e.storage().persistent().set(&count_key, &last_index);
+    }
+}
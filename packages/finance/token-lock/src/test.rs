@@ -0,0 +1,225 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+use stellar_access_control::{grant_role_no_auth, set_admin};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    early_release, get_lock, is_unlocked, lock, lock_count, locks_of, unlock, ReleaseTime,
+    TokenLockData, RELEASER_ROLE,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, depositor: &Address) -> (Address, Address) {
+    let locker = e.register(MockContract, ());
+    let token = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    StellarAssetClient::new(e, &token).mint(depositor, &1_000);
+    (locker, token)
+}
+
+#[test]
+fn lock_and_unlock_at_ledger() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        let lock_id = lock(&e, &depositor, &token, &beneficiary, 400, ReleaseTime::Ledger(100));
+        assert_eq!(lock_id, 0);
+        assert_eq!(
+            get_lock(&e, lock_id),
+            TokenLockData {
+                token: token.clone(),
+                beneficiary: beneficiary.clone(),
+                amount: 400,
+                release: ReleaseTime::Ledger(100),
+            }
+        );
+        assert!(!is_unlocked(&e, lock_id));
+
+        let event_assert = EventAssertion::new(&e, locker.clone());
+        // token transfer + lock
+        event_assert.assert_event_count(2);
+    });
+    let client = TokenClient::new(&e, &token);
+    assert_eq!(client.balance(&locker), 400);
+    assert_eq!(client.balance(&depositor), 600);
+
+    e.ledger().set_sequence_number(100);
+
+    e.as_contract(&locker, || {
+        assert!(is_unlocked(&e, 0));
+        assert_eq!(unlock(&e, 0), 400);
+        assert_eq!(lock_count(&e, &beneficiary), 0);
+    });
+    assert_eq!(client.balance(&locker), 0);
+    assert_eq!(client.balance(&beneficiary), 400);
+}
+
+#[test]
+fn multiple_locks_per_beneficiary() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        lock(&e, &depositor, &token, &beneficiary, 100, ReleaseTime::Timestamp(1_000));
+    });
+    e.as_contract(&locker, || {
+        lock(&e, &depositor, &token, &beneficiary, 200, ReleaseTime::Timestamp(2_000));
+    });
+    e.as_contract(&locker, || {
+        assert_eq!(lock_count(&e, &beneficiary), 2);
+        assert_eq!(locks_of(&e, &beneficiary, 0, 10), vec![&e, 0, 1]);
+    });
+
+    e.ledger().set_timestamp(1_500);
+
+    e.as_contract(&locker, || {
+        assert!(is_unlocked(&e, 0));
+        assert!(!is_unlocked(&e, 1));
+        assert_eq!(unlock(&e, 0), 100);
+        assert_eq!(locks_of(&e, &beneficiary, 0, 10), vec![&e, 1]);
+    });
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 100);
+}
+
+#[test]
+fn locks_of_is_paginated() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    for _ in 0..5 {
+        e.as_contract(&locker, || {
+            lock(&e, &depositor, &token, &beneficiary, 10, ReleaseTime::Ledger(100));
+        });
+    }
+
+    e.as_contract(&locker, || {
+        assert_eq!(lock_count(&e, &beneficiary), 5);
+        assert_eq!(locks_of(&e, &beneficiary, 0, 2), vec![&e, 0, 1]);
+        assert_eq!(locks_of(&e, &beneficiary, 2, 2), vec![&e, 2, 3]);
+        assert_eq!(locks_of(&e, &beneficiary, 4, 2), vec![&e, 4]);
+        assert_eq!(locks_of(&e, &beneficiary, 6, 2).len(), 0);
+    });
+
+    e.ledger().set_sequence_number(100);
+
+    e.as_contract(&locker, || {
+        unlock(&e, 1);
+        // the last lock takes the index of the unlocked one
+        assert_eq!(locks_of(&e, &beneficiary, 0, u32::MAX), vec![&e, 0, 4, 2, 3]);
+    });
+}
+
+#[test]
+fn early_release_by_releaser() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let releaser = Address::generate(&e);
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        set_admin(&e, &admin);
+        grant_role_no_auth(&e, &admin, &releaser, &RELEASER_ROLE);
+        lock(&e, &depositor, &token, &beneficiary, 300, ReleaseTime::Ledger(1_000));
+    });
+
+    e.as_contract(&locker, || {
+        assert_eq!(early_release(&e, &releaser, 0), 300);
+
+        let event_assert = EventAssertion::new(&e, locker.clone());
+        // early_release + token transfer + unlock
+        event_assert.assert_event_count(3);
+    });
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 300);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1601)")]
+fn unlock_before_release_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        lock(&e, &depositor, &token, &beneficiary, 100, ReleaseTime::Ledger(100));
+    });
+
+    e.ledger().set_sequence_number(99);
+
+    e.as_contract(&locker, || {
+        unlock(&e, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1600)")]
+fn unlock_twice_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        lock(&e, &depositor, &token, &beneficiary, 100, ReleaseTime::Ledger(0));
+        unlock(&e, 0);
+        unlock(&e, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1602)")]
+fn lock_zero_amount_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        lock(&e, &depositor, &token, &beneficiary, 0, ReleaseTime::Ledger(100));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1210)")]
+fn early_release_without_role_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let depositor = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let (locker, token) = setup(&e, &depositor);
+
+    e.as_contract(&locker, || {
+        set_admin(&e, &admin);
+        lock(&e, &depositor, &token, &beneficiary, 100, ReleaseTime::Ledger(100));
+    });
+
+    e.as_contract(&locker, || {
+        early_release(&e, &beneficiary, 0);
+    });
+}
@@ -0,0 +1,210 @@
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+/// The role allowed to release locks before their release point.
+pub const RELEASER_ROLE: Symbol = symbol_short!("releaser");
+
+/// The point from which locked tokens can be unlocked.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReleaseTime {
+    /// A ledger sequence number.
+    Ledger(u32),
+    /// A ledger timestamp, in seconds.
+    Timestamp(u64),
+}
+
+/// Storage container for a lock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLockData {
+    /// The address of the locked SEP-41 token.
+    pub token: Address,
+    /// The address receiving the tokens when they are unlocked.
+    pub beneficiary: Address,
+    /// The amount of locked tokens.
+    pub amount: i128,
+    /// The point from which the tokens can be unlocked.
+    pub release: ReleaseTime,
+}
+
+/// A trait for a contract that holds SEP-41 tokens for beneficiaries until a
+/// release point.
+pub trait TokenLock {
+    /// Returns the lock with id `lock_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `lock_id` - The id of the lock.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenLockError::LockNotFound`] - If the lock does not exist.
+    fn get_lock(e: &Env, lock_id: u32) -> TokenLockData;
+
+    /// Returns the number of locks of `beneficiary`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `beneficiary` - The address receiving the tokens.
+    fn lock_count(e: &Env, beneficiary: Address) -> u32;
+
+    /// Returns the ids of at most `limit` locks of `beneficiary`, starting at
+    /// index `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `beneficiary` - The address receiving the tokens.
+    /// * `start` - The index of the first lock to return.
+    /// * `limit` - The maximum number of locks to return.
+    fn locks_of(e: &Env, beneficiary: Address, start: u32, limit: u32) -> Vec<u32>;
+
+    /// Locks `amount` of `token` from `depositor` for `beneficiary` until
+    /// `release`, and returns the id of the new lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `depositor` - The address providing the tokens.
+    /// * `token` - The address of the SEP-41 token.
+    /// * `beneficiary` - The address receiving the tokens when they are
+    ///   unlocked.
+    /// * `amount` - The amount of tokens to lock.
+    /// * `release` - The point from which the tokens can be unlocked.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenLockError::InvalidAmount`] - If `amount` is not positive.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["lock", lock_id: u32, beneficiary: Address]`
+    /// * data - `[token: Address, amount: i128, release: ReleaseTime]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `depositor` is required.
+    fn lock(
+        e: &Env,
+        depositor: Address,
+        token: Address,
+        beneficiary: Address,
+        amount: i128,
+        release: ReleaseTime,
+    ) -> u32;
+
+    /// Sends the tokens of the lock with id `lock_id` to its beneficiary, once
+    /// the release point is reached. Returns the amount of unlocked tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `lock_id` - The id of the lock.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenLockError::LockNotFound`] - If the lock does not exist.
+    /// * [`TokenLockError::StillLocked`] - If the release point is not reached.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+    /// * data - `[token: Address, amount: i128]`
+    fn unlock(e: &Env, lock_id: u32) -> i128;
+
+    /// Sends the tokens of the lock with id `lock_id` to its beneficiary
+    /// before the release point. Returns the amount of unlocked tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - The address releasing the lock.
+    /// * `lock_id` - The id of the lock.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenLockError::LockNotFound`] - If the lock does not exist.
+    /// * [`stellar_access_control::AccessControlError::Unauthorized`] - If
+    ///   `caller` does not have the [`RELEASER_ROLE`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["early_release", lock_id: u32, caller: Address]`
+    /// * data - `[]`
+    /// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+    /// * data - `[token: Address, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `caller` is required.
+    fn early_release(e: &Env, caller: Address, lock_id: u32) -> i128;
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenLockError {
+    /// The lock does not exist or was already unlocked.
+    LockNotFound = 1600,
+    /// The release point of the lock is not reached.
+    StillLocked = 1601,
+    /// The amount to lock is not positive.
+    InvalidAmount = 1602,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when tokens are locked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `lock_id` - The id of the new lock.
+/// * `lock` - The new lock.
+///
+/// # Events
+///
+/// * topics - `["lock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128, release: ReleaseTime]`
+pub fn emit_lock(e: &Env, lock_id: u32, lock: &TokenLockData) {
+    let topics = (symbol_short!("lock"), lock_id, lock.beneficiary.clone());
+    e.events().publish(topics, (lock.token.clone(), lock.amount, lock.release))
+}
+
+/// Emits an event when tokens are unlocked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `lock_id` - The id of the unlocked lock.
+/// * `lock` - The unlocked lock.
+///
+/// # Events
+///
+/// * topics - `["unlock", lock_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, amount: i128]`
+pub fn emit_unlock(e: &Env, lock_id: u32, lock: &TokenLockData) {
+    let topics = (symbol_short!("unlock"), lock_id, lock.beneficiary.clone());
+    e.events().publish(topics, (lock.token.clone(), lock.amount))
+}
+
+/// Emits an event when a lock is released before its release point.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `lock_id` - The id of the released lock.
+/// * `caller` - The address releasing the lock.
+///
+/// # Events
+///
+/// * topics - `["early_release", lock_id: u32, caller: Address]`
+/// * data - `[]`
+pub fn emit_early_release(e: &Env, lock_id: u32, caller: &Address) {
+    let topics = (Symbol::new(e, "early_release"), lock_id, caller);
+    e.events().publish(topics, ())
+}
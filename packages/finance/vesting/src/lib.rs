@@ -0,0 +1,56 @@
+//! # Vesting Wallet Contract Module.
+//!
+//! This module implements a vesting wallet: a contract that holds SEP-41
+//! tokens (including `FungibleToken` contracts and Stellar Asset Contracts)
+//! and releases them to a beneficiary following a vesting schedule.
+//!
+//! The `VestingWallet` trait exposes methods for:
+//! - Getting the vesting schedule
+//! - Getting the amount of a token already released and releasable
+//! - Releasing the vested tokens to the beneficiary
+//!
+//! ## Vesting Schedule
+//!
+//! Every token held by the wallet, including tokens received after the start
+//! of the vesting, follows the same [`VestingSchedule`]:
+//!
+//! - Nothing is vested before `start + cliff`.
+//! - The tokens vest linearly between `start` and `start + duration`, i.e. at
+//!   `start + cliff` the amount vested during the cliff is released at once.
+//! - Everything is vested from `start + duration` on.
+//!
+//! A `cliff` equal to `duration` makes a cliff-only schedule, where all the
+//! tokens are vested at once at `start + duration`. The schedule is expressed
+//! either in ledger sequence numbers or in ledger timestamps, see
+//! [`VestingClock`].
+//!
+//! ## Beneficiary
+//!
+//! The beneficiary is the owner of the contract, as defined by
+//! `stellar_ownable`, so the vesting can be transferred to another account
+//! with the 2-step ownership transfer. Releasing does not require any
+//! authorization, as the tokens are always sent to the beneficiary.
+//!
+//! ```ignore
+//! #[contractimpl]
+//! impl ExampleContract {
+//!     pub fn __constructor(e: &Env, beneficiary: Address, schedule: VestingSchedule) {
+//!         set_owner(e, &beneficiary);
+//!         set_schedule(e, &schedule);
+//!     }
+//! }
+//! ```
+#![no_std]
+
+mod storage;
+mod vesting;
+
+pub use crate::{
+    storage::{
+        now, releasable, release, released, set_schedule, vested_amount, vesting_schedule,
+        VestingStorageKey,
+    },
+    vesting::{emit_released, VestingClock, VestingError, VestingSchedule, VestingWallet},
+};
+
+mod test;
@@ -0,0 +1,176 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+use stellar_ownable::{get_owner, OwnableError};
+
+use crate::vesting::{emit_released, VestingClock, VestingError, VestingSchedule};
+
+/// Storage keys for the data associated with the vesting wallet
+#[contracttype]
+pub enum VestingStorageKey {
+    /// Stores the vesting schedule
+    Schedule,
+    /// Stores the amount of a token already released
+    Released(Address),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the vesting schedule.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`VestingError::ScheduleNotSet`] - If the schedule is not set.
+pub fn vesting_schedule(e: &Env) -> VestingSchedule {
+    e.storage()
+        .instance()
+        .get(&VestingStorageKey::Schedule)
+        .unwrap_or_else(|| panic_with_error!(e, VestingError::ScheduleNotSet))
+}
+
+/// Returns the amount of `token` already released. Defaults to `0`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+pub fn released(e: &Env, token: &Address) -> i128 {
+    let key = VestingStorageKey::Released(token.clone());
+    if let Some(released) = e.storage().persistent().get::<_, i128>(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        released
+    } else {
+        0
+    }
+}
+
+/// Returns the amount of `token` that is vested but not released yet, or `0`
+/// if the wallet lost tokens so that less is vested than already released.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+///
+/// # Errors
+///
+/// * refer to [`vested_amount`] errors.
+pub fn releasable(e: &Env, token: &Address) -> i128 {
+    let schedule = vesting_schedule(e);
+    (vested_amount(e, token, now(e, schedule.clock)) - released(e, token)).max(0)
+}
+
+/// Returns the amount of `token` vested at `time`, out of all the tokens ever
+/// received by the wallet, i.e. its balance and the released tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+/// * `time` - The point in time, expressed in the clock of the schedule.
+///
+/// # Errors
+///
+/// * [`VestingError::ScheduleNotSet`] - If the schedule is not set.
+pub fn vested_amount(e: &Env, token: &Address, time: u64) -> i128 {
+    let schedule = vesting_schedule(e);
+    let balance = TokenClient::new(e, token).balance(&e.current_contract_address());
+    let total_allocation = balance + released(e, token);
+
+    if time < schedule.start.saturating_add(schedule.cliff) {
+        0
+    } else if time >= schedule.start.saturating_add(schedule.duration) {
+        total_allocation
+    } else {
+        // `duration > 0` here, and the split multiplication cannot overflow
+        let (elapsed, duration) = ((time - schedule.start) as u128, schedule.duration as u128);
+        let total = total_allocation as u128;
+        (total / duration * elapsed + total % duration * elapsed / duration) as i128
+    }
+}
+
+// ################## CHANGE STATE ##################
+
+/// Sets the vesting schedule.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `schedule` - The vesting schedule.
+///
+/// # Errors
+///
+/// * [`VestingError::ScheduleAlreadySet`] - If the schedule is already set.
+/// * [`VestingError::InvalidSchedule`] - If the cliff is longer than the
+///   duration.
+///
+/// **IMPORTANT**: this function lacks authorization checks.
+/// It is expected to call this function only in the constructor!
+pub fn set_schedule(e: &Env, schedule: &VestingSchedule) {
+    if e.storage().instance().has(&VestingStorageKey::Schedule) {
+        panic_with_error!(e, VestingError::ScheduleAlreadySet);
+    }
+    if schedule.cliff > schedule.duration {
+        panic_with_error!(e, VestingError::InvalidSchedule);
+    }
+    e.storage().instance().set(&VestingStorageKey::Schedule, schedule);
+}
+
+/// Releases the vested amount of `token` to the beneficiary, i.e. the owner
+/// of the contract, and returns the released amount.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the SEP-41 token.
+///
+/// # Errors
+///
+/// * refer to [`releasable`] errors.
+/// * [`OwnableError::OwnerNotSet`] - If there is no beneficiary.
+///
+/// # Events
+///
+/// * topics - `["released", token: Address]`
+/// * data - `[beneficiary: Address, amount: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the tokens can only be sent to the
+/// beneficiary.
+pub fn release(e: &Env, token: &Address) -> i128 {
+    let Some(beneficiary) = get_owner(e) else {
+        panic_with_error!(e, OwnableError::OwnerNotSet);
+    };
+    let amount = releasable(e, token);
+    if amount > 0 {
+        let released = released(e, token) + amount;
+        e.storage().persistent().set(&VestingStorageKey::Released(token.clone()), &released);
+
+        TokenClient::new(e, token).transfer(&e.current_contract_address(), &beneficiary, &amount);
+        emit_released(e, token, &beneficiary, amount);
+    }
+    amount
+}
+
+// ################## LOW-LEVEL HELPERS ##################
+
+/// Returns the current point in time, expressed in `clock`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `clock` - The clock to read.
+pub fn now(e: &Env, clock: VestingClock) -> u64 {
+    match clock {
+        VestingClock::Ledger => e.ledger().sequence() as u64,
+        VestingClock::Timestamp => e.ledger().timestamp(),
+    }
+}
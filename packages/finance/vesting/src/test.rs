@@ -0,0 +1,209 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+use stellar_ownable::{accept_ownership, set_owner, transfer_ownership};
+
+use crate::{
+    releasable, release, released, set_schedule, vested_amount, VestingClock, VestingSchedule,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, beneficiary: &Address, schedule: &VestingSchedule) -> (Address, Address) {
+    let wallet = e.register(MockContract, ());
+    let token = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    StellarAssetClient::new(e, &token).mint(&wallet, &1_000);
+
+    e.as_contract(&wallet, || {
+        set_owner(e, beneficiary);
+        set_schedule(e, schedule);
+    });
+    (wallet, token)
+}
+
+fn linear_schedule(clock: VestingClock, cliff: u64) -> VestingSchedule {
+    VestingSchedule { clock, start: 100, cliff, duration: 1_000 }
+}
+
+#[test]
+fn linear_vesting_in_ledgers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 0));
+
+    e.as_contract(&wallet, || {
+        assert_eq!(vested_amount(&e, &token, 50), 0);
+        assert_eq!(vested_amount(&e, &token, 350), 250);
+        assert_eq!(vested_amount(&e, &token, 1_100), 1_000);
+        assert_eq!(vested_amount(&e, &token, 5_000), 1_000);
+    });
+
+    e.ledger().set_sequence_number(600);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(releasable(&e, &token), 500);
+        assert_eq!(release(&e, &token), 500);
+        assert_eq!(released(&e, &token), 500);
+        assert_eq!(releasable(&e, &token), 0);
+
+        let event_assert = EventAssertion::new(&e, wallet.clone());
+        // token transfer + released
+        event_assert.assert_event_count(2);
+    });
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 500);
+
+    e.ledger().set_sequence_number(1_100);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(release(&e, &token), 500);
+    });
+    assert_eq!(TokenClient::new(&e, &token).balance(&beneficiary), 1_000);
+}
+
+#[test]
+fn linear_vesting_in_timestamps() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Timestamp, 0));
+
+    e.ledger().set_timestamp(350);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(releasable(&e, &token), 250);
+    });
+}
+
+#[test]
+fn cliff_vesting() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 400));
+
+    e.as_contract(&wallet, || {
+        assert_eq!(vested_amount(&e, &token, 499), 0);
+        assert_eq!(vested_amount(&e, &token, 500), 400);
+    });
+
+    e.ledger().set_sequence_number(300);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(release(&e, &token), 0);
+        let event_assert = EventAssertion::new(&e, wallet.clone());
+        event_assert.assert_event_count(0);
+    });
+}
+
+#[test]
+fn tokens_received_later_follow_the_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 0));
+
+    e.ledger().set_sequence_number(600);
+
+    e.as_contract(&wallet, || {
+        release(&e, &token);
+    });
+
+    StellarAssetClient::new(&e, &token).mint(&wallet, &1_000);
+
+    e.as_contract(&wallet, || {
+        // half of the 2_000 tokens received are vested, 500 already released
+        assert_eq!(releasable(&e, &token), 500);
+    });
+}
+
+#[test]
+fn releasable_is_zero_when_balance_decreases() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let other = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 0));
+
+    e.ledger().set_sequence_number(600);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(release(&e, &token), 500);
+    });
+    // e.g. clawed back, so only 350 of 700 are vested while 500 are released
+    TokenClient::new(&e, &token).transfer(&wallet, &other, &300);
+
+    e.as_contract(&wallet, || {
+        assert_eq!(vested_amount(&e, &token, 600), 350);
+        assert_eq!(releasable(&e, &token), 0);
+        assert_eq!(release(&e, &token), 0);
+        assert_eq!(released(&e, &token), 500);
+    });
+}
+
+#[test]
+fn release_follows_ownership_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let new_beneficiary = Address::generate(&e);
+    let (wallet, token) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 0));
+
+    e.as_contract(&wallet, || {
+        transfer_ownership(&e, &new_beneficiary, 1_000);
+    });
+
+    e.as_contract(&wallet, || {
+        accept_ownership(&e);
+    });
+
+    e.ledger().set_sequence_number(1_100);
+
+    e.as_contract(&wallet, || {
+        release(&e, &token);
+    });
+    assert_eq!(TokenClient::new(&e, &token).balance(&new_beneficiary), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1501)")]
+fn set_schedule_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    let (wallet, _) = setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 0));
+
+    e.as_contract(&wallet, || {
+        set_schedule(&e, &linear_schedule(VestingClock::Ledger, 0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1502)")]
+fn cliff_longer_than_duration_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let beneficiary = Address::generate(&e);
+    setup(&e, &beneficiary, &linear_schedule(VestingClock::Ledger, 1_001));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1500)")]
+fn releasable_without_schedule_fails() {
+    let e = Env::default();
+    let wallet = e.register(MockContract, ());
+    let token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+
+    e.as_contract(&wallet, || {
+        releasable(&e, &token);
+    });
+}
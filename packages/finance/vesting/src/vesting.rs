@@ -0,0 +1,118 @@
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env};
+
+/// The clock a [`VestingSchedule`] is expressed in.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VestingClock {
+    /// Ledger sequence numbers.
+    Ledger,
+    /// Ledger timestamps, in seconds.
+    Timestamp,
+}
+
+/// A linear vesting schedule with an optional cliff.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// The clock `start`, `cliff` and `duration` are expressed in.
+    pub clock: VestingClock,
+    /// The point at which the vesting starts.
+    pub start: u64,
+    /// The time after `start` during which nothing is vested.
+    pub cliff: u64,
+    /// The time after `start` at which everything is vested.
+    pub duration: u64,
+}
+
+/// A trait for a wallet that releases SEP-41 tokens to its beneficiary
+/// following a vesting schedule.
+///
+/// The beneficiary is the owner of the contract, so this trait is designed to
+/// be used in conjunction with the `stellar_ownable::Ownable` trait.
+pub trait VestingWallet {
+    /// Returns the vesting schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::ScheduleNotSet`] - If the schedule is not set.
+    fn vesting_schedule(e: &Env) -> VestingSchedule;
+
+    /// Returns the amount of `token` already released.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    fn released(e: &Env, token: Address) -> i128;
+
+    /// Returns the amount of `token` that is vested but not released yet, or
+    /// `0` if less is vested than already released.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::ScheduleNotSet`] - If the schedule is not set.
+    fn releasable(e: &Env, token: Address) -> i128;
+
+    /// Releases the vested amount of `token` to the beneficiary and returns
+    /// the released amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the SEP-41 token.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::ScheduleNotSet`] - If the schedule is not set.
+    /// * [`stellar_ownable::OwnableError::OwnerNotSet`] - If there is no
+    ///   beneficiary.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["released", token: Address]`
+    /// * data - `[beneficiary: Address, amount: i128]`
+    fn release(e: &Env, token: Address) -> i128;
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    /// The vesting schedule is not set.
+    ScheduleNotSet = 1500,
+    /// The vesting schedule is already set.
+    ScheduleAlreadySet = 1501,
+    /// The cliff of the vesting schedule is longer than its duration.
+    InvalidSchedule = 1502,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when vested tokens are released.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token` - The address of the released token.
+/// * `beneficiary` - The address receiving the tokens.
+/// * `amount` - The amount of tokens released.
+///
+/// # Events
+///
+/// * topics - `["released", token: Address]`
+/// * data - `[beneficiary: Address, amount: i128]`
+pub fn emit_released(e: &Env, token: &Address, beneficiary: &Address, amount: i128) {
+    let topics = (symbol_short!("released"), token);
+    e.events().publish(topics, (beneficiary.clone(), amount))
+}
@@ -2,7 +2,7 @@
 
 use std::collections::HashSet;
 
-use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, Map, Symbol, Val, Vec};
 
 pub struct EventAssertion<'a> {
     env: &'a Env,
@@ -72,6 +72,40 @@ impl<'a> EventAssertion<'a> {
         assert_eq!(event_amount, amount, "Transfer event has wrong amount");
     }
 
+    pub fn assert_fungible_transfer_muxed(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: u64,
+        amount: i128,
+    ) {
+        let transfer_event = self.find_event_by_symbol("transfer");
+
+        assert!(transfer_event.is_some(), "Transfer event not found in event log");
+
+        let (contract, topics, data) = transfer_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 3, "Transfer event should have 3 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, symbol_short!("transfer"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_to: Address = topics.get_unchecked(2).into_val(self.env);
+        let event_data: Map<Symbol, Val> = data.into_val(self.env);
+        let event_amount: i128 =
+            event_data.get_unchecked(Symbol::new(self.env, "amount")).into_val(self.env);
+        let event_to_muxed_id: u64 =
+            event_data.get_unchecked(Symbol::new(self.env, "to_muxed_id")).into_val(self.env);
+
+        assert_eq!(&event_from, from, "Transfer event has wrong from address");
+        assert_eq!(&event_to, to, "Transfer event has wrong to address");
+        assert_eq!(event_amount, amount, "Transfer event has wrong amount");
+        assert_eq!(event_to_muxed_id, to_muxed_id, "Transfer event has wrong muxed id");
+    }
+
     pub fn assert_non_fungible_transfer(&mut self, from: &Address, to: &Address, token_id: u32) {
         let transfer_event = self.find_event_by_symbol("transfer");
 
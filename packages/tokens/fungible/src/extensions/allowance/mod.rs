@@ -0,0 +1,148 @@
+//! # Allowance Extension for Fungible Token
+//!
+//! The `allowance` module provides `increase_allowance` and
+//! `decrease_allowance`, which change an existing allowance relatively instead
+//! of overwriting it, and an optional enumerable mode that keeps track of the
+//! spenders of every owner.
+//!
+//! ## Implementation Notes
+//!
+//! - **Front-running**: Changing an allowance with `approve` lets the spender
+//!   spend both the old and the new allowance if it front-runs the change.
+//!   `increase_allowance` and `decrease_allowance` only add to or remove from
+//!   what is left, and keep the `live_until_ledger` of the allowance.
+//! - **Enumeration**: [`EnumerableAllowances`] records the spenders of an owner
+//!   in the `after_approve` hook of [`crate::ContractOverrides`], so that
+//!   wallets can list ([`EnumerableAllowances::allowances`]) and revoke
+//!   ([`EnumerableAllowances::revoke_all`]) all the allowances of an owner. The
+//!   amounts are read from the existing [`crate::AllowanceData`] records, so
+//!   expired allowances are left out of the listing.
+//! - **Storage**: Every spender is stored in its own entry, indexed per owner,
+//!   and is removed as soon as its allowance reaches `0`, whether it is revoked
+//!   or fully spent. Listing and revoking are paginated, so their cost does not
+//!   grow with the number of spenders of an owner.
+//! - **Stacking**: [`EnumerableAllowances`] only overrides a hook, so it can be
+//!   combined with other extensions with [`crate::Stack`].
+//! - **Low-level allowances**: Allowances set with
+//!   [`crate::Base::set_allowance`] bypass the contract type and have to be
+//!   recorded with [`EnumerableAllowances::add_spender`].
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Vec};
+pub use storage::{EnumerableAllowances, EnumerableAllowancesStorageKey};
+
+use crate::{AllowanceData, FungibleToken};
+
+/// Allowance Trait for Fungible Token
+///
+/// The `FungibleAllowance` trait extends the `FungibleToken` trait to provide
+/// relative changes of allowances. This trait is designed to be used in
+/// conjunction with the `FungibleToken` trait.
+pub trait FungibleAllowance: FungibleToken {
+    /// Increases the allowance of `spender` on the tokens of `owner` by
+    /// `amount`, keeping its `live_until_ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens added to the allowance.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InvalidLiveUntilLedger`] - When the
+    ///   allowance does not exist or has expired.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`crate::FungibleTokenError::MathOverflow`] - When the new allowance
+    ///   overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    fn increase_allowance(e: &Env, owner: Address, spender: Address, amount: i128);
+
+    /// Decreases the allowance of `spender` on the tokens of `owner` by
+    /// `amount`, keeping its `live_until_ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens removed from the allowance.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientAllowance`] - When `amount`
+    ///   is greater than the current allowance.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    fn decrease_allowance(e: &Env, owner: Address, spender: Address, amount: i128);
+}
+
+/// Enumerable Allowances Trait for Fungible Token
+///
+/// The `FungibleEnumerableAllowances` trait extends the `FungibleToken` trait
+/// to list and revoke all the allowances of an owner. It requires
+/// [`EnumerableAllowances`] to be (part of) the `ContractType` of the
+/// `FungibleToken` implementation.
+pub trait FungibleEnumerableAllowances: FungibleToken {
+    /// Returns the number of recorded spenders of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    fn spender_count(e: &Env, owner: Address) -> u32;
+
+    /// Returns the spenders with a live, non-zero allowance among at most
+    /// `limit` recorded spenders of `owner` starting at index `start`,
+    /// together with their allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `start` - The index of the first spender to check.
+    /// * `limit` - The maximum number of spenders to check.
+    fn allowances(e: &Env, owner: Address, start: u32, limit: u32)
+        -> Vec<(Address, AllowanceData)>;
+
+    /// Sets the allowance of at most `limit` recorded spenders of `owner` to
+    /// `0`, and returns the number of spenders left.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `limit` - The maximum number of allowances to revoke.
+    ///
+    /// # Events
+    ///
+    /// For each revoked spender:
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    fn revoke_all(e: &Env, owner: Address, limit: u32) -> u32;
+}
@@ -0,0 +1,263 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    fungible::emit_approve,
+    overrides::{Base, ContractOverrides},
+    AllowanceData, AllowanceKey,
+};
+
+pub struct EnumerableAllowances;
+
+impl ContractOverrides for EnumerableAllowances {
+    fn after_approve(
+        e: &Env,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        _live_until_ledger: u32,
+    ) {
+        if amount > 0 {
+            EnumerableAllowances::add_spender(e, owner, spender);
+        } else {
+            EnumerableAllowances::remove_spender(e, owner, spender);
+        }
+    }
+}
+
+/// Storage key for the spender of an owner at an index
+#[contracttype]
+pub struct OwnerSpenderKey {
+    pub owner: Address,
+    pub index: u32,
+}
+
+/// Storage keys for the data associated with the enumerable allowances
+/// extension
+#[contracttype]
+pub enum EnumerableAllowancesStorageKey {
+    /// Stores the number of spenders an owner has granted an allowance to
+    SpenderCount(Address),
+    /// Stores the spender of an owner at an index
+    Spender(OwnerSpenderKey),
+    /// Stores the index of a spender among the spenders of an owner
+    SpenderIndex(AllowanceKey),
+}
+
+impl EnumerableAllowances {
+    // ################## QUERY STATE ##################
+
+    /// Returns the number of recorded spenders of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    pub fn spender_count(e: &Env, owner: &Address) -> u32 {
+        let key = EnumerableAllowancesStorageKey::SpenderCount(owner.clone());
+        if let Some(count) = e.storage().persistent().get::<_, u32>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            count
+        } else {
+            0
+        }
+    }
+
+    /// Returns at most `limit` recorded spenders of `owner`, starting at index
+    /// `start`. Use along with [`EnumerableAllowances::spender_count`] to
+    /// enumerate all the spenders of `owner`. The spenders may include ones
+    /// whose allowance has expired since it was granted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `start` - The index of the first spender to return.
+    /// * `limit` - The maximum number of spenders to return.
+    ///
+    /// # Notes
+    ///
+    /// Removing a spender moves the last spender of `owner` to its index, so
+    /// the order of the spenders is not preserved between pages.
+    pub fn spenders(e: &Env, owner: &Address, start: u32, limit: u32) -> Vec<Address> {
+        let end = EnumerableAllowances::spender_count(e, owner).min(start.saturating_add(limit));
+        let mut spenders = Vec::new(e);
+        for index in start..end {
+            spenders.push_back(EnumerableAllowances::spender_at(e, owner, index));
+        }
+        spenders
+    }
+
+    /// Returns the spenders with a live, non-zero allowance among at most
+    /// `limit` recorded spenders of `owner` starting at index `start`,
+    /// together with their [`AllowanceData`]. A page can therefore hold fewer
+    /// than `limit` entries even if more spenders follow.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `start` - The index of the first spender to check.
+    /// * `limit` - The maximum number of spenders to check.
+    pub fn allowances(
+        e: &Env,
+        owner: &Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(Address, AllowanceData)> {
+        let mut allowances = Vec::new(e);
+        for spender in EnumerableAllowances::spenders(e, owner, start, limit).iter() {
+            if Base::allowance(e, owner, &spender) > 0 {
+                let allowance = Base::allowance_data(e, owner, &spender);
+                allowances.push_back((spender, allowance));
+            }
+        }
+        allowances
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the allowance of at most `limit` recorded spenders of `owner` to
+    /// `0` and removes them from the spenders of `owner`. Returns the number
+    /// of spenders left, so that the remaining allowances can be revoked by
+    /// calling this function again until it returns `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `limit` - The maximum number of allowances to revoke.
+    ///
+    /// # Events
+    ///
+    /// For each revoked spender:
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    pub fn revoke_all(e: &Env, owner: &Address, limit: u32) -> u32 {
+        owner.require_auth();
+        let count = EnumerableAllowances::spender_count(e, owner);
+        let remaining = count.saturating_sub(limit);
+        // revoking from the end avoids moving the remaining spenders
+        for index in (remaining..count).rev() {
+            let spender = EnumerableAllowances::spender_at(e, owner, index);
+            Base::set_allowance(e, owner, &spender, 0, 0);
+            emit_approve(e, owner, &spender, 0, 0);
+            EnumerableAllowances::remove_spender(e, owner, &spender);
+        }
+        remaining
+    }
+
+    /// Low-level function that records `spender` as a spender of `owner`, if
+    /// it is not recorded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. It is called by the
+    /// `after_approve` hook, and should only be called directly for
+    /// allowances that are set without the contract type, e.g. with
+    /// [`Base::set_allowance`].
+    pub fn add_spender(e: &Env, owner: &Address, spender: &Address) {
+        let index_key = EnumerableAllowancesStorageKey::SpenderIndex(AllowanceKey {
+            owner: owner.clone(),
+            spender: spender.clone(),
+        });
+        if e.storage().persistent().has(&index_key) {
+            return;
+        }
+
+        let index = EnumerableAllowances::spender_count(e, owner);
+        e.storage().persistent().set(
+            &EnumerableAllowancesStorageKey::Spender(OwnerSpenderKey {
+                owner: owner.clone(),
+                index,
+            }),
+            spender,
+        );
+        e.storage().persistent().set(&index_key, &index);
+        e.storage()
+            .persistent()
+            .set(&EnumerableAllowancesStorageKey::SpenderCount(owner.clone()), &(index + 1));
+    }
+
+    /// Low-level function that removes `spender` from the spenders of
+    /// `owner` by moving the last spender of `owner` to its index. It does not
+    /// change the allowance itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn remove_spender(e: &Env, owner: &Address, spender: &Address) {
+        let index_key = EnumerableAllowancesStorageKey::SpenderIndex(AllowanceKey {
+            owner: owner.clone(),
+            spender: spender.clone(),
+        });
+        let Some(index) = e.storage().persistent().get::<_, u32>(&index_key) else {
+            return;
+        };
+        let last_index = EnumerableAllowances::spender_count(e, owner) - 1;
+
+        if index != last_index {
+            let last_spender = EnumerableAllowances::spender_at(e, owner, last_index);
+            e.storage().persistent().set(
+                &EnumerableAllowancesStorageKey::Spender(OwnerSpenderKey {
+                    owner: owner.clone(),
+                    index,
+                }),
+                &last_spender,
+            );
+            e.storage().persistent().set(
+                &EnumerableAllowancesStorageKey::SpenderIndex(AllowanceKey {
+                    owner: owner.clone(),
+                    spender: last_spender,
+                }),
+                &index,
+            );
+        }
+
+        e.storage().persistent().remove(&EnumerableAllowancesStorageKey::Spender(
+            OwnerSpenderKey { owner: owner.clone(), index: last_index },
+        ));
+        e.storage().persistent().remove(&index_key);
+        let count_key = EnumerableAllowancesStorageKey::SpenderCount(owner.clone());
+        if last_index == 0 {
+            e.storage().persistent().remove(&count_key);
+        } else {
+            e.storage().persistent().set(&count_key, &last_index);
+        }
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Returns the spender of `owner` at `index`.
+    fn spender_at(e: &Env, owner: &Address, index: u32) -> Address {
+        let key = EnumerableAllowancesStorageKey::Spender(OwnerSpenderKey {
+            owner: owner.clone(),
+            index,
+        });
+        let spender = e
+            .storage()
+            .persistent()
+            .get::<_, Address>(&key)
+            .expect("spenders are recorded for every index below the count");
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        spender
+    }
+}
@@ -0,0 +1,200 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    blocklist::BlockList, extensions::allowance::storage::EnumerableAllowances, Base,
+    ContractOverrides, Stack,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn approve_records_spenders() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::approve(&e, &owner, &spender1, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::approve(&e, &owner, &spender2, 20, 500);
+    });
+
+    e.as_contract(&address, || {
+        let allowances = EnumerableAllowances::allowances(&e, &owner, 0, 10);
+        assert_eq!(allowances.len(), 2);
+
+        let (spender, allowance) = allowances.get_unchecked(0);
+        assert_eq!(spender, spender1);
+        assert_eq!(allowance.amount, 50);
+        assert_eq!(allowance.live_until_ledger, 1000);
+
+        let (spender, allowance) = allowances.get_unchecked(1);
+        assert_eq!(spender, spender2);
+        assert_eq!(allowance.amount, 20);
+        assert_eq!(allowance.live_until_ledger, 500);
+    });
+}
+
+#[test]
+fn zero_allowance_removes_spender() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::decrease_allowance(&e, &owner, &spender, 50);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 0);
+    });
+}
+
+#[test]
+fn spent_allowance_removes_spender() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        EnumerableAllowances::approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::transfer_from(&e, &spender, &owner, &spender, 20);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 1);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::transfer_from(&e, &spender, &owner, &spender, 30);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 0);
+        assert_eq!(EnumerableAllowances::allowances(&e, &owner, 0, 10).len(), 0);
+    });
+}
+
+#[test]
+fn revoke_all_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::approve(&e, &owner, &spender1, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::increase_allowance(&e, &owner, &spender1, 10);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableAllowances::approve(&e, &owner, &spender2, 20, 500);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableAllowances::revoke_all(&e, &owner, 10), 0);
+        assert_eq!(Base::allowance(&e, &owner, &spender1), 0);
+        assert_eq!(Base::allowance(&e, &owner, &spender2), 0);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 0);
+
+        // the spenders are revoked from the last one
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_approve(&owner, &spender2, 0, 0);
+        event_assert.assert_fungible_approve(&owner, &spender1, 0, 0);
+    });
+}
+
+#[test]
+fn spenders_are_paginated_and_revoked_in_pages() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spenders: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&e)).collect();
+
+    for spender in &spenders {
+        e.as_contract(&address, || {
+            EnumerableAllowances::approve(&e, &owner, spender, 10, 1000);
+        });
+    }
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 5);
+        let page = EnumerableAllowances::spenders(&e, &owner, 3, 10);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get_unchecked(0), spenders[3]);
+        assert_eq!(page.get_unchecked(1), spenders[4]);
+        assert_eq!(EnumerableAllowances::allowances(&e, &owner, 1, 2).len(), 2);
+        assert_eq!(EnumerableAllowances::spenders(&e, &owner, 5, 10).len(), 0);
+    });
+
+    e.as_contract(&address, || {
+        // removing a spender moves the last one to its index
+        EnumerableAllowances::approve(&e, &owner, &spenders[1], 0, 0);
+        assert_eq!(EnumerableAllowances::spenders(&e, &owner, 1, 1).get_unchecked(0), spenders[4]);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableAllowances::revoke_all(&e, &owner, 3), 1);
+        assert_eq!(EnumerableAllowances::spenders(&e, &owner, 0, 10).get_unchecked(0), spenders[0]);
+        assert_eq!(Base::allowance(&e, &owner, &spenders[4]), 0);
+        assert_eq!(Base::allowance(&e, &owner, &spenders[0]), 10);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableAllowances::revoke_all(&e, &owner, 3), 0);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 0);
+        assert_eq!(Base::allowance(&e, &owner, &spenders[0]), 0);
+    });
+}
+
+#[test]
+fn stacked_contract_type_records_spenders() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Stack::<BlockList, EnumerableAllowances>::approve(&e, &owner, &spender, 50, 1000);
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn increase_allowance_runs_contract_type_checks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 1000);
+        BlockList::block_user(&e, &owner);
+    });
+
+    e.as_contract(&address, || {
+        Stack::<BlockList, EnumerableAllowances>::increase_allowance(&e, &owner, &spender, 10);
+    });
+}
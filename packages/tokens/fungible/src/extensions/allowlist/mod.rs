@@ -16,6 +16,11 @@ use crate::FungibleToken;
 /// the receiver are both allowed. Note that, spender does not have to be
 /// allowed.
 ///
+/// **IMPORTANT**: Minting to an address that is not allowed panics with
+/// [`crate::FungibleTokenError::UserNotAllowed`], whereas earlier versions of
+/// this extension only restricted transfers and approvals. Allow the receiver
+/// before minting to it.
+///
 /// This trait is designed to be used in conjunction with the `FungibleToken`
 /// trait.
 ///
@@ -29,7 +34,10 @@ use crate::FungibleToken;
 /// However, this parameter is omitted from the module functions, defined in
 /// "storage.rs", because the authorizations are to be handled in the access
 /// control helpers or directly implemented.
-pub trait FungibleAllowList: FungibleToken<ContractType = AllowList> {
+///
+/// The checks are enforced by [`AllowList`], so `ContractType` must be either
+/// [`AllowList`] or a [`crate::Stack`] that contains it.
+pub trait FungibleAllowList: FungibleToken {
     /// Returns the allowed status of an account.
     ///
     /// # Arguments
@@ -10,16 +10,34 @@ use crate::{
 pub struct AllowList;
 
 impl ContractOverrides for AllowList {
-    fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-        AllowList::transfer(e, from, to, amount);
+    fn check_transfer(e: &Env, from: &Address, to: &Address, _amount: i128) {
+        if !AllowList::allowed(e, from) || !AllowList::allowed(e, to) {
+            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
+        }
     }
 
-    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
-        AllowList::transfer_from(e, spender, from, to, amount);
+    fn check_approve(
+        e: &Env,
+        owner: &Address,
+        _spender: &Address,
+        _amount: i128,
+        _live_until_ledger: u32,
+    ) {
+        if !AllowList::allowed(e, owner) {
+            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
+        }
     }
 
-    fn approve(e: &Env, owner: &Address, spender: &Address, amount: i128, live_until_ledger: u32) {
-        AllowList::approve(e, owner, spender, amount, live_until_ledger);
+    fn check_burn(e: &Env, from: &Address, _amount: i128) {
+        if !AllowList::allowed(e, from) {
+            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
+        }
+    }
+
+    fn check_mint(e: &Env, to: &Address, _amount: i128) {
+        if !AllowList::allowed(e, to) {
+            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
+        }
     }
 }
 
@@ -138,9 +156,7 @@ impl AllowList {
     ///   not allowed.
     /// * Also refer to [`Base::transfer`] errors.
     pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-        if !AllowList::allowed(e, from) || !AllowList::allowed(e, to) {
-            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
-        }
+        AllowList::check_transfer(e, from, to, amount);
         Base::transfer(e, from, to, amount);
     }
 
@@ -163,10 +179,7 @@ impl AllowList {
     ///   is not allowed.
     /// * Also refer to [`Base::transfer_from`] errors.
     pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
-        if !AllowList::allowed(e, from) || !AllowList::allowed(e, to) {
-            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
-        }
-
+        AllowList::check_transfer(e, from, to, amount);
         Base::transfer_from(e, spender, from, to, amount);
     }
 
@@ -194,10 +207,7 @@ impl AllowList {
         amount: i128,
         live_until_ledger: u32,
     ) {
-        if !AllowList::allowed(e, owner) {
-            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
-        }
-
+        AllowList::check_approve(e, owner, spender, amount, live_until_ledger);
         Base::approve(e, owner, spender, amount, live_until_ledger);
     }
 
@@ -207,9 +217,7 @@ impl AllowList {
     ///
     /// Please refer to [`Base::burn`] for the inline documentation.
     pub fn burn(e: &Env, from: &Address, amount: i128) {
-        if !AllowList::allowed(e, from) {
-            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
-        }
+        AllowList::check_burn(e, from, amount);
         Base::burn(e, from, amount);
     }
 
@@ -219,9 +227,7 @@ impl AllowList {
     ///
     /// Please refer to [`Base::burn_from`] for the inline documentation.
     pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
-        if !AllowList::allowed(e, from) {
-            panic_with_error!(e, FungibleTokenError::UserNotAllowed);
-        }
+        AllowList::check_burn(e, from, amount);
         Base::burn_from(e, spender, from, amount);
     }
 }
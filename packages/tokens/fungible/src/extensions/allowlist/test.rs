@@ -2,7 +2,7 @@ extern crate std;
 
 use soroban_sdk::{contract, testutils::Address as _, Address, Env};
 
-use crate::{extensions::allowlist::storage::AllowList, Base};
+use crate::{extensions::allowlist::storage::AllowList, Base, ContractOverrides};
 
 #[contract]
 struct MockContract;
@@ -215,3 +215,15 @@ fn burn_from_with_not_allowed_panics() {
         AllowList::burn_from(&e, &user2, &user1, 50);
     });
 }
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn mint_to_not_allowed_user_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        AllowList::mint(&e, &user, 100);
+    });
+}
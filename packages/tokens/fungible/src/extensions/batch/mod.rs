@@ -0,0 +1,99 @@
+//! # Batch Extension for Fungible Token
+//!
+//! The `batch` module allows to transfer tokens to multiple recipients in a
+//! single invocation, e.g. for payrolls or reward distributions.
+//!
+//! ## Implementation Notes
+//!
+//! - **Authorization**: The sender (or the spender) authorizes the whole batch
+//!   once, and for `batch_transfer_from` the allowance is spent once for the
+//!   sum of the amounts.
+//! - **Validation**: The sum of the amounts is validated against the balance of
+//!   the sender before any balance is changed.
+//! - **Contract types**: Every recipient goes through `check_transfer` and
+//!   [`crate::ContractOverrides::update_transfer`] of the contract type passed
+//!   to the functions of [`Batch`], and gets its own `transfer` event, so the
+//!   batch is equivalent to a sequence of transfers (e.g. [`crate::fee::Fee`]
+//!   charges the fee on every recipient).
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Vec};
+pub use storage::Batch;
+
+use crate::FungibleToken;
+
+/// Batch Trait for Fungible Token
+///
+/// The `FungibleBatch` trait extends the `FungibleToken` trait to provide
+/// transfers to multiple recipients in a single invocation. This trait is
+/// designed to be used in conjunction with the `FungibleToken` trait.
+pub trait FungibleBatch: FungibleToken {
+    /// Transfers tokens from `from` to every recipient in `recipients`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientBalance`] - When the sum of
+    ///   the amounts is greater than the balance of `from`.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When any amount is
+    ///   negative.
+    /// * [`crate::FungibleTokenError::MathOverflow`] - When the sum of the
+    ///   amounts overflows.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn batch_transfer(e: &Env, from: Address, recipients: Vec<(Address, i128)>);
+
+    /// Transfers tokens from `from` to every recipient in `recipients` using
+    /// the allowance mechanism. The sum of the amounts is deducted from the
+    /// allowance of `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfers, and having its
+    ///   allowance consumed.
+    /// * `from` - The address holding the tokens.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientAllowance`] - When the sum of
+    ///   the amounts is greater than the allowance of `spender`.
+    /// * also refer to [`FungibleBatch::batch_transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        recipients: Vec<(Address, i128)>,
+    );
+}
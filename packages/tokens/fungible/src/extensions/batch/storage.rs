@@ -0,0 +1,161 @@
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::{
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct Batch;
+
+impl Batch {
+    // ################## QUERY STATE ##################
+
+    /// Returns the sum of the amounts in `recipients`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When any amount is negative.
+    /// * [`FungibleTokenError::MathOverflow`] - When the sum overflows.
+    pub fn total_amount(e: &Env, recipients: &Vec<(Address, i128)>) -> i128 {
+        let mut total: i128 = 0;
+        for (_, amount) in recipients.iter() {
+            if amount < 0 {
+                panic_with_error!(e, FungibleTokenError::LessThanZero);
+            }
+            let Some(sum) = total.checked_add(amount) else {
+                panic_with_error!(e, FungibleTokenError::MathOverflow);
+            };
+            total = sum;
+        }
+        total
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Transfers tokens from `from` to every recipient in `recipients` with
+    /// `check_transfer` and [`ContractOverrides::update`] of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Batch::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        recipients: &Vec<(Address, i128)>,
+    ) {
+        from.require_auth();
+        Batch::update::<T>(e, from, recipients, Batch::total_amount(e, recipients));
+    }
+
+    /// Transfers tokens from `from` to every recipient in `recipients` using
+    /// the allowance mechanism, with `check_transfer` and
+    /// [`ContractOverrides::update`] of `T`. The sum of the amounts is
+    /// deducted from the allowance of `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfers, and having its
+    ///   allowance consumed.
+    /// * `from` - The address holding the tokens.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::spend_allowance`] errors.
+    /// * refer to [`Batch::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from<T: ContractOverrides>(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        recipients: &Vec<(Address, i128)>,
+    ) {
+        spender.require_auth();
+        let total = Batch::total_amount(e, recipients);
+        T::spend_allowance(e, from, spender, total);
+        Batch::update::<T>(e, from, recipients, total);
+    }
+
+    /// Low-level function that moves tokens from `from` to every recipient in
+    /// `recipients` with `check_transfer` and
+    /// [`ContractOverrides::update_transfer`] of `T`, after validating `total`
+    /// against the balance of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `recipients` - The addresses receiving the tokens and the amounts they
+    ///   receive.
+    /// * `total` - The sum of the amounts, see [`Batch::total_amount`].
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InsufficientBalance`] - When `total` is greater
+    ///   than the balance of `from`.
+    /// * refer to [`ContractOverrides::update_transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient, the events of
+    /// [`ContractOverrides::update_transfer`] of `T`, by default:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        recipients: &Vec<(Address, i128)>,
+        total: i128,
+    ) {
+        if total > Base::balance(e, from) {
+            panic_with_error!(e, FungibleTokenError::InsufficientBalance);
+        }
+
+        for (to, amount) in recipients.iter() {
+            T::check_transfer(e, from, &to, amount);
+            T::update_transfer(e, from, &to, None, amount);
+        }
+    }
+}
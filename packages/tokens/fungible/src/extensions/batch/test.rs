@@ -0,0 +1,173 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{blocklist::BlockList, extensions::batch::storage::Batch, fee::Fee, Base};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to1 = Address::generate(&e);
+    let to2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to1.clone(), 30), (to2.clone(), 50)];
+        Batch::batch_transfer::<Base>(&e, &from, &recipients);
+
+        assert_eq!(Base::balance(&e, &from), 20);
+        assert_eq!(Base::balance(&e, &to1), 30);
+        assert_eq!(Base::balance(&e, &to2), 50);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_transfer(&from, &to1, 30);
+        event_assert.assert_fungible_transfer(&from, &to2, 50);
+    });
+}
+
+#[test]
+fn batch_transfer_charges_fee_on_every_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to1 = Address::generate(&e);
+    let to2 = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 1_000, &collector);
+        Base::mint(&e, &from, 1_000);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to1.clone(), 300), (to2.clone(), 500)];
+        Batch::batch_transfer::<Fee>(&e, &from, &recipients);
+
+        assert_eq!(Base::balance(&e, &from), 200);
+        assert_eq!(Base::balance(&e, &to1), 270);
+        assert_eq!(Base::balance(&e, &to2), 450);
+        assert_eq!(Base::balance(&e, &collector), 80);
+        assert_eq!(Base::total_supply(&e), 1_000);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        // for each recipient: transfer to recipient + transfer to collector + fee
+        event_assert.assert_event_count(6);
+    });
+}
+
+#[test]
+fn batch_transfer_from_spends_total_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let to1 = Address::generate(&e);
+    let to2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 60, 100);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to1.clone(), 10), (to2.clone(), 40)];
+        Batch::batch_transfer_from::<Base>(&e, &spender, &owner, &recipients);
+
+        assert_eq!(Base::allowance(&e, &owner, &spender), 10);
+        assert_eq!(Base::balance(&e, &owner), 50);
+        assert_eq!(Base::balance(&e, &to2), 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn batch_transfer_total_exceeds_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to.clone(), 60), (to.clone(), 41)];
+        Batch::batch_transfer::<Base>(&e, &from, &recipients);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn batch_transfer_from_insufficient_allowance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 50, 100);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to.clone(), 30), (to.clone(), 21)];
+        Batch::batch_transfer_from::<Base>(&e, &spender, &owner, &recipients);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn batch_transfer_negative_amount_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to.clone(), 60), (to.clone(), -10)];
+        Batch::batch_transfer::<Base>(&e, &from, &recipients);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn batch_transfer_runs_contract_type_checks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let blocked = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+        BlockList::block_user(&e, &blocked);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (to.clone(), 10), (blocked.clone(), 10)];
+        Batch::batch_transfer::<BlockList>(&e, &from, &recipients);
+    });
+}
@@ -16,6 +16,10 @@ use crate::FungibleToken;
 /// and the receiver are not blocked. Note that, this restriction does not
 /// apply to the spender.
 ///
+/// **IMPORTANT**: Minting to a blocked address panics with
+/// [`crate::FungibleTokenError::UserBlocked`], whereas earlier versions of
+/// this extension only restricted transfers and approvals.
+///
 /// This trait is designed to be used in conjunction with the `FungibleToken`
 /// trait.
 ///
@@ -29,7 +33,10 @@ use crate::FungibleToken;
 /// However, this parameter is omitted from the module functions, defined in
 /// "storage.rs", because the authorizations are to be handled in the access
 /// control helpers or directly implemented.
-pub trait FungibleBlockList: FungibleToken<ContractType = BlockList> {
+///
+/// The checks are enforced by [`BlockList`], so `ContractType` must be either
+/// [`BlockList`] or a [`crate::Stack`] that contains it.
+pub trait FungibleBlockList: FungibleToken {
     /// Returns the blocked status of an account.
     ///
     /// # Arguments
@@ -10,16 +10,34 @@ use crate::{
 pub struct BlockList;
 
 impl ContractOverrides for BlockList {
-    fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-        BlockList::transfer(e, from, to, amount);
+    fn check_transfer(e: &Env, from: &Address, to: &Address, _amount: i128) {
+        if BlockList::blocked(e, from) || BlockList::blocked(e, to) {
+            panic_with_error!(e, FungibleTokenError::UserBlocked);
+        }
+    }
+
+    fn check_approve(
+        e: &Env,
+        owner: &Address,
+        _spender: &Address,
+        _amount: i128,
+        _live_until_ledger: u32,
+    ) {
+        if BlockList::blocked(e, owner) {
+            panic_with_error!(e, FungibleTokenError::UserBlocked);
+        }
     }
 
-    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
-        BlockList::transfer_from(e, spender, from, to, amount);
+    fn check_burn(e: &Env, from: &Address, _amount: i128) {
+        if BlockList::blocked(e, from) {
+            panic_with_error!(e, FungibleTokenError::UserBlocked);
+        }
     }
 
-    fn approve(e: &Env, owner: &Address, spender: &Address, amount: i128, live_until_ledger: u32) {
-        BlockList::approve(e, owner, spender, amount, live_until_ledger);
+    fn check_mint(e: &Env, to: &Address, _amount: i128) {
+        if BlockList::blocked(e, to) {
+            panic_with_error!(e, FungibleTokenError::UserBlocked);
+        }
     }
 }
 
@@ -138,10 +156,7 @@ impl BlockList {
     ///   blocked.
     /// * Also refer to [`Base::transfer`] errors.
     pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-        if BlockList::blocked(e, from) || BlockList::blocked(e, to) {
-            panic_with_error!(e, FungibleTokenError::UserBlocked);
-        }
-
+        BlockList::check_transfer(e, from, to, amount);
         Base::transfer(e, from, to, amount);
     }
 
@@ -164,10 +179,7 @@ impl BlockList {
     ///   blocked.
     /// * Also refer to [`Base::transfer_from`] errors.
     pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
-        if BlockList::blocked(e, from) || BlockList::blocked(e, to) {
-            panic_with_error!(e, FungibleTokenError::UserBlocked);
-        }
-
+        BlockList::check_transfer(e, from, to, amount);
         Base::transfer_from(e, spender, from, to, amount);
     }
 
@@ -195,10 +207,7 @@ impl BlockList {
         amount: i128,
         live_until_ledger: u32,
     ) {
-        if BlockList::blocked(e, owner) {
-            panic_with_error!(e, FungibleTokenError::UserBlocked);
-        }
-
+        BlockList::check_approve(e, owner, spender, amount, live_until_ledger);
         Base::approve(e, owner, spender, amount, live_until_ledger);
     }
 
@@ -208,10 +217,7 @@ impl BlockList {
     ///
     /// Please refer to [`Base::burn`] for the inline documentation.
     pub fn burn(e: &Env, from: &Address, amount: i128) {
-        if BlockList::blocked(e, from) {
-            panic_with_error!(e, FungibleTokenError::UserBlocked);
-        }
-
+        BlockList::check_burn(e, from, amount);
         Base::burn(e, from, amount);
     }
 
@@ -221,10 +227,7 @@ impl BlockList {
     ///
     /// Please refer to [`Base::burn_from`] for the inline documentation.
     pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
-        if BlockList::blocked(e, from) {
-            panic_with_error!(e, FungibleTokenError::UserBlocked);
-        }
-
+        BlockList::check_burn(e, from, amount);
         Base::burn_from(e, spender, from, amount);
     }
 }
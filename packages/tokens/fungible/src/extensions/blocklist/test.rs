@@ -2,7 +2,7 @@ extern crate std;
 
 use soroban_sdk::{contract, testutils::Address as _, Address, Env};
 
-use crate::{extensions::blocklist::storage::BlockList, Base};
+use crate::{extensions::blocklist::storage::BlockList, Base, ContractOverrides};
 
 #[contract]
 struct MockContract;
@@ -212,3 +212,16 @@ fn burn_from_with_blocked_user_panics() {
         BlockList::burn_from(&e, &user2, &user1, 50);
     });
 }
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn mint_to_blocked_user_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        BlockList::block_user(&e, &user);
+        BlockList::mint(&e, &user, 100);
+    });
+}
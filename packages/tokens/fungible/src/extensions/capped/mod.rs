@@ -9,6 +9,58 @@
 /// - `query_cap`: Returns the maximum token supply.
 /// - `check_cap`: Panics if minting a specified `amount` would exceed the cap.
 ///   Should be used before calling `mint()`.
+///
+/// Alternatively, the `Capped` contract type runs `check_cap` on every `mint`
+/// of [`crate::ContractOverrides`].
+///
+/// The cap of a live token can be governed with the following helper
+/// functions, which are to be wrapped in functions with access control:
+/// - `update_cap`: Raises or lowers the cap, but never below the total supply.
+/// - `schedule_cap_increase`: Raises the cap from a given ledger on.
+/// - `remaining_supply`: Returns the amount of tokens that can still be minted.
+/// - `cap_at`: Returns the cap at a given ledger, from the recorded changes.
+/// - `scheduled_caps`: Returns the scheduled increases that are still pending.
 mod storage;
-pub use self::storage::{check_cap, query_cap, set_cap, CAP_KEY};
+use soroban_sdk::{Env, Symbol};
+
+pub use self::storage::{
+    cap_at, check_cap, query_cap, remaining_supply, schedule_cap_increase, scheduled_caps, set_cap,
+    update_cap, CapCheckpoint, Capped, CappedStorageKey, CAP_KEY,
+};
 mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event when the cap is updated.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `old_cap` - The previous maximum supply.
+/// * `new_cap` - The new maximum supply.
+///
+/// # Events
+///
+/// * topics - `["cap_updated"]`
+/// * data - `[old_cap: i128, new_cap: i128]`
+pub fn emit_cap_updated(e: &Env, old_cap: i128, new_cap: i128) {
+    let topics = (Symbol::new(e, "cap_updated"),);
+    e.events().publish(topics, (old_cap, new_cap))
+}
+
+/// Emits an event when a cap increase is scheduled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `ledger` - The ledger from which the new cap applies.
+/// * `cap` - The new maximum supply.
+///
+/// # Events
+///
+/// * topics - `["cap_scheduled"]`
+/// * data - `[ledger: u32, cap: i128]`
+pub fn emit_cap_scheduled(e: &Env, ledger: u32, cap: i128) {
+    let topics = (Symbol::new(e, "cap_scheduled"),);
+    e.events().publish(topics, (ledger, cap))
+}
@@ -1,10 +1,46 @@
-use soroban_sdk::{panic_with_error, symbol_short, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
 
-use crate::{FungibleTokenError, StorageKey};
+use crate::{
+    extensions::capped::{emit_cap_scheduled, emit_cap_updated},
+    overrides::ContractOverrides,
+    FungibleTokenError, StorageKey,
+};
 
 /// Storage key
 pub const CAP_KEY: Symbol = symbol_short!("CAP");
 
+/// Contract type that runs [`check_cap`] before every `mint`, so that the cap
+/// is enforced without calling [`check_cap`] manually. Mostly useful in
+/// combination with other contract types through [`crate::Stack`].
+pub struct Capped;
+
+impl ContractOverrides for Capped {
+    fn check_mint(e: &Env, _to: &Address, amount: i128) {
+        check_cap(e, amount);
+    }
+}
+
+/// A cap that applies from `ledger` on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapCheckpoint {
+    pub ledger: u32,
+    pub cap: i128,
+}
+
+/// Storage keys for the cap history and schedule
+#[contracttype]
+pub enum CappedStorageKey {
+    /// Stores the scheduled cap increases that were not moved to the history
+    /// yet, sorted by ledger
+    Schedule,
+    /// Stores the number of recorded cap changes
+    HistoryLen,
+    /// Stores a recorded cap change by index
+    History(u32),
+}
+
 /// Set the maximum supply of tokens.
 ///
 /// # Arguments
@@ -29,11 +65,97 @@ pub const CAP_KEY: Symbol = symbol_short!("CAP");
 ///   might decide to permanently reduce the token supply by burning tokens
 ///   later, and setting a lower cap ahead of time effectively prevents any
 ///   further minting until the total supply falls below the new cap.
+/// * To change the cap of a live token, prefer [`update_cap`], which also emits
+///   an event.
 pub fn set_cap(e: &Env, cap: i128) {
     if cap < 0 {
         panic_with_error!(e, FungibleTokenError::InvalidCap);
     }
-    e.storage().instance().set(&CAP_KEY, &cap);
+    write_cap(e, cap);
+}
+
+/// Raises or lowers the maximum supply of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `cap` - The new maximum supply.
+///
+/// # Errors
+///
+/// * refer to [`query_cap`] errors.
+/// * [`FungibleTokenError::InvalidCap`] - Occurs when the provided cap is
+///   negative.
+/// * [`FungibleTokenError::CapBelowSupply`] - Occurs when the provided cap is
+///   less than the current total supply.
+///
+/// # Events
+///
+/// * topics - `["cap_updated"]`
+/// * data - `[old_cap: i128, new_cap: i128]`
+///
+/// # Notes
+///
+/// Scheduled increases to a cap that is not greater than `cap` are cancelled.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should only
+/// be used in admin functions that implement their own authorization logic.
+pub fn update_cap(e: &Env, cap: i128) {
+    if cap < 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidCap);
+    }
+    let total_supply: i128 = e.storage().instance().get(&StorageKey::TotalSupply).unwrap_or(0);
+    if cap < total_supply {
+        panic_with_error!(e, FungibleTokenError::CapBelowSupply);
+    }
+    let old_cap = query_cap(e);
+    write_cap(e, cap);
+    emit_cap_updated(e, old_cap, cap);
+}
+
+/// Schedules an increase of the maximum supply of tokens to `cap`, taking
+/// effect at `ledger`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `ledger` - The ledger from which `cap` applies.
+/// * `cap` - The new maximum supply.
+///
+/// # Errors
+///
+/// * refer to [`query_cap`] errors.
+/// * [`FungibleTokenError::CapInvalidSchedule`] - Occurs when `ledger` is not
+///   after the current ledger and the ledger of the last scheduled increase, or
+///   when `cap` is not greater than the current cap and the cap of the last
+///   scheduled increase.
+///
+/// # Events
+///
+/// * topics - `["cap_scheduled"]`
+/// * data - `[ledger: u32, cap: i128]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should only
+/// be used:
+/// - During contract initialization/construction
+/// - In admin functions that implement their own authorization logic
+pub fn schedule_cap_increase(e: &Env, ledger: u32, cap: i128) {
+    prune_schedule(e);
+    let mut schedule = read_schedule(e);
+    let (last_ledger, last_cap) = match schedule.last() {
+        Some(last) => (last.ledger, last.cap),
+        None => (e.ledger().sequence(), query_cap(e)),
+    };
+    if ledger <= last_ledger || cap <= last_cap {
+        panic_with_error!(e, FungibleTokenError::CapInvalidSchedule);
+    }
+    schedule.push_back(CapCheckpoint { ledger, cap });
+    write_schedule(e, &schedule);
+    emit_cap_scheduled(e, ledger, cap);
 }
 
 /// Returns the maximum supply of tokens.
@@ -46,10 +168,96 @@ pub fn set_cap(e: &Env, cap: i128) {
 ///
 /// * [`FungibleTokenError::CapNotSet`] - Occurs when the cap has not been set.
 pub fn query_cap(e: &Env) -> i128 {
-    e.storage()
+    let current_ledger = e.ledger().sequence();
+    let mut cap = e
+        .storage()
         .instance()
         .get(&CAP_KEY)
-        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::CapNotSet))
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::CapNotSet));
+    for checkpoint in read_schedule(e).iter() {
+        if checkpoint.ledger > current_ledger {
+            break;
+        }
+        cap = checkpoint.cap;
+    }
+    cap
+}
+
+/// Returns the maximum supply of tokens at `ledger`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `ledger` - The ledger to query the cap at.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::CapNotSet`] - Occurs when the cap was not set at
+///   `ledger`.
+///
+/// # Notes
+///
+/// For ledgers in the future, only the currently scheduled increases are
+/// taken into account.
+pub fn cap_at(e: &Env, ledger: u32) -> i128 {
+    // scheduled increases always apply after the recorded cap changes
+    let mut scheduled = None;
+    for checkpoint in read_schedule(e).iter() {
+        if checkpoint.ledger > ledger {
+            break;
+        }
+        scheduled = Some(checkpoint.cap);
+    }
+    if let Some(cap) = scheduled {
+        return cap;
+    }
+
+    // binary search for the last recorded change at or before `ledger`
+    let (mut low, mut high) = (0, history_len(e));
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if history_at(e, mid).ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if low == 0 {
+        panic_with_error!(e, FungibleTokenError::CapNotSet);
+    }
+    history_at(e, low - 1).cap
+}
+
+/// Returns the scheduled cap increases that have not taken effect yet, sorted
+/// by ledger.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn scheduled_caps(e: &Env) -> Vec<CapCheckpoint> {
+    let current_ledger = e.ledger().sequence();
+    let mut pending = Vec::new(e);
+    for checkpoint in read_schedule(e).iter() {
+        if checkpoint.ledger > current_ledger {
+            pending.push_back(checkpoint);
+        }
+    }
+    pending
+}
+
+/// Returns the amount of tokens that can still be minted before reaching the
+/// cap, or `0` if the total supply is already at or above the cap.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * refer to [`query_cap`] errors.
+pub fn remaining_supply(e: &Env) -> i128 {
+    let total_supply: i128 = e.storage().instance().get(&StorageKey::TotalSupply).unwrap_or(0);
+    (query_cap(e) - total_supply).max(0)
 }
 
 /// Panics if new `amount` of tokens added to the current supply will exceed the
@@ -67,7 +275,13 @@ pub fn query_cap(e: &Env) -> i128 {
 ///   amount and the current total supply will overflow.
 /// * [`FungibleTokenError::ExceededCap`] - Occurs when the new amount of tokens
 ///   will exceed the cap.
+///
+/// # Notes
+///
+/// The scheduled increases that already took effect are moved to the recorded
+/// cap changes, so that the schedule only keeps the pending ones.
 pub fn check_cap(e: &Env, amount: i128) {
+    prune_schedule(e);
     let cap: i128 = query_cap(e);
     let total_supply: i128 = e.storage().instance().get(&StorageKey::TotalSupply).unwrap_or(0);
     let Some(sum) = total_supply.checked_add(amount) else {
@@ -77,3 +291,81 @@ pub fn check_cap(e: &Env, amount: i128) {
         panic_with_error!(e, FungibleTokenError::ExceededCap);
     }
 }
+
+// ################## HELPERS ##################
+
+/// Sets `cap` as the current cap: the scheduled increases that already took
+/// effect are moved to the history, the pending ones that do not raise `cap`
+/// are cancelled, and `cap` is recorded at the current ledger.
+fn write_cap(e: &Env, cap: i128) {
+    prune_schedule(e);
+    let mut schedule = Vec::new(e);
+    for checkpoint in read_schedule(e).iter() {
+        if checkpoint.cap > cap {
+            schedule.push_back(checkpoint);
+        }
+    }
+    write_schedule(e, &schedule);
+
+    e.storage().instance().set(&CAP_KEY, &cap);
+    record_history(e, &CapCheckpoint { ledger: e.ledger().sequence(), cap });
+}
+
+/// Moves the scheduled cap increases that already took effect to the
+/// history, and sets the last of them as the current cap.
+fn prune_schedule(e: &Env) {
+    let current_ledger = e.ledger().sequence();
+    let mut schedule = Vec::new(e);
+    let mut cap = None;
+    for checkpoint in read_schedule(e).iter() {
+        if checkpoint.ledger <= current_ledger {
+            record_history(e, &checkpoint);
+            cap = Some(checkpoint.cap);
+        } else {
+            schedule.push_back(checkpoint);
+        }
+    }
+    if let Some(cap) = cap {
+        e.storage().instance().set(&CAP_KEY, &cap);
+        write_schedule(e, &schedule);
+    }
+}
+
+/// Returns all the scheduled cap increases, including the ones that already
+/// took effect but were not moved to the history yet.
+fn read_schedule(e: &Env) -> Vec<CapCheckpoint> {
+    e.storage().instance().get(&CappedStorageKey::Schedule).unwrap_or_else(|| Vec::new(e))
+}
+
+/// Stores `schedule`, or removes it when it is empty.
+fn write_schedule(e: &Env, schedule: &Vec<CapCheckpoint>) {
+    if schedule.is_empty() {
+        e.storage().instance().remove(&CappedStorageKey::Schedule);
+    } else {
+        e.storage().instance().set(&CappedStorageKey::Schedule, schedule);
+    }
+}
+
+/// Returns the number of recorded cap changes.
+fn history_len(e: &Env) -> u32 {
+    e.storage().instance().get(&CappedStorageKey::HistoryLen).unwrap_or(0)
+}
+
+/// Returns the recorded cap change at `index`.
+fn history_at(e: &Env, index: u32) -> CapCheckpoint {
+    let key = CappedStorageKey::History(index);
+    let checkpoint = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("cap changes are recorded for every index below the length");
+    e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    checkpoint
+}
+
+/// Appends `checkpoint` to the recorded cap changes.
+fn record_history(e: &Env, checkpoint: &CapCheckpoint) {
+    let len = history_len(e);
+    e.storage().persistent().set(&CappedStorageKey::History(len), checkpoint);
+    e.storage().instance().set(&CappedStorageKey::HistoryLen, &(len + 1));
+}
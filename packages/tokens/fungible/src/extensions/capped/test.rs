@@ -2,11 +2,18 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, Vec,
+};
 
 use crate::{
-    extensions::capped::{check_cap, query_cap, set_cap},
-    Base,
+    extensions::capped::{
+        cap_at, check_cap, query_cap, remaining_supply, schedule_cap_increase, scheduled_caps,
+        set_cap, update_cap, CapCheckpoint, Capped, CappedStorageKey, CAP_KEY,
+    },
+    Base, ContractOverrides,
 };
 
 #[contract]
@@ -136,3 +143,194 @@ fn test_cap_not_set() {
         let _ = query_cap(&e);
     });
 }
+
+#[test]
+fn test_capped_contract_type_mint_under_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        Capped::mint(&e, &user, 1000);
+        assert_eq!(Base::balance(&e, &user), 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_capped_contract_type_mint_exceeds_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        Capped::mint(&e, &user, 600);
+        Capped::mint(&e, &user, 600);
+    });
+}
+
+#[test]
+fn test_update_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        Base::mint(&e, &user, 600);
+    });
+
+    e.as_contract(&contract_address, || {
+        update_cap(&e, 1500);
+        assert_eq!(query_cap(&e), 1500);
+        assert_eq!(remaining_supply(&e), 900);
+
+        update_cap(&e, 600);
+        assert_eq!(query_cap(&e), 600);
+        assert_eq!(remaining_supply(&e), 0);
+        assert_eq!(e.events().all().len(), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #135)")]
+fn test_update_cap_below_supply() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        Base::mint(&e, &user, 600);
+        update_cap(&e, 599);
+    });
+}
+
+#[test]
+fn test_scheduled_cap_increase() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        schedule_cap_increase(&e, 100, 2000);
+        schedule_cap_increase(&e, 200, 3000);
+        assert_eq!(scheduled_caps(&e).len(), 2);
+    });
+
+    e.ledger().set_sequence_number(150);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(query_cap(&e), 2000);
+        assert_eq!(scheduled_caps(&e), vec![&e, CapCheckpoint { ledger: 200, cap: 3000 }]);
+        Capped::mint(&e, &user, 2000);
+    });
+}
+
+#[test]
+fn test_check_cap_moves_effective_increases_to_history() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        schedule_cap_increase(&e, 100, 2000);
+        schedule_cap_increase(&e, 200, 3000);
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&contract_address, || {
+        check_cap(&e, 1);
+
+        let schedule: Vec<CapCheckpoint> =
+            e.storage().instance().get(&CappedStorageKey::Schedule).unwrap();
+        assert_eq!(schedule, vec![&e, CapCheckpoint { ledger: 200, cap: 3000 }]);
+        assert_eq!(e.storage().instance().get::<_, i128>(&CAP_KEY), Some(2000));
+        assert_eq!(query_cap(&e), 2000);
+        assert_eq!(cap_at(&e, 99), 1000);
+        assert_eq!(cap_at(&e, 100), 2000);
+    });
+
+    e.ledger().set_sequence_number(250);
+    e.as_contract(&contract_address, || {
+        schedule_cap_increase(&e, 300, 4000);
+
+        let schedule: Vec<CapCheckpoint> =
+            e.storage().instance().get(&CappedStorageKey::Schedule).unwrap();
+        assert_eq!(schedule, vec![&e, CapCheckpoint { ledger: 300, cap: 4000 }]);
+        assert_eq!(cap_at(&e, 200), 3000);
+    });
+}
+
+#[test]
+fn test_update_cap_cancels_lower_scheduled_increases() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        schedule_cap_increase(&e, 100, 2000);
+        schedule_cap_increase(&e, 200, 3000);
+    });
+
+    e.as_contract(&contract_address, || {
+        update_cap(&e, 2500);
+        assert_eq!(scheduled_caps(&e), vec![&e, CapCheckpoint { ledger: 200, cap: 3000 }]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #136)")]
+fn test_schedule_cap_decrease_fails() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        schedule_cap_increase(&e, 100, 2000);
+        schedule_cap_increase(&e, 200, 1500);
+    });
+}
+
+#[test]
+fn test_cap_at() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        schedule_cap_increase(&e, 50, 2000);
+    });
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&contract_address, || {
+        update_cap(&e, 5000);
+    });
+
+    e.ledger().set_sequence_number(120);
+    e.as_contract(&contract_address, || {
+        assert_eq!(cap_at(&e, 10), 1000);
+        assert_eq!(cap_at(&e, 49), 1000);
+        assert_eq!(cap_at(&e, 50), 2000);
+        assert_eq!(cap_at(&e, 99), 2000);
+        assert_eq!(cap_at(&e, 100), 5000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")]
+fn test_cap_at_before_set() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        let _ = cap_at(&e, 9);
+    });
+}
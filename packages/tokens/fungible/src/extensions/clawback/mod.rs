@@ -0,0 +1,136 @@
+//! # Clawback Extension for Fungible Token
+//!
+//! The `clawback` module brings the clawback capability of Stellar Asset
+//! Contracts (see [`crate::sac_admin_wrapper`]) to custom fungible tokens: an
+//! authorized account can burn tokens of any account (`clawback`) or move them
+//! to another account (`forced_transfer`) without the consent of the holder.
+//!
+//! ## Implementation Notes
+//!
+//! - **Checks**: The `check_*` functions of the contract type are skipped, so
+//!   tokens can be seized from accounts that are blocked
+//!   ([`crate::blocklist`]), not allowed ([`crate::allowlist`]) or whose tokens
+//!   are frozen ([`crate::freezable`]).
+//! - **Update hooks**: The balances are changed with
+//!   [`crate::ContractOverrides::update`] of the contract type passed to the
+//!   functions of [`Clawback`], so extensions that keep track of balance
+//!   changes (e.g. [`crate::votes::Votes`]) stay in sync.
+//! - **Restricted tokens**: Extensions that restrict a part of the balance
+//!   adjust it in their `after_update` hook when the balance drops below it:
+//!   [`crate::freezable::Freezable`] unfreezes the excess and
+//!   [`crate::partition::Partition`] takes the excess out of the partitions of
+//!   the account. They only do so if they are part of the contract type passed
+//!   to [`Clawback`], e.g. `Stack<Freezable, Partition>`.
+//! - **Events**: Dedicated `clawback` and `forced_transfer` events are emitted
+//!   instead of the `burn` and `transfer` events, so that they can be told
+//!   apart from the actions of the holder.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+pub use storage::Clawback;
+
+use crate::FungibleToken;
+
+/// Clawback Trait for Fungible Token
+///
+/// The `FungibleClawback` trait extends the `FungibleToken` trait to provide
+/// clawbacks and forced transfers that can be performed by an authorized
+/// account. This trait is designed to be used in conjunction with the
+/// `FungibleToken` trait.
+///
+/// **NOTE**
+///
+/// All functions, exposed in the `FungibleClawback` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+pub trait FungibleClawback: FungibleToken {
+    /// Burns `amount` of tokens of `from` without its authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `amount` - The amount of tokens to claw back.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientBalance`] - When `from` does
+    ///   not have enough tokens.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["clawback", from: Address]`
+    /// * data - `[amount: i128]`
+    fn clawback(e: &Env, from: Address, amount: i128, operator: Address);
+
+    /// Transfers `amount` of tokens from `from` to `to` without the
+    /// authorization of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens to transfer.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientBalance`] - When `from` does
+    ///   not have enough tokens.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["forced_transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    fn forced_transfer(e: &Env, from: Address, to: Address, amount: i128, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when tokens are clawed back.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address whose tokens are clawed back.
+/// * `amount` - The amount of tokens clawed back.
+///
+/// # Events
+///
+/// * topics - `["clawback", from: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_clawback(e: &Env, from: &Address, amount: i128) {
+    let topics = (symbol_short!("clawback"), from);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when tokens are transferred without the authorization of
+/// their holder.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address whose tokens are transferred.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens transferred.
+///
+/// # Events
+///
+/// * topics - `["forced_transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_forced_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "forced_transfer"), from, to);
+    e.events().publish(topics, amount)
+}
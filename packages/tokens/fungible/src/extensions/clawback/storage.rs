@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    extensions::clawback::{emit_clawback, emit_forced_transfer},
+    ContractOverrides,
+};
+
+pub struct Clawback;
+
+impl Clawback {
+    // ################## CHANGE STATE ##################
+
+    /// Burns `amount` of tokens of `from` with [`ContractOverrides::update`]
+    /// of `T`, skipping the `check_burn` of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `amount` - The amount of tokens to claw back.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["clawback", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can claw back
+    /// tokens.
+    pub fn clawback<T: ContractOverrides>(e: &Env, from: &Address, amount: i128) {
+        T::update(e, Some(from), None, amount);
+        emit_clawback(e, from, amount);
+    }
+
+    /// Transfers `amount` of tokens from `from` to `to` with
+    /// [`ContractOverrides::update`] of `T`, skipping the `check_transfer` of
+    /// `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["forced_transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can force
+    /// transfers.
+    pub fn forced_transfer<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) {
+        T::update(e, Some(from), Some(to), amount);
+        emit_forced_transfer(e, from, to, amount);
+    }
+}
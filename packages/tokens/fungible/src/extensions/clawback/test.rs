@@ -0,0 +1,128 @@
+extern crate std;
+
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    blocklist::BlockList, extensions::clawback::storage::Clawback, freezable::Freezable,
+    partition::Partition, votes::Votes, Base, Stack,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn clawback_burns_tokens() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+    });
+
+    e.as_contract(&address, || {
+        Clawback::clawback::<Base>(&e, &account, 40);
+        assert_eq!(Base::balance(&e, &account), 60);
+        assert_eq!(Base::total_supply(&e), 60);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+fn forced_transfer_from_blocked_account() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let blocked = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &blocked, 100);
+        BlockList::block_user(&e, &blocked);
+    });
+
+    e.as_contract(&address, || {
+        Clawback::forced_transfer::<BlockList>(&e, &blocked, &treasury, 100);
+        assert_eq!(Base::balance(&e, &blocked), 0);
+        assert_eq!(Base::balance(&e, &treasury), 100);
+    });
+}
+
+#[test]
+fn clawback_unfreezes_excess() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Freezable::freeze_partial(&e, &account, 80);
+    });
+
+    e.as_contract(&address, || {
+        Clawback::clawback::<Freezable>(&e, &account, 50);
+        assert_eq!(Base::balance(&e, &account), 50);
+        assert_eq!(Freezable::frozen_balance(&e, &account), 50);
+        assert_eq!(Freezable::available_balance(&e, &account), 0);
+    });
+}
+
+#[test]
+fn clawback_takes_excess_out_of_partitions() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let locked = symbol_short!("locked");
+    let reg_s = symbol_short!("reg_s");
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &account, &locked, 50);
+        Partition::issue_by_partition::<Partition>(&e, &account, &reg_s, 30);
+        Base::mint(&e, &account, 20);
+    });
+
+    e.as_contract(&address, || {
+        Clawback::forced_transfer::<Stack<Freezable, Partition>>(&e, &account, &treasury, 70);
+        assert_eq!(Base::balance(&e, &account), 30);
+        // the unpartitioned tokens are taken first, then the partitions in order
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &locked), 0);
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &reg_s), 30);
+        assert_eq!(Partition::unpartitioned_balance(&e, &account), 0);
+        assert_eq!(Partition::partitions_of(&e, &account).len(), 1);
+    });
+}
+
+#[test]
+fn forced_transfer_runs_update_hooks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::delegate(&e, &from, &from);
+    });
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &from, 100);
+        Clawback::forced_transfer::<Stack<BlockList, Votes>>(&e, &from, &to, 30);
+        assert_eq!(Votes::get_votes(&e, &from), 70);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn clawback_more_than_balance_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Clawback::clawback::<Base>(&e, &account, 101);
+    });
+}
@@ -0,0 +1,188 @@
+//! # Dividend Extension for Fungible Token
+//!
+//! The `dividend` module distributes deposits of SEP-41 tokens (reward tokens)
+//! to the holders of the token, pro rata to their balances, similar to
+//! ERC-2222. Holders claim their share whenever they want, so distributions
+//! do not need to snapshot the balances or to push payouts to every holder.
+//!
+//! ## Implementation Notes
+//!
+//! - **Accumulator**: Every reward token has an accumulator of the amount
+//!   distributed per token held, magnified by `2^96` to limit the rounding
+//!   errors. A distribution raises the accumulator by `amount * 2^96 /
+//!   total_supply`, so its cost does not depend on the number of holders.
+//! - **Corrections**: Every account has a correction per reward token, which
+//!   compensates the changes of its balance in the `before_update` hook of
+//!   [`Dividend`], so that tokens received after a distribution do not earn
+//!   from it, and tokens sent away keep what they have earned before.
+//! - **Reward tokens**: Reward tokens have to be registered with
+//!   [`Dividend::add_reward_token`] before they can be distributed. Every
+//!   registered reward token adds the update of its corrections to every
+//!   transfer, mint and burn, so their number should be kept small.
+//! - **Rounding**: The accumulator and the dividends are rounded down, so the
+//!   contract might keep some dust of the distributed reward tokens.
+//!
+//! ## Usage
+//!
+//! - **IMPORTANT**: Every change of balances must go through the functions of
+//!   [`crate::ContractOverrides`] for a contract type that includes
+//!   [`Dividend`] (e.g. [`Dividend::mint`] instead of [`crate::Base::mint`]) or
+//!   must be preceded by a call to [`Dividend::update_corrections`], otherwise
+//!   the dividends will be wrong.
+//! - [`Dividend::add_reward_token`] does not enforce authorization, so it has
+//!   to be exposed by the contract with its own access control.
+//! - The balances of the contract are not part of the reward tokens: a reward
+//!   token must not be the token itself.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+pub use storage::{Dividend, DividendStorageKey};
+
+use crate::FungibleToken;
+
+/// Dividend Trait for Fungible Token
+///
+/// The `FungibleDividend` trait extends the `FungibleToken` trait to
+/// distribute deposits of reward tokens to the holders pro rata to their
+/// balances. This trait is designed to be used in conjunction with the
+/// `FungibleToken` trait.
+///
+/// The corrections are updated by [`Dividend`], so `ContractType` must be
+/// either [`Dividend`] or a [`crate::Stack`] that contains it.
+pub trait FungibleDividend: FungibleToken {
+    /// Returns the registered reward tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn reward_tokens(e: &Env) -> Vec<Address>;
+
+    /// Returns the amount of `reward_token` that `account` can claim.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    fn withdrawable_dividend(e: &Env, reward_token: Address, account: Address) -> i128;
+
+    /// Returns the amount of `reward_token` that `account` has claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    fn withdrawn_dividend(e: &Env, reward_token: Address, account: Address) -> i128;
+
+    /// Transfers `amount` of `reward_token` from `from` to the contract and
+    /// distributes it to the holders pro rata to their balances.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `from` - The address paying the dividends.
+    /// * `amount` - The amount of `reward_token` to distribute.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::DividendUnknownRewardToken`] - When
+    ///   `reward_token` is not registered.
+    /// * [`crate::FungibleTokenError::DividendNoSupply`] - When the total
+    ///   supply is zero.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["dividends_distributed", reward_token: Address, from:
+    ///   Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn distribute(e: &Env, reward_token: Address, from: Address, amount: i128);
+
+    /// Transfers the amount of `reward_token` that `account` can claim to
+    /// `account`, and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::DividendUnknownRewardToken`] - When
+    ///   `reward_token` is not registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["dividend_withdrawn", reward_token: Address, account:
+    ///   Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `account` is required.
+    fn claim(e: &Env, reward_token: Address, account: Address) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a reward token is registered.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `reward_token` - The address of the reward token.
+///
+/// # Events
+///
+/// * topics - `["reward_token_added", reward_token: Address]`
+/// * data - `[]`
+pub fn emit_reward_token_added(e: &Env, reward_token: &Address) {
+    let topics = (Symbol::new(e, "reward_token_added"), reward_token);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when dividends are distributed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `reward_token` - The address of the reward token.
+/// * `from` - The address paying the dividends.
+/// * `amount` - The amount of `reward_token` distributed.
+///
+/// # Events
+///
+/// * topics - `["dividends_distributed", reward_token: Address, from: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_dividends_distributed(e: &Env, reward_token: &Address, from: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "dividends_distributed"), reward_token, from);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when dividends are claimed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `reward_token` - The address of the reward token.
+/// * `account` - The address of the holder.
+/// * `amount` - The amount of `reward_token` claimed.
+///
+/// # Events
+///
+/// * topics - `["dividend_withdrawn", reward_token: Address, account: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_dividend_withdrawn(e: &Env, reward_token: &Address, account: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "dividend_withdrawn"), reward_token, account);
+    e.events().publish(topics, amount)
+}
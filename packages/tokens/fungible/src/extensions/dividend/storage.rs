@@ -0,0 +1,351 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env, Vec, I256};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{
+    extensions::dividend::{
+        emit_dividend_withdrawn, emit_dividends_distributed, emit_reward_token_added,
+    },
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+/// The accumulators are magnified by `2^MAGNITUDE_BITS`.
+const MAGNITUDE_BITS: u32 = 96;
+
+pub struct Dividend;
+
+impl ContractOverrides for Dividend {
+    fn before_update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        Dividend::update_corrections(e, from, to, amount);
+    }
+}
+
+/// Storage keys for the data associated with the dividend extension
+#[contracttype]
+pub enum DividendStorageKey {
+    /// Stores the registered reward tokens
+    RewardTokens,
+    /// Stores the magnified amount of a reward token distributed per token
+    MagnifiedPerShare(Address /* reward_token */),
+    /// Stores the magnified correction of an account for a reward token
+    Correction(Address /* reward_token */, Address /* account */),
+    /// Stores the amount of a reward token claimed by an account
+    Withdrawn(Address /* reward_token */, Address /* account */),
+}
+
+impl Dividend {
+    // ################## QUERY STATE ##################
+
+    /// Returns the registered reward tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn reward_tokens(e: &Env) -> Vec<Address> {
+        e.storage().instance().get(&DividendStorageKey::RewardTokens).unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Returns the total amount of `reward_token` earned by `account`,
+    /// including the amount it has already claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MathOverflow`] - When the amount does not fit in
+    ///   an `i128`.
+    pub fn accumulative_dividend(e: &Env, reward_token: &Address, account: &Address) -> i128 {
+        let per_share =
+            get_magnified(e, &DividendStorageKey::MagnifiedPerShare(reward_token.clone()));
+        let correction = get_magnified(
+            e,
+            &DividendStorageKey::Correction(reward_token.clone(), account.clone()),
+        );
+        let magnified =
+            per_share.mul(&I256::from_i128(e, Base::balance(e, account))).add(&correction);
+        magnified
+            .shr(MAGNITUDE_BITS)
+            .to_i128()
+            .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+    }
+
+    /// Returns the amount of `reward_token` that `account` has claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    pub fn withdrawn_dividend(e: &Env, reward_token: &Address, account: &Address) -> i128 {
+        let key = DividendStorageKey::Withdrawn(reward_token.clone(), account.clone());
+        if let Some(withdrawn) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_EXTEND_AMOUNT,
+            );
+            withdrawn
+        } else {
+            0
+        }
+    }
+
+    /// Returns the amount of `reward_token` that `account` can claim.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Dividend::accumulative_dividend`] errors.
+    pub fn withdrawable_dividend(e: &Env, reward_token: &Address, account: &Address) -> i128 {
+        Dividend::accumulative_dividend(e, reward_token, account)
+            - Dividend::withdrawn_dividend(e, reward_token, account)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Registers `reward_token`, so that it can be distributed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::DividendRewardTokenExists`] - When
+    ///   `reward_token` is already registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["reward_token_added", reward_token: Address]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can register
+    /// reward tokens. As every reward token makes transfers more expensive,
+    /// failure to do so lets anyone make transfers exceed the resource
+    /// limits.
+    pub fn add_reward_token(e: &Env, reward_token: &Address) {
+        let mut reward_tokens = Dividend::reward_tokens(e);
+        if reward_tokens.contains(reward_token) {
+            panic_with_error!(e, FungibleTokenError::DividendRewardTokenExists);
+        }
+        reward_tokens.push_back(reward_token.clone());
+        e.storage().instance().set(&DividendStorageKey::RewardTokens, &reward_tokens);
+        emit_reward_token_added(e, reward_token);
+    }
+
+    /// Transfers `amount` of `reward_token` from `from` to the contract and
+    /// distributes it to the holders pro rata to their balances.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `from` - The address paying the dividends.
+    /// * `amount` - The amount of `reward_token` to distribute.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::DividendUnknownRewardToken`] - When
+    ///   `reward_token` is not registered.
+    /// * [`FungibleTokenError::DividendNoSupply`] - When the total supply is
+    ///   zero.
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["dividends_distributed", reward_token: Address, from:
+    ///   Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn distribute(e: &Env, reward_token: &Address, from: &Address, amount: i128) {
+        from.require_auth();
+        check_reward_token(e, reward_token);
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        let total_supply = Base::total_supply(e);
+        if total_supply == 0 {
+            panic_with_error!(e, FungibleTokenError::DividendNoSupply);
+        }
+
+        TokenClient::new(e, reward_token).transfer(from, &e.current_contract_address(), &amount);
+
+        let key = DividendStorageKey::MagnifiedPerShare(reward_token.clone());
+        let increase =
+            I256::from_i128(e, amount).shl(MAGNITUDE_BITS).div(&I256::from_i128(e, total_supply));
+        let per_share = get_magnified(e, &key).add(&increase);
+        e.storage().persistent().set(&key, &per_share);
+
+        emit_dividends_distributed(e, reward_token, from, amount);
+    }
+
+    /// Transfers the amount of `reward_token` that `account` can claim to
+    /// `account`, and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `reward_token` - The address of the reward token.
+    /// * `account` - The address of the holder.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::DividendUnknownRewardToken`] - When
+    ///   `reward_token` is not registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["dividend_withdrawn", reward_token: Address, account:
+    ///   Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `account` is required.
+    pub fn claim(e: &Env, reward_token: &Address, account: &Address) -> i128 {
+        account.require_auth();
+        check_reward_token(e, reward_token);
+
+        let amount = Dividend::withdrawable_dividend(e, reward_token, account);
+        if amount > 0 {
+            let withdrawn = Dividend::withdrawn_dividend(e, reward_token, account) + amount;
+            e.storage().persistent().set(
+                &DividendStorageKey::Withdrawn(reward_token.clone(), account.clone()),
+                &withdrawn,
+            );
+            TokenClient::new(e, reward_token).transfer(
+                &e.current_contract_address(),
+                account,
+                &amount,
+            );
+            emit_dividend_withdrawn(e, reward_token, account, amount);
+        }
+        amount
+    }
+
+    /// Updates the corrections of `from` and `to` for every reward token, so
+    /// that a balance change of `amount` does not change their dividends.
+    ///
+    /// Called by the `before_update` hook of [`Dividend`]. Must be called
+    /// before every balance change that does not go through
+    /// [`ContractOverrides::update`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address whose balance decreases, or `None` for mints.
+    /// * `to` - The address whose balance increases, or `None` for burns.
+    /// * `amount` - The amount of tokens moved.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update_corrections(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        for reward_token in Dividend::reward_tokens(e).iter() {
+            let per_share =
+                get_magnified(e, &DividendStorageKey::MagnifiedPerShare(reward_token.clone()));
+            let delta = per_share.mul(&I256::from_i128(e, amount));
+            if delta == I256::from_i128(e, 0) {
+                continue;
+            }
+
+            if let Some(from) = from {
+                let key = DividendStorageKey::Correction(reward_token.clone(), from.clone());
+                let correction = get_magnified(e, &key).add(&delta);
+                e.storage().persistent().set(&key, &correction);
+            }
+            if let Some(to) = to {
+                let key = DividendStorageKey::Correction(reward_token.clone(), to.clone());
+                let correction = get_magnified(e, &key).sub(&delta);
+                e.storage().persistent().set(&key, &correction);
+            }
+        }
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// This is a wrapper around [`Base::transfer()`] that also updates the
+    /// corrections of `from` and `to`.
+    ///
+    /// Please refer to [`Base::transfer`] for the inline documentation.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        <Dividend as ContractOverrides>::transfer(e, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::transfer_from()`] that also updates
+    /// the corrections of `from` and `to`.
+    ///
+    /// Please refer to [`Base::transfer_from`] for the inline documentation.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        <Dividend as ContractOverrides>::transfer_from(e, spender, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::mint()`] that also updates the
+    /// correction of `to`.
+    ///
+    /// Please refer to [`Base::mint`] for the inline documentation.
+    pub fn mint(e: &Env, to: &Address, amount: i128) {
+        <Dividend as ContractOverrides>::mint(e, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::dividend::FungibleDividend`]
+    ///
+    /// Please refer to [`Base::burn`] for the inline documentation.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        <Dividend as ContractOverrides>::burn(e, from, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn_from()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::dividend::FungibleDividend`]
+    ///
+    /// Please refer to [`Base::burn_from`] for the inline documentation.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        <Dividend as ContractOverrides>::burn_from(e, spender, from, amount);
+    }
+}
+
+// ################## HELPERS ##################
+
+/// Panics if `reward_token` is not registered.
+fn check_reward_token(e: &Env, reward_token: &Address) {
+    if !Dividend::reward_tokens(e).contains(reward_token) {
+        panic_with_error!(e, FungibleTokenError::DividendUnknownRewardToken);
+    }
+}
+
+/// Returns the magnified value stored under `key`, extending its TTL if
+/// present, or `0`.
+fn get_magnified(e: &Env, key: &DividendStorageKey) -> I256 {
+    if let Some(value) = e.storage().persistent().get::<_, I256>(key) {
+        e.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        value
+    } else {
+        I256::from_i128(e, 0)
+    }
+}
@@ -0,0 +1,184 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::extensions::dividend::storage::Dividend;
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, payer: &Address, amount: i128) -> (Address, Address) {
+    let reward_token = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    StellarAssetClient::new(e, &reward_token).mint(payer, &amount);
+    let token = e.register(MockContract, ());
+    e.as_contract(&token, || Dividend::add_reward_token(e, &reward_token));
+    (token, reward_token)
+}
+
+#[test]
+fn distribute_pro_rata() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        assert_eq!(Dividend::reward_tokens(&e), vec![&e, reward_token.clone()]);
+        Dividend::mint(&e, &alice, 300);
+        Dividend::mint(&e, &bob, 100);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 400);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &alice), 300);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &bob), 100);
+    });
+    assert_eq!(TokenClient::new(&e, &reward_token).balance(&token), 400);
+}
+
+#[test]
+fn balance_changes_after_distribution_keep_dividends() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        Dividend::mint(&e, &alice, 100);
+        Dividend::mint(&e, &bob, 100);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 200);
+    });
+
+    e.as_contract(&token, || {
+        // the dividends earned before the transfer stay with alice
+        Dividend::transfer(&e, &alice, &bob, 50);
+        // tokens minted after the distribution do not earn from it
+        Dividend::mint(&e, &carol, 200);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &alice), 100);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &bob), 100);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &carol), 0);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 400);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &alice), 150);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &bob), 250);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &carol), 200);
+    });
+
+    e.as_contract(&token, || {
+        // burning keeps the earned dividends as well
+        Dividend::burn(&e, &carol, 200);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &carol), 200);
+    });
+}
+
+#[test]
+fn claim_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        Dividend::mint(&e, &alice, 100);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 300);
+    });
+
+    e.as_contract(&token, || {
+        assert_eq!(Dividend::claim(&e, &reward_token, &alice), 300);
+        assert_eq!(Dividend::withdrawn_dividend(&e, &reward_token, &alice), 300);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &alice), 0);
+    });
+
+    e.as_contract(&token, || {
+        // nothing left to claim
+        assert_eq!(Dividend::claim(&e, &reward_token, &alice), 0);
+    });
+    assert_eq!(TokenClient::new(&e, &reward_token).balance(&alice), 300);
+}
+
+#[test]
+fn rounding_keeps_dust_in_contract() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        Dividend::mint(&e, &alice, 1);
+        Dividend::mint(&e, &bob, 1);
+        Dividend::mint(&e, &carol, 1);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 100);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &alice), 33);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &bob), 33);
+        assert_eq!(Dividend::withdrawable_dividend(&e, &reward_token, &carol), 33);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn distribute_unknown_reward_token_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let token = e.register(MockContract, ());
+    let reward_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+
+    e.as_contract(&token, || {
+        Dividend::mint(&e, &payer, 100);
+    });
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #142)")]
+fn add_reward_token_twice_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        Dividend::add_reward_token(&e, &reward_token);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #143)")]
+fn distribute_without_supply_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let payer = Address::generate(&e);
+    let (token, reward_token) = setup(&e, &payer, 1_000);
+
+    e.as_contract(&token, || {
+        Dividend::distribute(&e, &reward_token, &payer, 100);
+    });
+}
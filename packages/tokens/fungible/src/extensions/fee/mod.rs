@@ -0,0 +1,169 @@
+//! # Fee Extension for Fungible Token
+//!
+//! The `fee` module deducts a fee from every transfer and sends it to a fee
+//! collector, i.e. the receiver of a transfer gets `amount - fee` tokens.
+//!
+//! ## Implementation Notes
+//!
+//! - **Fee rate**: The fee is expressed in basis points (1/100 of a percent) of
+//!   the transferred amount, rounded down. It cannot be greater than
+//!   [`MAX_FEE_BASIS_POINTS`].
+//! - **Exemptions**: No fee is charged when either the sender or the receiver
+//!   is exempt. The fee collector is not exempt by default.
+//! - **Scope**: Only `transfer`, `transfer_muxed`, `transfer_from` and every
+//!   recipient of a batch transfer are charged. Mints, burns and approvals are
+//!   not affected, and the total supply is never changed by the fee.
+//! - **Events**: The fee is moved with a separate balance update, so a charged
+//!   transfer emits a `transfer` event to the receiver, a `transfer` event to
+//!   the fee collector and a `fee` event.
+//!
+//! ## Usage
+//!
+//! [`Fee`] overrides [`crate::ContractOverrides::update_transfer_with`], which
+//! [`crate::Stack`] takes from its top. To combine the fee with other
+//! extensions, put [`Fee`] at the top of the stack, e.g.
+//! `Stack<Fee, Stack<AllowList, Votes>>`; the fee is then moved through the
+//! checks and update hooks of the whole stack. Stacks with [`Fee`] anywhere
+//! else do not compile.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+pub use storage::{Fee, FeeStorageKey, MAX_FEE_BASIS_POINTS};
+
+use crate::FungibleToken;
+
+/// Fee Trait for Fungible Token
+///
+/// The `FungibleFee` trait extends the `FungibleToken` trait to provide a fee
+/// on transfers that can be managed by an authorized account. This trait is
+/// designed to be used in conjunction with the `FungibleToken` trait.
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `FungibleFee` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// The fee is deducted by [`Fee`], so `ContractType` must be either [`Fee`],
+/// a [`crate::Stack`] with [`Fee`] at its top, or a contract type that
+/// implements `update_transfer_with` with [`Fee::update_with_fee`].
+pub trait FungibleFee: FungibleToken {
+    /// Returns the fee rate in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn fee_rate(e: &Env) -> u32;
+
+    /// Returns the address receiving the fees, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn fee_collector(e: &Env) -> Option<Address>;
+
+    /// Returns whether `account` is exempt from the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    fn is_fee_exempt(e: &Env, account: Address) -> bool;
+
+    /// Sets the fee rate and the address receiving the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `rate` - The fee rate in basis points.
+    /// * `collector` - The address receiving the fees.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::FeeInvalidRate`] - When `rate` is greater
+    ///   than [`MAX_FEE_BASIS_POINTS`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_set"]`
+    /// * data - `[rate: u32, collector: Address]`
+    fn set_fee(e: &Env, rate: u32, collector: Address, operator: Address);
+
+    /// Sets whether `account` is exempt from the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update the exemption for.
+    /// * `exempt` - Whether `account` is exempt.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_exempt", account: Address]`
+    /// * data - `[exempt: bool]`
+    fn set_fee_exempt(e: &Env, account: Address, exempt: bool, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a fee is charged on a transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address paying the fee.
+/// * `collector` - The address receiving the fee.
+/// * `amount` - The amount of the fee.
+///
+/// # Events
+///
+/// * topics - `["fee", from: Address, collector: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_fee(e: &Env, from: &Address, collector: &Address, amount: i128) {
+    let topics = (symbol_short!("fee"), from, collector);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when the fee rate and collector are set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `rate` - The new fee rate in basis points.
+/// * `collector` - The new address receiving the fees.
+///
+/// # Events
+///
+/// * topics - `["fee_set"]`
+/// * data - `[rate: u32, collector: Address]`
+pub fn emit_fee_set(e: &Env, rate: u32, collector: &Address) {
+    let topics = (symbol_short!("fee_set"),);
+    e.events().publish(topics, (rate, collector.clone()))
+}
+
+/// Emits an event when the fee exemption of an account changes.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose exemption changed.
+/// * `exempt` - Whether `account` is exempt.
+///
+/// # Events
+///
+/// * topics - `["fee_exempt", account: Address]`
+/// * data - `[exempt: bool]`
+pub fn emit_fee_exempt(e: &Env, account: &Address, exempt: bool) {
+    let topics = (Symbol::new(e, "fee_exempt"), account);
+    e.events().publish(topics, exempt)
+}
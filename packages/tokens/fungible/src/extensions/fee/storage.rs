@@ -0,0 +1,300 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{
+    extensions::fee::{emit_fee, emit_fee_exempt, emit_fee_set},
+    fungible::{emit_transfer, emit_transfer_muxed},
+    overrides::ContractOverrides,
+    FungibleTokenError,
+};
+
+/// The maximum fee rate in basis points (10%).
+pub const MAX_FEE_BASIS_POINTS: u32 = 1_000;
+
+/// The denominator of the fee rate.
+const BASIS_POINTS: i128 = 10_000;
+
+pub struct Fee;
+
+impl ContractOverrides for Fee {
+    const OVERRIDES_UPDATE_TRANSFER: bool = true;
+
+    fn update_transfer_with<T: ContractOverrides + ?Sized>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) {
+        Fee::update_with_fee::<T>(e, from, to, to_muxed_id, amount);
+    }
+}
+
+/// Storage keys for the data associated with the fee extension
+#[contracttype]
+pub enum FeeStorageKey {
+    /// Stores the fee rate in basis points
+    Rate,
+    /// Stores the address receiving the fees
+    Collector,
+    /// Stores the exemption of an account
+    Exempt(Address),
+}
+
+impl Fee {
+    // ################## QUERY STATE ##################
+
+    /// Returns the fee rate in basis points. Defaults to `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn fee_rate(e: &Env) -> u32 {
+        e.storage().instance().get(&FeeStorageKey::Rate).unwrap_or(0)
+    }
+
+    /// Returns the address receiving the fees, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn fee_collector(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&FeeStorageKey::Collector)
+    }
+
+    /// Returns whether `account` is exempt from the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    pub fn is_fee_exempt(e: &Env, account: &Address) -> bool {
+        let key = FeeStorageKey::Exempt(account.clone());
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_EXTEND_AMOUNT,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the fee charged on a transfer of `amount` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address sending the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    pub fn calculate_fee(e: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        let rate = Fee::fee_rate(e) as i128;
+        if rate == 0 || Fee::is_fee_exempt(e, from) || Fee::is_fee_exempt(e, to) {
+            return 0;
+        }
+        // split the multiplication to avoid overflowing for large amounts
+        amount / BASIS_POINTS * rate + amount % BASIS_POINTS * rate / BASIS_POINTS
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the fee rate and the address receiving the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `rate` - The fee rate in basis points.
+    /// * `collector` - The address receiving the fees.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::FeeInvalidRate`] - When `rate` is greater than
+    ///   [`MAX_FEE_BASIS_POINTS`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_set"]`
+    /// * data - `[rate: u32, collector: Address]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized fee modifications.
+    pub fn set_fee(e: &Env, rate: u32, collector: &Address) {
+        if rate > MAX_FEE_BASIS_POINTS {
+            panic_with_error!(e, FungibleTokenError::FeeInvalidRate);
+        }
+        e.storage().instance().set(&FeeStorageKey::Rate, &rate);
+        e.storage().instance().set(&FeeStorageKey::Collector, collector);
+
+        emit_fee_set(e, rate, collector);
+    }
+
+    /// Sets whether `account` is exempt from the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update the exemption for.
+    /// * `exempt` - Whether `account` is exempt.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_exempt", account: Address]`
+    /// * data - `[exempt: bool]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized exemptions.
+    pub fn set_fee_exempt(e: &Env, account: &Address, exempt: bool) {
+        let key = FeeStorageKey::Exempt(account.clone());
+
+        // only emit an event if the exemption changes
+        if e.storage().persistent().has(&key) != exempt {
+            if exempt {
+                e.storage().persistent().set(&key, &());
+            } else {
+                e.storage().persistent().remove(&key);
+            }
+
+            emit_fee_exempt(e, account, exempt);
+        }
+    }
+
+    /// Moves `amount` of tokens from `from` to `to` with
+    /// [`ContractOverrides::update`] of `T`, deducting the fee and moving it
+    /// to the fee collector.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `to_muxed_id` - The muxed id of `to`, or `None`. It is included only
+    ///   in the `transfer` event to `to`.
+    /// * `amount` - The amount of tokens to be transferred, including the fee.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Fee::calculate_fee`] errors.
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`, or `MuxedTransferData` if `to_muxed_id` is
+    ///   set
+    ///
+    /// and if a fee is charged:
+    ///
+    /// * topics - `["transfer", from: Address, collector: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["fee", from: Address, collector: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update_with_fee<T: ContractOverrides + ?Sized>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) {
+        let fee = Fee::calculate_fee(e, from, to, amount);
+
+        T::update(e, Some(from), Some(to), amount - fee);
+        match to_muxed_id {
+            Some(to_muxed_id) => emit_transfer_muxed(e, from, to, to_muxed_id, amount - fee),
+            None => emit_transfer(e, from, to, amount - fee),
+        }
+
+        if fee > 0 {
+            let collector =
+                Fee::fee_collector(e).expect("the rate is non-zero only after a collector is set");
+            T::update(e, Some(from), Some(&collector), fee);
+            emit_transfer(e, from, &collector, fee);
+            emit_fee(e, from, &collector, fee);
+        }
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// Transfers `amount` of tokens from `from` to `to`, of which the fee is
+    /// sent to the fee collector.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred, including the fee.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Fee::update_with_fee`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`Fee::update_with_fee`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        <Fee as ContractOverrides>::transfer(e, from, to, amount);
+    }
+
+    /// Transfers `amount` of tokens from `from` to `to` using the allowance
+    /// mechanism, of which the fee is sent to the fee collector. The full
+    /// `amount` is deducted from the allowance of `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `spender` - The address authorizing the transfer, and having its
+    ///   allowance consumed during the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred, including the fee.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`Fee::update_with_fee`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`Fee::update_with_fee`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        <Fee as ContractOverrides>::transfer_from(e, spender, from, to, amount);
+    }
+}
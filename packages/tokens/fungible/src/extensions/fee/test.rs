@@ -0,0 +1,190 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{extensions::fee::storage::Fee, votes::Votes, Base, ContractOverrides, Stack};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn transfer_deducts_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 250, &collector);
+        Base::mint(&e, &from, 1_000);
+    });
+
+    e.as_contract(&address, || {
+        Fee::transfer(&e, &from, &to, 1_000);
+
+        assert_eq!(Base::balance(&e, &from), 0);
+        assert_eq!(Base::balance(&e, &to), 975);
+        assert_eq!(Base::balance(&e, &collector), 25);
+        assert_eq!(Base::total_supply(&e), 1_000);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        // transfer to receiver + transfer to collector + fee
+        event_assert.assert_event_count(3);
+    });
+}
+
+#[test]
+fn transfer_from_spends_full_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 100, &collector);
+        Base::mint(&e, &owner, 1_000);
+        Base::approve(&e, &owner, &spender, 500, 100);
+    });
+
+    e.as_contract(&address, || {
+        Fee::transfer_from(&e, &spender, &owner, &to, 500);
+
+        assert_eq!(Base::allowance(&e, &owner, &spender), 0);
+        assert_eq!(Base::balance(&e, &owner), 500);
+        assert_eq!(Base::balance(&e, &to), 495);
+        assert_eq!(Base::balance(&e, &collector), 5);
+    });
+}
+
+#[test]
+fn stacked_transfer_deducts_fee_through_hooks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 250, &collector);
+        for account in [&from, &to, &collector] {
+            Votes::set_delegate(&e, account, account);
+        }
+        Stack::<Fee, Votes>::mint(&e, &from, 1_000);
+    });
+
+    e.as_contract(&address, || {
+        Stack::<Fee, Votes>::transfer(&e, &from, &to, 1_000);
+
+        assert_eq!(Base::balance(&e, &to), 975);
+        assert_eq!(Base::balance(&e, &collector), 25);
+        // both legs went through the `after_update` hook of `Votes`
+        assert_eq!(Votes::get_votes(&e, &from), 0);
+        assert_eq!(Votes::get_votes(&e, &to), 975);
+        assert_eq!(Votes::get_votes(&e, &collector), 25);
+    });
+}
+
+#[test]
+fn fee_rounds_down() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 30, &collector);
+        assert_eq!(Fee::calculate_fee(&e, &from, &to, 333), 0);
+        assert_eq!(Fee::calculate_fee(&e, &from, &to, 334), 1);
+        // does not overflow for large amounts
+        let expected = i128::MAX / 10_000 * 30 + i128::MAX % 10_000 * 30 / 10_000;
+        assert_eq!(Fee::calculate_fee(&e, &from, &to, i128::MAX), expected);
+    });
+}
+
+#[test]
+fn exempt_accounts_pay_no_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let exempt = Address::generate(&e);
+    let user = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 500, &collector);
+        Fee::set_fee_exempt(&e, &exempt, true);
+        assert!(Fee::is_fee_exempt(&e, &exempt));
+        Base::mint(&e, &exempt, 1_000);
+    });
+
+    e.as_contract(&address, || {
+        // exempt sender
+        Fee::transfer(&e, &exempt, &user, 100);
+        assert_eq!(Base::balance(&e, &user), 100);
+    });
+
+    e.as_contract(&address, || {
+        // exempt receiver
+        Fee::transfer(&e, &user, &exempt, 100);
+        assert_eq!(Base::balance(&e, &exempt), 1_000);
+        assert_eq!(Base::balance(&e, &collector), 0);
+    });
+
+    e.as_contract(&address, || {
+        Fee::set_fee_exempt(&e, &exempt, false);
+        assert!(!Fee::is_fee_exempt(&e, &exempt));
+    });
+}
+
+#[test]
+fn no_fee_without_configuration() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Fee::fee_rate(&e), 0);
+        assert_eq!(Fee::fee_collector(&e), None);
+        Base::mint(&e, &from, 100);
+        Fee::transfer(&e, &from, &to, 100);
+        assert_eq!(Base::balance(&e, &to), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #129)")]
+fn set_fee_above_max_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 1_001, &collector);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn negative_transfer_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Fee::set_fee(&e, 100, &collector);
+        Fee::transfer(&e, &from, &to, -1);
+    });
+}
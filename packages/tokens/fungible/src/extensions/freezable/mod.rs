@@ -0,0 +1,146 @@
+//! # Freezable Extension for Fungible Token
+//!
+//! The `freezable` module allows an authorized account to freeze a specific
+//! amount of the balance of an account, e.g. an amount that is subject to a
+//! dispute. Unlike [`crate::blocklist`], the rest of the balance can still be
+//! transferred and burned.
+//!
+//! ## Implementation Notes
+//!
+//! - **Enforcement**: [`Freezable`] checks in `check_transfer` and `check_burn`
+//!   that the amount does not exceed the unfrozen balance of the sender, so it
+//!   can be combined with other extensions through [`crate::Stack`].
+//! - **Invariant**: The frozen amount cannot be greater than the balance of the
+//!   account when it is frozen. Since frozen tokens cannot leave the account
+//!   through the functions of [`crate::ContractOverrides`], the invariant is
+//!   preserved as long as every balance decrease goes through them. Updates
+//!   that skip the checks (e.g. [`crate::clawback`]) unfreeze the excess in the
+//!   `after_update` hook instead.
+//! - **Mints**: Receiving tokens is not restricted.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env};
+pub use storage::{Freezable, FreezableStorageKey};
+
+use crate::FungibleToken;
+
+/// Freezable Trait for Fungible Token
+///
+/// The `FungibleFreezable` trait extends the `FungibleToken` trait to provide
+/// partial freezing of balances that can be managed by an authorized account.
+/// This trait is designed to be used in conjunction with the `FungibleToken`
+/// trait.
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `FungibleFreezable` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// The checks are enforced by [`Freezable`], so `ContractType` must be either
+/// [`Freezable`] or a [`crate::Stack`] that contains it.
+pub trait FungibleFreezable: FungibleToken {
+    /// Returns the amount of frozen tokens of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the frozen balance is being queried.
+    fn frozen_balance(e: &Env, account: Address) -> i128;
+
+    /// Returns the amount of tokens of `account` that are not frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the available balance is being
+    ///   queried.
+    fn available_balance(e: &Env, account: Address) -> i128;
+
+    /// Freezes `amount` of tokens of `account` in addition to the already
+    /// frozen ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are frozen.
+    /// * `amount` - The amount of tokens to freeze.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::FreezeExceedsBalance`] - When `amount` is
+    ///   greater than the available balance of `account`.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["freeze", account: Address]`
+    /// * data - `[amount: i128]`
+    fn freeze_partial(e: &Env, account: Address, amount: i128, operator: Address);
+
+    /// Unfreezes `amount` of the frozen tokens of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are unfrozen.
+    /// * `amount` - The amount of tokens to unfreeze.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::UnfreezeExceedsFrozen`] - When `amount`
+    ///   is greater than the frozen balance of `account`.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unfreeze", account: Address]`
+    /// * data - `[amount: i128]`
+    fn unfreeze_partial(e: &Env, account: Address, amount: i128, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when tokens of an account are frozen.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose tokens are frozen.
+/// * `amount` - The amount of tokens frozen.
+///
+/// # Events
+///
+/// * topics - `["freeze", account: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_freeze(e: &Env, account: &Address, amount: i128) {
+    let topics = (symbol_short!("freeze"), account);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when tokens of an account are unfrozen.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose tokens are unfrozen.
+/// * `amount` - The amount of tokens unfrozen.
+///
+/// # Events
+///
+/// * topics - `["unfreeze", account: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_unfreeze(e: &Env, account: &Address, amount: i128) {
+    let topics = (symbol_short!("unfreeze"), account);
+    e.events().publish(topics, amount)
+}
@@ -0,0 +1,242 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::freezable::{emit_freeze, emit_unfreeze},
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct Freezable;
+
+impl ContractOverrides for Freezable {
+    fn check_transfer(e: &Env, from: &Address, _to: &Address, amount: i128) {
+        Freezable::check_available_balance(e, from, amount);
+    }
+
+    fn check_burn(e: &Env, from: &Address, amount: i128) {
+        Freezable::check_available_balance(e, from, amount);
+    }
+
+    fn after_update(e: &Env, from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        // Updates that skip the checks (e.g. clawbacks) can take frozen tokens.
+        if let Some(from) = from {
+            Freezable::unfreeze_excess(e, from);
+        }
+    }
+}
+
+/// Storage keys for the data associated with the freezable extension
+#[contracttype]
+pub enum FreezableStorageKey {
+    /// Stores the amount of frozen tokens of an account
+    Frozen(Address),
+}
+
+impl Freezable {
+    // ################## QUERY STATE ##################
+
+    /// Returns the amount of frozen tokens of `account`. Defaults to `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the frozen balance is being queried.
+    pub fn frozen_balance(e: &Env, account: &Address) -> i128 {
+        let key = FreezableStorageKey::Frozen(account.clone());
+        if let Some(frozen) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            frozen
+        } else {
+            0
+        }
+    }
+
+    /// Returns the amount of tokens of `account` that are not frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the available balance is being
+    ///   queried.
+    pub fn available_balance(e: &Env, account: &Address) -> i128 {
+        // frozen tokens never exceed the balance, see the module docs
+        Base::balance(e, account) - Freezable::frozen_balance(e, account)
+    }
+
+    /// Panics if `amount` is greater than the available balance of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are moved.
+    /// * `amount` - The amount of tokens moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InsufficientUnfrozenBalance`] - When `amount` is
+    ///   greater than the available balance of `account` while some of its
+    ///   tokens are frozen.
+    pub fn check_available_balance(e: &Env, account: &Address, amount: i128) {
+        let frozen = Freezable::frozen_balance(e, account);
+        // without frozen tokens, an insufficient balance is reported by `update`
+        if frozen > 0 && amount > Base::balance(e, account) - frozen {
+            panic_with_error!(e, FungibleTokenError::InsufficientUnfrozenBalance);
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Freezes `amount` of tokens of `account` in addition to the already
+    /// frozen ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are frozen.
+    /// * `amount` - The amount of tokens to freeze.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`FungibleTokenError::FreezeExceedsBalance`] - When `amount` is
+    ///   greater than the available balance of `account`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["freeze", account: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized freezing of tokens.
+    pub fn freeze_partial(e: &Env, account: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        if amount > Freezable::available_balance(e, account) {
+            panic_with_error!(e, FungibleTokenError::FreezeExceedsBalance);
+        }
+
+        let frozen = Freezable::frozen_balance(e, account) + amount;
+        e.storage().persistent().set(&FreezableStorageKey::Frozen(account.clone()), &frozen);
+
+        emit_freeze(e, account, amount);
+    }
+
+    /// Unfreezes `amount` of the frozen tokens of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are unfrozen.
+    /// * `amount` - The amount of tokens to unfreeze.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`FungibleTokenError::UnfreezeExceedsFrozen`] - When `amount` is
+    ///   greater than the frozen balance of `account`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unfreeze", account: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized unfreezing of tokens.
+    pub fn unfreeze_partial(e: &Env, account: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        let frozen = Freezable::frozen_balance(e, account);
+        if amount > frozen {
+            panic_with_error!(e, FungibleTokenError::UnfreezeExceedsFrozen);
+        }
+
+        let key = FreezableStorageKey::Frozen(account.clone());
+        if frozen == amount {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &(frozen - amount));
+        }
+
+        emit_unfreeze(e, account, amount);
+    }
+
+    /// Low-level function that unfreezes the frozen tokens of `account` that
+    /// exceed its balance, so that the frozen balance never exceeds the
+    /// balance. It is called by the `after_update` hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose excess frozen tokens are unfrozen.
+    ///
+    /// # Events
+    ///
+    /// If tokens are unfrozen:
+    ///
+    /// * topics - `["unfreeze", account: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization, but it only releases
+    /// frozen tokens that `account` no longer holds.
+    pub fn unfreeze_excess(e: &Env, account: &Address) {
+        let excess = Freezable::frozen_balance(e, account) - Base::balance(e, account);
+        if excess > 0 {
+            Freezable::unfreeze_partial(e, account, excess);
+        }
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// This is a wrapper around [`Base::transfer()`] that rejects transfers
+    /// of frozen tokens.
+    ///
+    /// Please refer to [`Base::transfer`] for the inline documentation.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        <Freezable as ContractOverrides>::transfer(e, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::transfer_from()`] that rejects
+    /// transfers of frozen tokens.
+    ///
+    /// Please refer to [`Base::transfer_from`] for the inline documentation.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        <Freezable as ContractOverrides>::transfer_from(e, spender, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::freezable::FungibleFreezable`]
+    ///
+    /// Please refer to [`Base::burn`] for the inline documentation.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        <Freezable as ContractOverrides>::burn(e, from, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn_from()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::freezable::FungibleFreezable`]
+    ///
+    /// Please refer to [`Base::burn_from`] for the inline documentation.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        <Freezable as ContractOverrides>::burn_from(e, spender, from, amount);
+    }
+}
@@ -0,0 +1,146 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{extensions::freezable::storage::Freezable, Base};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn freeze_and_unfreeze_partial() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Freezable::freeze_partial(&e, &account, 30);
+        Freezable::freeze_partial(&e, &account, 10);
+        assert_eq!(Freezable::frozen_balance(&e, &account), 40);
+        assert_eq!(Freezable::available_balance(&e, &account), 60);
+
+        Freezable::unfreeze_partial(&e, &account, 40);
+        assert_eq!(Freezable::frozen_balance(&e, &account), 0);
+        assert_eq!(Freezable::available_balance(&e, &account), 100);
+    });
+}
+
+#[test]
+fn transfer_of_available_balance_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+        Freezable::freeze_partial(&e, &from, 30);
+    });
+
+    e.as_contract(&address, || {
+        Freezable::transfer(&e, &from, &to, 70);
+        assert_eq!(Base::balance(&e, &from), 30);
+        assert_eq!(Freezable::available_balance(&e, &from), 0);
+        // the receiver is not affected
+        assert_eq!(Freezable::available_balance(&e, &to), 70);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn transfer_of_frozen_tokens_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+        Freezable::freeze_partial(&e, &from, 30);
+    });
+
+    e.as_contract(&address, || {
+        Freezable::transfer(&e, &from, &to, 71);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn transfer_from_of_frozen_tokens_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 100, 100);
+        Freezable::freeze_partial(&e, &owner, 50);
+    });
+
+    e.as_contract(&address, || {
+        Freezable::transfer_from(&e, &spender, &owner, &spender, 51);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn burn_of_frozen_tokens_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Freezable::freeze_partial(&e, &account, 1);
+    });
+
+    e.as_contract(&address, || {
+        Freezable::burn(&e, &account, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #131)")]
+fn freeze_more_than_balance_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Freezable::freeze_partial(&e, &account, 60);
+        Freezable::freeze_partial(&e, &account, 41);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #132)")]
+fn unfreeze_more_than_frozen_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 100);
+        Freezable::freeze_partial(&e, &account, 10);
+        Freezable::unfreeze_partial(&e, &account, 11);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn freeze_negative_amount_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Freezable::freeze_partial(&e, &account, -1);
+    });
+}
@@ -0,0 +1,132 @@
+//! # Max Balance Extension for Fungible Token
+//!
+//! The `max_balance` module limits the amount of tokens a single account can
+//! hold, e.g. to prevent the accumulation of a large share of the supply
+//! right after the launch of a token.
+//!
+//! ## Implementation Notes
+//!
+//! - **Scope**: The limit is checked in `check_transfer` and `check_mint` of
+//!   the [`MaxBalance`] contract type against the balance of the receiver, so
+//!   it applies to `transfer`, `transfer_from` and `mint`, and can be stacked
+//!   with other extensions with [`crate::Stack`].
+//! - **Exemptions**: Exempt accounts, e.g. liquidity pools or treasuries, can
+//!   hold any amount of tokens.
+//! - **Existing balances**: Lowering the limit does not affect the tokens
+//!   already held, but accounts above the new limit cannot receive any more
+//!   tokens.
+//! - **Unset limit**: Without a configured limit, no limit is enforced.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol};
+pub use storage::{MaxBalance, MaxBalanceStorageKey};
+
+use crate::FungibleToken;
+
+/// Max Balance Trait for Fungible Token
+///
+/// The `FungibleMaxBalance` trait extends the `FungibleToken` trait to provide
+/// a maximum balance per account that can be managed by an authorized account.
+/// This trait is designed to be used in conjunction with the `FungibleToken`
+/// trait.
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `FungibleMaxBalance` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// The limit is enforced by [`MaxBalance`], so `ContractType` must be either
+/// [`MaxBalance`] or a [`crate::Stack`] containing it.
+pub trait FungibleMaxBalance: FungibleToken {
+    /// Returns the maximum balance per account, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn max_balance(e: &Env) -> Option<i128>;
+
+    /// Returns whether `account` is exempt from the maximum balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    fn is_max_balance_exempt(e: &Env, account: Address) -> bool;
+
+    /// Sets the maximum balance per account.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `max_balance` - The maximum amount of tokens an account can hold.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `max_balance < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_balance_set"]`
+    /// * data - `[max_balance: i128]`
+    fn set_max_balance(e: &Env, max_balance: i128, operator: Address);
+
+    /// Sets whether `account` is exempt from the maximum balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update the exemption for.
+    /// * `exempt` - Whether `account` is exempt.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_balance_exempt", account: Address]`
+    /// * data - `[exempt: bool]`
+    fn set_max_balance_exempt(e: &Env, account: Address, exempt: bool, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when the maximum balance is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `max_balance` - The new maximum balance per account.
+///
+/// # Events
+///
+/// * topics - `["max_balance_set"]`
+/// * data - `[max_balance: i128]`
+pub fn emit_max_balance_set(e: &Env, max_balance: i128) {
+    let topics = (Symbol::new(e, "max_balance_set"),);
+    e.events().publish(topics, max_balance)
+}
+
+/// Emits an event when the maximum balance exemption of an account changes.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose exemption changed.
+/// * `exempt` - Whether `account` is exempt.
+///
+/// # Events
+///
+/// * topics - `["max_balance_exempt", account: Address]`
+/// * data - `[exempt: bool]`
+pub fn emit_max_balance_exempt(e: &Env, account: &Address, exempt: bool) {
+    let topics = (Symbol::new(e, "max_balance_exempt"), account);
+    e.events().publish(topics, exempt)
+}
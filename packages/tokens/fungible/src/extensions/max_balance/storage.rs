@@ -0,0 +1,161 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::max_balance::{emit_max_balance_exempt, emit_max_balance_set},
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct MaxBalance;
+
+impl ContractOverrides for MaxBalance {
+    fn check_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        // a transfer to oneself does not change the balance
+        if from != to {
+            MaxBalance::check_max_balance(e, to, amount);
+        }
+    }
+
+    fn check_mint(e: &Env, to: &Address, amount: i128) {
+        MaxBalance::check_max_balance(e, to, amount);
+    }
+}
+
+/// Storage keys for the data associated with the max balance extension
+#[contracttype]
+pub enum MaxBalanceStorageKey {
+    /// Stores the maximum balance per account
+    MaxBalance,
+    /// Stores the exemption of an account
+    Exempt(Address),
+}
+
+impl MaxBalance {
+    // ################## QUERY STATE ##################
+
+    /// Returns the maximum balance per account, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn max_balance(e: &Env) -> Option<i128> {
+        e.storage().instance().get(&MaxBalanceStorageKey::MaxBalance)
+    }
+
+    /// Returns whether `account` is exempt from the maximum balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    pub fn is_max_balance_exempt(e: &Env, account: &Address) -> bool {
+        let key = MaxBalanceStorageKey::Exempt(account.clone());
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Panics if receiving `amount` of tokens would raise the balance of
+    /// `account` above the maximum balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens received.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MaxBalanceExceeded`] - When the new balance of
+    ///   `account` would be greater than the maximum balance.
+    pub fn check_max_balance(e: &Env, account: &Address, amount: i128) {
+        let Some(max_balance) = MaxBalance::max_balance(e) else {
+            return;
+        };
+        if MaxBalance::is_max_balance_exempt(e, account) {
+            return;
+        }
+        // an overflowing balance exceeds any maximum balance
+        match Base::balance(e, account).checked_add(amount) {
+            Some(balance) if balance <= max_balance => {}
+            _ => panic_with_error!(e, FungibleTokenError::MaxBalanceExceeded),
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the maximum balance per account.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `max_balance` - The maximum amount of tokens an account can hold.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `max_balance < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_balance_set"]`
+    /// * data - `[max_balance: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized changes of the limit.
+    pub fn set_max_balance(e: &Env, max_balance: i128) {
+        if max_balance < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        e.storage().instance().set(&MaxBalanceStorageKey::MaxBalance, &max_balance);
+
+        emit_max_balance_set(e, max_balance);
+    }
+
+    /// Sets whether `account` is exempt from the maximum balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update the exemption for.
+    /// * `exempt` - Whether `account` is exempt.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_balance_exempt", account: Address]`
+    /// * data - `[exempt: bool]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized exemptions.
+    pub fn set_max_balance_exempt(e: &Env, account: &Address, exempt: bool) {
+        let key = MaxBalanceStorageKey::Exempt(account.clone());
+
+        // only emit an event if the exemption changes
+        if e.storage().persistent().has(&key) != exempt {
+            if exempt {
+                e.storage().persistent().set(&key, &());
+            } else {
+                e.storage().persistent().remove(&key);
+            }
+
+            emit_max_balance_exempt(e, account, exempt);
+        }
+    }
+}
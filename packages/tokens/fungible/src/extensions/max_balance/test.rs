@@ -0,0 +1,131 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    blocklist::BlockList, extensions::max_balance::storage::MaxBalance, Base, ContractOverrides,
+    Stack,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn transfer_up_to_max_balance_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        Base::mint(&e, &from, 100);
+        Base::mint(&e, &to, 40);
+    });
+
+    e.as_contract(&address, || {
+        MaxBalance::transfer(&e, &from, &to, 60);
+        assert_eq!(Base::balance(&e, &to), 100);
+    });
+}
+
+#[test]
+fn set_max_balance_emits_event() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        MaxBalance::set_max_balance_exempt(&e, &account, true);
+        MaxBalance::set_max_balance_exempt(&e, &account, true);
+
+        assert_eq!(MaxBalance::max_balance(&e), Some(100));
+        assert!(MaxBalance::is_max_balance_exempt(&e, &account));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn exempt_account_exceeds_max_balance() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let pool = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        MaxBalance::set_max_balance_exempt(&e, &pool, true);
+        MaxBalance::mint(&e, &pool, 1_000);
+        assert_eq!(Base::balance(&e, &pool), 1_000);
+    });
+}
+
+#[test]
+fn unset_max_balance_is_not_enforced() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::mint(&e, &account, 1_000);
+        assert_eq!(Base::balance(&e, &account), 1_000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #137)")]
+fn transfer_above_max_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        Base::mint(&e, &from, 100);
+        Base::mint(&e, &to, 50);
+    });
+
+    e.as_contract(&address, || {
+        MaxBalance::transfer(&e, &from, &to, 51);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #137)")]
+fn transfer_from_above_max_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 101, 1000);
+    });
+
+    e.as_contract(&address, || {
+        MaxBalance::transfer_from(&e, &spender, &owner, &to, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #137)")]
+fn stacked_mint_above_max_balance_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxBalance::set_max_balance(&e, 100);
+        Stack::<BlockList, MaxBalance>::mint(&e, &account, 101);
+    });
+}
@@ -0,0 +1,160 @@
+//! # Mintable Extension for Fungible Token
+//!
+//! The `mintable` module lets multiple minters mint tokens, each one within a
+//! quota that refills over a window of ledgers, e.g. custodians of a
+//! stablecoin that may each mint a bounded amount per day.
+//!
+//! ## Implementation Notes
+//!
+//! - **Quota**: Every minter has a `limit` and a `window` (in ledgers). Minting
+//!   consumes the available quota, which refills linearly, at `limit` tokens
+//!   per `window` ledgers, up to `limit`. A `window` of `0` disables the
+//!   refill, so the quota is a one-off allowance that the operator tops up by
+//!   setting the minter again.
+//! - **Reconfiguration**: Setting an existing minter keeps its available quota,
+//!   capped at the new `limit`, so that it cannot be used to reset a consumed
+//!   quota.
+//! - **Contract types**: The tokens are minted with `mint` of the contract type
+//!   passed to [`Mintable::mint`], so the supply cap is enforced by passing
+//!   [`crate::capped::Capped`] (or a [`crate::Stack`] containing it), which
+//!   runs [`crate::capped::check_cap`] before the mint.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol};
+pub use storage::{Mintable, MintableStorageKey, MinterQuota};
+
+use crate::FungibleToken;
+
+/// Mintable Trait for Fungible Token
+///
+/// The `FungibleMintable` trait extends the `FungibleToken` trait to provide
+/// mints by multiple minters, each one within its own quota. This trait is
+/// designed to be used in conjunction with the `FungibleToken` trait.
+///
+/// **NOTE**
+///
+/// The functions managing the minters, exposed in the `FungibleMintable`
+/// trait, include an additional parameter `operator: Address`. This account
+/// is the one authorizing the invocation. Having it as a parameter grants the
+/// flexibility to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+pub trait FungibleMintable: FungibleToken {
+    /// Returns the amount of tokens `minter` can currently mint. Defaults to
+    /// `0` for accounts that are not minters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    fn available_quota(e: &Env, minter: Address) -> i128;
+
+    /// Mints `amount` of tokens to `to` within the quota of `minter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `to` - The address receiving the new tokens.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::MinterNotFound`] - When `minter` is not a
+    ///   minter.
+    /// * [`crate::FungibleTokenError::MinterQuotaExceeded`] - When `amount` is
+    ///   greater than the available quota of `minter`.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `minter` is required.
+    fn mint(e: &Env, minter: Address, to: Address, amount: i128);
+
+    /// Sets `minter` as a minter with a quota of `limit` tokens that refills
+    /// over `window` ledgers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `limit` - The maximum quota of the minter.
+    /// * `window` - The number of ledgers over which the quota fully refills,
+    ///   or `0` for no refill.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `limit < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["minter_set", minter: Address]`
+    /// * data - `[limit: i128, window: u32]`
+    fn set_minter(e: &Env, minter: Address, limit: i128, window: u32, operator: Address);
+
+    /// Removes `minter` from the minters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::MinterNotFound`] - When `minter` is not a
+    ///   minter.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["minter_removed", minter: Address]`
+    /// * data - `[]`
+    fn remove_minter(e: &Env, minter: Address, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a minter is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `minter` - The address of the minter.
+/// * `limit` - The maximum quota of the minter.
+/// * `window` - The number of ledgers over which the quota fully refills.
+///
+/// # Events
+///
+/// * topics - `["minter_set", minter: Address]`
+/// * data - `[limit: i128, window: u32]`
+pub fn emit_minter_set(e: &Env, minter: &Address, limit: i128, window: u32) {
+    let topics = (Symbol::new(e, "minter_set"), minter);
+    e.events().publish(topics, (limit, window))
+}
+
+/// Emits an event when a minter is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `minter` - The address of the removed minter.
+///
+/// # Events
+///
+/// * topics - `["minter_removed", minter: Address]`
+/// * data - `[]`
+pub fn emit_minter_removed(e: &Env, minter: &Address) {
+    let topics = (Symbol::new(e, "minter_removed"), minter);
+    e.events().publish(topics, ())
+}
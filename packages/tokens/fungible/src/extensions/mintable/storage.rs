@@ -0,0 +1,259 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::mintable::{emit_minter_removed, emit_minter_set},
+    overrides::ContractOverrides,
+    FungibleTokenError,
+};
+
+pub struct Mintable;
+
+/// Storage container for the quota of a minter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterQuota {
+    /// The maximum amount of tokens the minter can mint at once.
+    pub limit: i128,
+    /// The number of ledgers over which the quota fully refills, or `0` for
+    /// no refill.
+    pub window: u32,
+    /// The amount of tokens the minter could mint at `last_updated`.
+    pub available: i128,
+    /// The ledger at which `available` was computed.
+    pub last_updated: u32,
+    /// The part of the refill at `last_updated` that does not amount to a
+    /// whole token yet, in `1 / window` tokens.
+    pub remainder: u32,
+}
+
+/// Storage keys for the data associated with the mintable extension
+#[contracttype]
+pub enum MintableStorageKey {
+    /// Stores the quota of a minter
+    Minter(Address),
+}
+
+impl Mintable {
+    // ################## QUERY STATE ##################
+
+    /// Returns the quota of `minter`, if it is a minter.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    pub fn minter_quota(e: &Env, minter: &Address) -> Option<MinterQuota> {
+        let key = MintableStorageKey::Minter(minter.clone());
+        let quota = e.storage().persistent().get::<_, MinterQuota>(&key);
+        if quota.is_some() {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        }
+        quota
+    }
+
+    /// Returns whether `account` is a minter.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check.
+    pub fn is_minter(e: &Env, account: &Address) -> bool {
+        Mintable::minter_quota(e, account).is_some()
+    }
+
+    /// Returns the amount of tokens `minter` can currently mint, including the
+    /// refill since its last mint. Defaults to `0` for accounts that are not
+    /// minters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    pub fn available_quota(e: &Env, minter: &Address) -> i128 {
+        match Mintable::minter_quota(e, minter) {
+            Some(quota) => Mintable::refilled(e, &quota).0,
+            None => 0,
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Mints `amount` of tokens to `to` within the quota of `minter`, with
+    /// `mint` of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `to` - The address receiving the new tokens.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Mintable::consume_quota`] errors.
+    /// * refer to [`ContractOverrides::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `minter` is required.
+    pub fn mint<T: ContractOverrides>(e: &Env, minter: &Address, to: &Address, amount: i128) {
+        minter.require_auth();
+        Mintable::consume_quota(e, minter, amount);
+        T::mint(e, to, amount);
+    }
+
+    /// Sets `minter` as a minter with a quota of `limit` tokens that refills
+    /// over `window` ledgers. A new minter starts with a full quota, while an
+    /// existing minter keeps its available quota, capped at `limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `limit` - The maximum quota of the minter.
+    /// * `window` - The number of ledgers over which the quota fully refills,
+    ///   or `0` for no refill.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `limit < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["minter_set", minter: Address]`
+    /// * data - `[limit: i128, window: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized minting.
+    pub fn set_minter(e: &Env, minter: &Address, limit: i128, window: u32) {
+        if limit < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        let available = match Mintable::minter_quota(e, minter) {
+            Some(quota) => Mintable::refilled(e, &quota).0.min(limit),
+            None => limit,
+        };
+        let quota = MinterQuota {
+            limit,
+            window,
+            available,
+            last_updated: e.ledger().sequence(),
+            remainder: 0,
+        };
+        e.storage().persistent().set(&MintableStorageKey::Minter(minter.clone()), &quota);
+        emit_minter_set(e, minter, limit, window);
+    }
+
+    /// Removes `minter` from the minters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MinterNotFound`] - When `minter` is not a
+    ///   minter.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["minter_removed", minter: Address]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow unauthorized removal of minters.
+    pub fn remove_minter(e: &Env, minter: &Address) {
+        let key = MintableStorageKey::Minter(minter.clone());
+        if !e.storage().persistent().has(&key) {
+            panic_with_error!(e, FungibleTokenError::MinterNotFound);
+        }
+        e.storage().persistent().remove(&key);
+        emit_minter_removed(e, minter);
+    }
+
+    /// Low-level function that deducts `amount` from the available quota of
+    /// `minter`, after refilling it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `minter` - The address of the minter.
+    /// * `amount` - The amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`FungibleTokenError::MinterNotFound`] - When `minter` is not a
+    ///   minter.
+    /// * [`FungibleTokenError::MinterQuotaExceeded`] - When `amount` is greater
+    ///   than the available quota of `minter`.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization and does not mint any
+    /// tokens. Ensure that both are handled at a higher level.
+    pub fn consume_quota(e: &Env, minter: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        let Some(mut quota) = Mintable::minter_quota(e, minter) else {
+            panic_with_error!(e, FungibleTokenError::MinterNotFound);
+        };
+        let (available, remainder) = Mintable::refilled(e, &quota);
+        if amount > available {
+            panic_with_error!(e, FungibleTokenError::MinterQuotaExceeded);
+        }
+        quota.available = available - amount;
+        quota.last_updated = e.ledger().sequence();
+        quota.remainder = remainder;
+        e.storage().persistent().set(&MintableStorageKey::Minter(minter.clone()), &quota);
+    }
+
+    // ################## HELPERS ##################
+
+    /// Returns the available quota of `quota` at the current ledger, i.e.
+    /// `available` plus `limit` tokens per `window` ledgers elapsed since
+    /// `last_updated`, rounded down and capped at `limit`, and the part of the
+    /// refill that was rounded down, in `1 / window` tokens.
+    fn refilled(e: &Env, quota: &MinterQuota) -> (i128, u32) {
+        if quota.window == 0 {
+            return (quota.available, 0);
+        }
+        let elapsed = e.ledger().sequence().saturating_sub(quota.last_updated);
+        if elapsed >= quota.window {
+            return (quota.limit, 0);
+        }
+        let (elapsed, window) = (elapsed as i128, quota.window as i128);
+        // split the multiplication to avoid overflowing for large limits, the
+        // fractional part is below `window * window`, so it cannot overflow
+        let fraction = quota.limit % window * elapsed + quota.remainder as i128;
+        let refill = quota.limit / window * elapsed + fraction / window;
+        let available = quota.available.saturating_add(refill);
+        if available >= quota.limit {
+            (quota.limit, 0)
+        } else {
+            (available, (fraction % window) as u32)
+        }
+    }
+}
@@ -0,0 +1,205 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    capped::{set_cap, Capped},
+    extensions::mintable::storage::Mintable,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_within_quota_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 400);
+        assert_eq!(Base::balance(&e, &to), 400);
+        assert_eq!(Mintable::available_quota(&e, &minter), 600);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_mint(&to, 400);
+    });
+}
+
+#[test]
+fn quota_refills_over_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 1000);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number += 25);
+    e.as_contract(&address, || {
+        assert_eq!(Mintable::available_quota(&e, &minter), 250);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number += 200);
+    e.as_contract(&address, || {
+        assert_eq!(Mintable::available_quota(&e, &minter), 1000);
+    });
+}
+
+#[test]
+fn frequent_mints_keep_partial_refills() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        // one token every 10 ledgers
+        Mintable::set_minter(&e, &minter, 10, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 10);
+    });
+
+    // mint everything available every 3 ledgers, i.e. before a whole token
+    // is refilled
+    let mut refilled = 0;
+    for _ in 0..33 {
+        e.ledger().with_mut(|l| l.sequence_number += 3);
+        e.as_contract(&address, || {
+            let available = Mintable::available_quota(&e, &minter);
+            Mintable::mint::<Base>(&e, &minter, &to, available);
+            refilled += available;
+        });
+    }
+
+    // 99 ledgers refill 9.9 tokens
+    assert_eq!(refilled, 9);
+    e.ledger().with_mut(|l| l.sequence_number += 1);
+    e.as_contract(&address, || {
+        assert_eq!(Mintable::available_quota(&e, &minter), 1);
+        assert_eq!(Base::balance(&e, &to), 19);
+    });
+}
+
+#[test]
+fn zero_window_does_not_refill() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 0);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 700);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number += 1_000);
+    e.as_contract(&address, || {
+        assert_eq!(Mintable::available_quota(&e, &minter), 300);
+    });
+}
+
+#[test]
+fn set_minter_caps_available_quota() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 200);
+        Mintable::set_minter(&e, &minter, 2000, 100);
+        assert_eq!(Mintable::available_quota(&e, &minter), 800);
+        Mintable::set_minter(&e, &minter, 500, 100);
+        assert_eq!(Mintable::available_quota(&e, &minter), 500);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #134)")]
+fn mint_exceeding_quota_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 1001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #133)")]
+fn mint_by_removed_minter_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Mintable::set_minter(&e, &minter, 1000, 100);
+        Mintable::remove_minter(&e, &minter);
+        assert_eq!(Mintable::available_quota(&e, &minter), 0);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Base>(&e, &minter, &to, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn mint_enforces_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let minter = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 500);
+        Mintable::set_minter(&e, &minter, 1000, 100);
+    });
+
+    e.as_contract(&address, || {
+        Mintable::mint::<Capped>(&e, &minter, &to, 501);
+    });
+}
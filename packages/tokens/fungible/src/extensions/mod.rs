@@ -1,4 +1,20 @@
+pub mod allowance;
 pub mod allowlist;
+pub mod batch;
 pub mod blocklist;
 pub mod burnable;
 pub mod capped;
+pub mod clawback;
+pub mod dividend;
+pub mod fee;
+pub mod freezable;
+pub mod max_balance;
+pub mod mintable;
+pub mod muxed;
+pub mod partition;
+pub mod pausable;
+pub mod permit;
+pub mod snapshot;
+pub mod vault;
+pub mod votes;
+pub mod wrapper;
@@ -0,0 +1,60 @@
+//! # Muxed Extension for Fungible Token
+//!
+//! The `muxed` module lets senders attribute a transfer to a sub-account of
+//! the receiver with a muxed id, e.g. a customer account of an exchange that
+//! holds the tokens of all its customers in a single address.
+//!
+//! ## Implementation Notes
+//!
+//! - **Balances**: The muxed id only identifies the sub-account and is not part
+//!   of the state, i.e. the tokens are credited to the balance of `to`.
+//! - **Events**: The `transfer` event keeps its topics, and its data becomes
+//!   [`crate::MuxedTransferData`], i.e. the map `{ amount, to_muxed_id }`
+//!   emitted by the Stellar Asset Contracts for transfers to muxed addresses.
+//!   Transfers without a muxed id keep emitting the plain amount.
+//! - **Contract types**: `transfer_muxed` goes through `check_transfer` and
+//!   [`crate::ContractOverrides::update`] of the contract type, exactly like
+//!   `transfer`, so it can be combined with the other extensions.
+//! - **Muxed addresses**: The muxed id is passed explicitly alongside the
+//!   address of the receiver, since the version of `soroban-sdk` used by this
+//!   library does not provide a muxed address type.
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env};
+
+use crate::FungibleToken;
+
+/// Muxed Trait for Fungible Token
+///
+/// The `FungibleMuxed` trait extends the `FungibleToken` trait to provide
+/// transfers to muxed accounts. This trait is designed to be used in
+/// conjunction with the `FungibleToken` trait.
+pub trait FungibleMuxed: FungibleToken {
+    /// Transfers `amount` of tokens from `from` to the muxed account
+    /// `to_muxed_id` of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `to_muxed_id` - The muxed id identifying the sub-account of `to`.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientBalance`] - When `from` does
+    ///   not have enough tokens.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `MuxedTransferData { amount: i128, to_muxed_id: u64 }`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn transfer_muxed(e: &Env, from: Address, to: Address, to_muxed_id: u64, amount: i128);
+}
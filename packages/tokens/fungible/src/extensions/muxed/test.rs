@@ -0,0 +1,111 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{blocklist::BlockList, fee::Fee, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn transfer_muxed_credits_receiver() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_muxed(&e, &from, &to, 42, 30);
+        assert_eq!(Base::balance(&e, &from), 70);
+        assert_eq!(Base::balance(&e, &to), 30);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_transfer_muxed(&from, &to, 42, 30);
+    });
+}
+
+#[test]
+fn transfer_without_muxed_id_emits_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &from, &to, 30);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_fungible_transfer(&from, &to, 30);
+    });
+}
+
+#[test]
+fn transfer_muxed_with_fee_tags_receiver_only() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let collector = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 1_000);
+        Fee::set_fee(&e, 100, &collector);
+    });
+
+    e.as_contract(&address, || {
+        Fee::transfer_muxed(&e, &from, &to, 7, 1_000);
+        assert_eq!(Base::balance(&e, &to), 990);
+        assert_eq!(Base::balance(&e, &collector), 10);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_fungible_transfer_muxed(&from, &to, 7, 990);
+        event_assert.assert_fungible_transfer(&from, &collector, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn transfer_muxed_runs_contract_type_checks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let blocked = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+        BlockList::block_user(&e, &blocked);
+    });
+
+    e.as_contract(&address, || {
+        BlockList::transfer_muxed(&e, &from, &blocked, 1, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn transfer_muxed_insufficient_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 100);
+        Base::transfer_muxed(&e, &from, &to, 1, 101);
+    });
+}
@@ -0,0 +1,338 @@
+//! # Partition Extension for Fungible Token
+//!
+//! The `partition` module splits the balances of security tokens into named
+//! partitions (tranches), similar to ERC-1400, e.g. `locked`, `unlocked` or
+//! `reg_s`, so that lockup classes and other restrictions can be tracked
+//! on-chain.
+//!
+//! ## Implementation Notes
+//!
+//! - **Balances**: The partitions are layered over [`crate::Base::update`], so
+//!   `balance` still returns the total balance of an account, which is the sum
+//!   of its partitions and of its tokens outside of any partition
+//!   (unpartitioned tokens).
+//! - **Enforcement**: [`Partition`] checks in `check_transfer` and `check_burn`
+//!   that the amount does not exceed the unpartitioned balance of the sender,
+//!   so partitioned tokens only move with the partition-aware functions. It can
+//!   be combined with other extensions through [`crate::Stack`]. Updates that
+//!   skip the checks (e.g. [`crate::clawback`]) take the tokens out of the
+//!   partitions in the `after_update` hook instead, so the unpartitioned
+//!   balance never becomes negative.
+//! - **Partition-aware transfers**: `transfer_by_partition` moves tokens from a
+//!   partition of the sender to the same partition of the receiver. The tokens
+//!   are taken out of the partition of the sender before the transfer, so the
+//!   transfer goes through the checks and update hooks of the contract type
+//!   like any other transfer.
+//! - **Operators**: Holders can authorize operators per partition, which can
+//!   then transfer the tokens of that partition on their behalf.
+//! - **Assignment**: Tokens are assigned to partitions when they are issued
+//!   with [`Partition::issue_by_partition`], or moved between partitions by an
+//!   authorized account with [`Partition::change_partition`], e.g. from
+//!   `locked` to `unlocked` at the end of a lockup.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+pub use storage::{Partition, PartitionStorageKey};
+
+use crate::FungibleToken;
+
+/// Partition Trait for Fungible Token
+///
+/// The `FungiblePartition` trait extends the `FungibleToken` trait to provide
+/// balances split into partitions, with partition-aware transfers and
+/// operators. This trait is designed to be used in conjunction with the
+/// `FungibleToken` trait.
+///
+/// **NOTE**
+///
+/// `change_partition` includes an additional parameter `operator: Address`.
+/// This account is the one authorizing the invocation. Having it as a
+/// parameter grants the flexibility to introduce simple or complex role-based
+/// access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// The checks are enforced by [`Partition`], so `ContractType` must be either
+/// [`Partition`] or a [`crate::Stack`] that contains it.
+pub trait FungiblePartition: FungibleToken {
+    /// Returns the amount of tokens of `account` in `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `partition` - The name of the partition.
+    fn balance_of_by_partition(e: &Env, account: Address, partition: Symbol) -> i128;
+
+    /// Returns the partitions in which `account` holds tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the partitions are being queried.
+    fn partitions_of(e: &Env, account: Address) -> Vec<Symbol>;
+
+    /// Returns whether `operator` can transfer the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    fn is_operator_for_partition(
+        e: &Env,
+        owner: Address,
+        operator: Address,
+        partition: Symbol,
+    ) -> bool;
+
+    /// Transfers `amount` of tokens from `partition` of `from` to the same
+    /// partition of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientPartitionBalance`] - When
+    ///   `from` has less than `amount` tokens in `partition`.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["transfer_by_partition", partition: Symbol, from: Address,
+    ///   to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn transfer_by_partition(e: &Env, from: Address, to: Address, partition: Symbol, amount: i128);
+
+    /// Transfers `amount` of tokens from `partition` of `from` to the same
+    /// partition of `to`, on behalf of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address of the operator.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::NotPartitionOperator`] - When `operator`
+    ///   is not authorized for `partition` of `from`.
+    /// * also refer to [`FungiblePartition::transfer_by_partition`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`FungiblePartition::transfer_by_partition`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    fn operator_transfer_by_partition(
+        e: &Env,
+        operator: Address,
+        from: Address,
+        to: Address,
+        partition: Symbol,
+        amount: i128,
+    );
+
+    /// Authorizes `operator` to transfer the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["partition_operator", partition: Symbol, owner: Address,
+    ///   operator: Address]`
+    /// * data - `[authorized: bool]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    fn authorize_operator_by_partition(
+        e: &Env,
+        owner: Address,
+        operator: Address,
+        partition: Symbol,
+    );
+
+    /// Revokes the authorization of `operator` for the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["partition_operator", partition: Symbol, owner: Address,
+    ///   operator: Address]`
+    /// * data - `[authorized: bool]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    fn revoke_operator_by_partition(e: &Env, owner: Address, operator: Address, partition: Symbol);
+
+    /// Moves `amount` of tokens of `account` from partition `from` to
+    /// partition `to`, where `None` stands for the unpartitioned tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address holding the tokens.
+    /// * `from` - The partition the tokens are moved from, or `None`.
+    /// * `to` - The partition the tokens are moved to, or `None`.
+    /// * `amount` - The amount of tokens to be moved.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::InsufficientPartitionBalance`] - When
+    ///   `account` has less than `amount` tokens in `from`.
+    /// * [`crate::FungibleTokenError::InsufficientUnpartitionedBalance`] - When
+    ///   `from` is `None` and `account` has less than `amount` unpartitioned
+    ///   tokens.
+    /// * [`crate::FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["change_partition", account: Address]`
+    /// * data - `[from: Option<Symbol>, to: Option<Symbol>, amount: i128]`
+    fn change_partition(
+        e: &Env,
+        account: Address,
+        from: Option<Symbol>,
+        to: Option<Symbol>,
+        amount: i128,
+        operator: Address,
+    );
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when tokens are transferred within a partition.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `partition` - The name of the partition.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens transferred.
+///
+/// # Events
+///
+/// * topics - `["transfer_by_partition", partition: Symbol, from: Address, to:
+///   Address]`
+/// * data - `[amount: i128]`
+pub fn emit_transfer_by_partition(
+    e: &Env,
+    partition: &Symbol,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    let topics = (Symbol::new(e, "transfer_by_partition"), partition.clone(), from, to);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when tokens are issued to a partition.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `partition` - The name of the partition.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens issued.
+///
+/// # Events
+///
+/// * topics - `["issue_by_partition", partition: Symbol, to: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_issue_by_partition(e: &Env, partition: &Symbol, to: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "issue_by_partition"), partition.clone(), to);
+    e.events().publish(topics, amount)
+}
+
+/// Emits an event when tokens of an account are moved between partitions.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address holding the tokens.
+/// * `from` - The partition the tokens are moved from, or `None`.
+/// * `to` - The partition the tokens are moved to, or `None`.
+/// * `amount` - The amount of tokens moved.
+///
+/// # Events
+///
+/// * topics - `["change_partition", account: Address]`
+/// * data - `[from: Option<Symbol>, to: Option<Symbol>, amount: i128]`
+pub fn emit_change_partition(
+    e: &Env,
+    account: &Address,
+    from: &Option<Symbol>,
+    to: &Option<Symbol>,
+    amount: i128,
+) {
+    let topics = (Symbol::new(e, "change_partition"), account);
+    e.events().publish(topics, (from.clone(), to.clone(), amount))
+}
+
+/// Emits an event when an operator is authorized or revoked for a partition.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `partition` - The name of the partition.
+/// * `owner` - The address holding the tokens.
+/// * `operator` - The address of the operator.
+/// * `authorized` - Whether `operator` is authorized.
+///
+/// # Events
+///
+/// * topics - `["partition_operator", partition: Symbol, owner: Address,
+///   operator: Address]`
+/// * data - `[authorized: bool]`
+pub fn emit_partition_operator(
+    e: &Env,
+    partition: &Symbol,
+    owner: &Address,
+    operator: &Address,
+    authorized: bool,
+) {
+    let topics = (Symbol::new(e, "partition_operator"), partition.clone(), owner, operator);
+    e.events().publish(topics, authorized)
+}
@@ -0,0 +1,552 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::partition::{
+        emit_change_partition, emit_issue_by_partition, emit_partition_operator,
+        emit_transfer_by_partition,
+    },
+    fungible::emit_transfer,
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct Partition;
+
+impl ContractOverrides for Partition {
+    fn check_transfer(e: &Env, from: &Address, _to: &Address, amount: i128) {
+        Partition::check_unpartitioned_balance(e, from, amount);
+    }
+
+    fn check_burn(e: &Env, from: &Address, amount: i128) {
+        Partition::check_unpartitioned_balance(e, from, amount);
+    }
+
+    fn after_update(e: &Env, from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        // Updates that skip the checks (e.g. clawbacks) can take partitioned
+        // tokens.
+        if let Some(from) = from {
+            Partition::remove_excess(e, from);
+        }
+    }
+}
+
+/// Storage keys for the data associated with the partition extension
+#[contracttype]
+pub enum PartitionStorageKey {
+    /// Stores the amount of tokens of an account in a partition
+    Balance(Address, Symbol),
+    /// Stores the partitions in which an account holds tokens
+    Partitions(Address),
+    /// Stores the amount of tokens of an account in all its partitions
+    Partitioned(Address),
+    /// Stores the authorization of an operator for a partition of an owner
+    Operator(Address, Address, Symbol),
+}
+
+impl Partition {
+    // ################## QUERY STATE ##################
+
+    /// Returns the amount of tokens of `account` in `partition`. Defaults to
+    /// `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `partition` - The name of the partition.
+    pub fn balance_of_by_partition(e: &Env, account: &Address, partition: &Symbol) -> i128 {
+        let key = PartitionStorageKey::Balance(account.clone(), partition.clone());
+        Partition::get_amount(e, &key)
+    }
+
+    /// Returns the partitions in which `account` holds tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the partitions are being queried.
+    pub fn partitions_of(e: &Env, account: &Address) -> Vec<Symbol> {
+        let key = PartitionStorageKey::Partitions(account.clone());
+        if let Some(partitions) = e.storage().persistent().get::<_, Vec<Symbol>>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            partitions
+        } else {
+            Vec::new(e)
+        }
+    }
+
+    /// Returns the amount of tokens of `account` in all its partitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    pub fn partitioned_balance(e: &Env, account: &Address) -> i128 {
+        Partition::get_amount(e, &PartitionStorageKey::Partitioned(account.clone()))
+    }
+
+    /// Returns the amount of tokens of `account` outside of its partitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    pub fn unpartitioned_balance(e: &Env, account: &Address) -> i128 {
+        Base::balance(e, account) - Partition::partitioned_balance(e, account)
+    }
+
+    /// Returns whether `operator` can transfer the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    pub fn is_operator_for_partition(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        partition: &Symbol,
+    ) -> bool {
+        let key = PartitionStorageKey::Operator(owner.clone(), operator.clone(), partition.clone());
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Panics if `amount` is greater than the unpartitioned balance of
+    /// `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose tokens are moved.
+    /// * `amount` - The amount of tokens moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InsufficientUnpartitionedBalance`] - When
+    ///   `amount` is greater than the unpartitioned balance of `account` while
+    ///   some of its tokens are in partitions.
+    pub fn check_unpartitioned_balance(e: &Env, account: &Address, amount: i128) {
+        let partitioned = Partition::partitioned_balance(e, account);
+        // without partitioned tokens, an insufficient balance is reported by
+        // `update`
+        if partitioned > 0 && amount > Base::balance(e, account) - partitioned {
+            panic_with_error!(e, FungibleTokenError::InsufficientUnpartitionedBalance);
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Transfers `amount` of tokens from `partition` of `from` to the same
+    /// partition of `to` with `check_transfer` and
+    /// [`ContractOverrides::update`] of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Partition::update_by_partition`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`Partition::update_by_partition`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer_by_partition<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        partition: &Symbol,
+        amount: i128,
+    ) {
+        from.require_auth();
+        Partition::update_by_partition::<T>(e, from, to, partition, amount);
+    }
+
+    /// Transfers `amount` of tokens from `partition` of `from` to the same
+    /// partition of `to` on behalf of `from`, with `check_transfer` and
+    /// [`ContractOverrides::update`] of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address of the operator.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::NotPartitionOperator`] - When `operator` is not
+    ///   authorized for `partition` of `from`.
+    /// * refer to [`Partition::update_by_partition`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`Partition::update_by_partition`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    pub fn operator_transfer_by_partition<T: ContractOverrides>(
+        e: &Env,
+        operator: &Address,
+        from: &Address,
+        to: &Address,
+        partition: &Symbol,
+        amount: i128,
+    ) {
+        operator.require_auth();
+        if !Partition::is_operator_for_partition(e, from, operator, partition) {
+            panic_with_error!(e, FungibleTokenError::NotPartitionOperator);
+        }
+        Partition::update_by_partition::<T>(e, from, to, partition, amount);
+    }
+
+    /// Authorizes `operator` to transfer the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["partition_operator", partition: Symbol, owner: Address,
+    ///   operator: Address]`
+    /// * data - `[authorized: bool]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    pub fn authorize_operator_by_partition(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        partition: &Symbol,
+    ) {
+        owner.require_auth();
+        let key = PartitionStorageKey::Operator(owner.clone(), operator.clone(), partition.clone());
+        e.storage().persistent().set(&key, &());
+        emit_partition_operator(e, partition, owner, operator, true);
+    }
+
+    /// Revokes the authorization of `operator` for the tokens of `owner` in
+    /// `partition`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address of the operator.
+    /// * `partition` - The name of the partition.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["partition_operator", partition: Symbol, owner: Address,
+    ///   operator: Address]`
+    /// * data - `[authorized: bool]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    pub fn revoke_operator_by_partition(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        partition: &Symbol,
+    ) {
+        owner.require_auth();
+        let key = PartitionStorageKey::Operator(owner.clone(), operator.clone(), partition.clone());
+        e.storage().persistent().remove(&key);
+        emit_partition_operator(e, partition, owner, operator, false);
+    }
+
+    /// Mints `amount` of tokens to `partition` of `to` with
+    /// [`ContractOverrides::mint`] of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["issue_by_partition", partition: Symbol, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations. Failure to implement proper authorization could
+    /// lead to security vulnerabilities such as unauthorized token creation.
+    pub fn issue_by_partition<T: ContractOverrides>(
+        e: &Env,
+        to: &Address,
+        partition: &Symbol,
+        amount: i128,
+    ) {
+        T::mint(e, to, amount);
+        Partition::credit(e, to, partition, amount);
+        emit_issue_by_partition(e, partition, to, amount);
+    }
+
+    /// Moves `amount` of tokens of `account` from partition `from` to
+    /// partition `to`, where `None` stands for the unpartitioned tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address holding the tokens.
+    /// * `from` - The partition the tokens are moved from, or `None`.
+    /// * `to` - The partition the tokens are moved to, or `None`.
+    /// * `amount` - The amount of tokens to be moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`FungibleTokenError::InsufficientPartitionBalance`] - When `account`
+    ///   has less than `amount` tokens in `from`.
+    /// * [`FungibleTokenError::InsufficientUnpartitionedBalance`] - When `from`
+    ///   is `None` and `account` has less than `amount` unpartitioned tokens.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["change_partition", account: Address]`
+    /// * data - `[from: Option<Symbol>, to: Option<Symbol>, amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    ///
+    /// Using this function in public-facing methods creates significant
+    /// security risks as it could allow holders to move tokens out of
+    /// restricted partitions.
+    pub fn change_partition(
+        e: &Env,
+        account: &Address,
+        from: &Option<Symbol>,
+        to: &Option<Symbol>,
+        amount: i128,
+    ) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        match from {
+            Some(partition) => Partition::debit(e, account, partition, amount),
+            None =>
+                if amount > Partition::unpartitioned_balance(e, account) {
+                    panic_with_error!(e, FungibleTokenError::InsufficientUnpartitionedBalance);
+                },
+        }
+        if let Some(partition) = to {
+            Partition::credit(e, account, partition, amount);
+        }
+
+        emit_change_partition(e, account, from, to, amount);
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Moves `amount` of tokens from `partition` of `from` to the same
+    /// partition of `to` with `check_transfer` and
+    /// [`ContractOverrides::update`] of `T`. The tokens are taken out of the
+    /// partition of `from` before the transfer, so the checks of `T` see them
+    /// as unpartitioned tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `partition` - The name of the partition.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`FungibleTokenError::InsufficientPartitionBalance`] - When `from` has
+    ///   less than `amount` tokens in `partition`.
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["transfer_by_partition", partition: Symbol, from: Address,
+    ///   to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update_by_partition<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        partition: &Symbol,
+        amount: i128,
+    ) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        Partition::debit(e, from, partition, amount);
+        T::check_transfer(e, from, to, amount);
+        T::update(e, Some(from), Some(to), amount);
+        Partition::credit(e, to, partition, amount);
+
+        emit_transfer(e, from, to, amount);
+        emit_transfer_by_partition(e, partition, from, to, amount);
+    }
+
+    /// Low-level function that takes the tokens `account` no longer holds out
+    /// of its partitions, in the order of [`Partition::partitions_of`], so
+    /// that the partitioned balance never exceeds the balance. It is called by
+    /// the `after_update` hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address whose partitions are adjusted.
+    ///
+    /// # Events
+    ///
+    /// For each adjusted partition:
+    ///
+    /// * topics - `["change_partition", account: Address]`
+    /// * data - `[from: Option<Symbol>, to: Option<Symbol>, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization, but it only removes
+    /// partitioned tokens that `account` no longer holds.
+    pub fn remove_excess(e: &Env, account: &Address) {
+        let mut excess = Partition::partitioned_balance(e, account) - Base::balance(e, account);
+        if excess <= 0 {
+            return;
+        }
+
+        for partition in Partition::partitions_of(e, account).iter() {
+            let amount = Partition::balance_of_by_partition(e, account, &partition).min(excess);
+            Partition::debit(e, account, &partition, amount);
+            emit_change_partition(e, account, &Some(partition), &None, amount);
+
+            excess -= amount;
+            if excess == 0 {
+                break;
+            }
+        }
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    fn get_amount(e: &Env, key: &PartitionStorageKey) -> i128 {
+        if let Some(amount) = e.storage().persistent().get::<_, i128>(key) {
+            e.storage().persistent().extend_ttl(key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            amount
+        } else {
+            0
+        }
+    }
+
+    fn set_amount(e: &Env, key: &PartitionStorageKey, amount: i128) {
+        if amount == 0 {
+            e.storage().persistent().remove(key);
+        } else {
+            e.storage().persistent().set(key, &amount);
+        }
+    }
+
+    fn credit(e: &Env, account: &Address, partition: &Symbol, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+
+        let balance = Partition::balance_of_by_partition(e, account, partition);
+        if balance == 0 {
+            let mut partitions = Partition::partitions_of(e, account);
+            partitions.push_back(partition.clone());
+            e.storage()
+                .persistent()
+                .set(&PartitionStorageKey::Partitions(account.clone()), &partitions);
+        }
+
+        let Some(new_balance) = balance.checked_add(amount) else {
+            panic_with_error!(e, FungibleTokenError::MathOverflow);
+        };
+        let key = PartitionStorageKey::Balance(account.clone(), partition.clone());
+        Partition::set_amount(e, &key, new_balance);
+
+        // the partitioned balance never exceeds the balance
+        let partitioned = Partition::partitioned_balance(e, account) + amount;
+        Partition::set_amount(e, &PartitionStorageKey::Partitioned(account.clone()), partitioned);
+    }
+
+    fn debit(e: &Env, account: &Address, partition: &Symbol, amount: i128) {
+        let balance = Partition::balance_of_by_partition(e, account, partition);
+        if balance < amount {
+            panic_with_error!(e, FungibleTokenError::InsufficientPartitionBalance);
+        }
+        if amount == 0 {
+            return;
+        }
+
+        let key = PartitionStorageKey::Balance(account.clone(), partition.clone());
+        Partition::set_amount(e, &key, balance - amount);
+
+        if balance == amount {
+            let mut partitions = Partition::partitions_of(e, account);
+            if let Some(index) = partitions.first_index_of(partition) {
+                partitions.remove(index);
+            }
+            let key = PartitionStorageKey::Partitions(account.clone());
+            if partitions.is_empty() {
+                e.storage().persistent().remove(&key);
+            } else {
+                e.storage().persistent().set(&key, &partitions);
+            }
+        }
+
+        let partitioned = Partition::partitioned_balance(e, account) - amount;
+        Partition::set_amount(e, &PartitionStorageKey::Partitioned(account.clone()), partitioned);
+    }
+}
@@ -0,0 +1,236 @@
+extern crate std;
+
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{blocklist::BlockList, partition::storage::Partition},
+    Base, ContractOverrides, Stack,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn issue_by_partition_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let locked = symbol_short!("locked");
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 50);
+    });
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &account, &locked, 100);
+
+        assert_eq!(Base::balance(&e, &account), 150);
+        assert_eq!(Base::total_supply(&e), 150);
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &locked), 100);
+        assert_eq!(Partition::partitioned_balance(&e, &account), 100);
+        assert_eq!(Partition::unpartitioned_balance(&e, &account), 50);
+        assert_eq!(Partition::partitions_of(&e, &account), vec![&e, locked.clone()]);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_mint(&account, 100);
+    });
+}
+
+#[test]
+fn transfer_by_partition_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let locked = symbol_short!("locked");
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &from, &locked, 100);
+    });
+
+    e.as_contract(&address, || {
+        Partition::transfer_by_partition::<Partition>(&e, &from, &to, &locked, 100);
+
+        assert_eq!(Base::balance(&e, &from), 0);
+        assert_eq!(Base::balance(&e, &to), 100);
+        assert_eq!(Partition::balance_of_by_partition(&e, &to, &locked), 100);
+        assert_eq!(Partition::partitions_of(&e, &from).len(), 0);
+        assert_eq!(Partition::partitions_of(&e, &to), vec![&e, locked.clone()]);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_transfer(&from, &to, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #139)")]
+fn transfer_of_partitioned_tokens_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 50);
+        Partition::issue_by_partition::<Partition>(&e, &from, &symbol_short!("locked"), 100);
+    });
+
+    e.as_contract(&address, || {
+        Partition::transfer(&e, &from, &to, 51);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #138)")]
+fn transfer_by_partition_exceeding_partition_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let locked = symbol_short!("locked");
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 50);
+        Partition::issue_by_partition::<Partition>(&e, &from, &locked, 100);
+    });
+
+    e.as_contract(&address, || {
+        // unpartitioned tokens cannot top up a partition transfer
+        Partition::transfer_by_partition::<Partition>(&e, &from, &to, &locked, 101);
+    });
+}
+
+#[test]
+fn operator_transfer_by_partition_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let to = Address::generate(&e);
+    let locked = symbol_short!("locked");
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &owner, &locked, 100);
+        Partition::authorize_operator_by_partition(&e, &owner, &operator, &locked);
+        assert!(Partition::is_operator_for_partition(&e, &owner, &operator, &locked));
+    });
+
+    e.as_contract(&address, || {
+        Partition::operator_transfer_by_partition::<Partition>(
+            &e, &operator, &owner, &to, &locked, 40,
+        );
+        assert_eq!(Partition::balance_of_by_partition(&e, &owner, &locked), 60);
+        assert_eq!(Partition::balance_of_by_partition(&e, &to, &locked), 40);
+    });
+
+    e.as_contract(&address, || {
+        Partition::revoke_operator_by_partition(&e, &owner, &operator, &locked);
+        assert!(!Partition::is_operator_for_partition(&e, &owner, &operator, &locked));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn operator_transfer_by_partition_of_other_partition_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let to = Address::generate(&e);
+    let locked = symbol_short!("locked");
+    let unlocked = symbol_short!("unlocked");
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &owner, &locked, 100);
+        Partition::authorize_operator_by_partition(&e, &owner, &operator, &unlocked);
+    });
+
+    e.as_contract(&address, || {
+        Partition::operator_transfer_by_partition::<Partition>(
+            &e, &operator, &owner, &to, &locked, 40,
+        );
+    });
+}
+
+#[test]
+fn change_partition_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let locked = symbol_short!("locked");
+    let unlocked = symbol_short!("unlocked");
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 20);
+        Partition::issue_by_partition::<Partition>(&e, &account, &locked, 100);
+
+        Partition::change_partition(
+            &e,
+            &account,
+            &Some(locked.clone()),
+            &Some(unlocked.clone()),
+            60,
+        );
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &locked), 40);
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &unlocked), 60);
+        assert_eq!(Partition::partitioned_balance(&e, &account), 100);
+
+        // release the rest of the lockup
+        Partition::change_partition(&e, &account, &Some(locked.clone()), &None, 40);
+        assert_eq!(Partition::partitions_of(&e, &account), vec![&e, unlocked.clone()]);
+        assert_eq!(Partition::unpartitioned_balance(&e, &account), 60);
+
+        // assign unpartitioned tokens
+        Partition::change_partition(&e, &account, &None, &Some(unlocked.clone()), 60);
+        assert_eq!(Partition::balance_of_by_partition(&e, &account, &unlocked), 120);
+        assert_eq!(Partition::unpartitioned_balance(&e, &account), 0);
+        assert_eq!(Base::balance(&e, &account), 120);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn transfer_by_partition_goes_through_stacked_checks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let locked = symbol_short!("locked");
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &from, &locked, 100);
+        BlockList::block_user(&e, &to);
+    });
+
+    e.as_contract(&address, || {
+        Partition::transfer_by_partition::<Stack<Partition, BlockList>>(
+            &e, &from, &to, &locked, 10,
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #139)")]
+fn burn_of_partitioned_tokens_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Partition::issue_by_partition::<Partition>(&e, &account, &symbol_short!("locked"), 100);
+    });
+
+    e.as_contract(&address, || {
+        <Partition as ContractOverrides>::burn(&e, &account, 1);
+    });
+}
@@ -0,0 +1,119 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_pausable::{pause, unpause};
+
+use crate::{extensions::pausable::storage::WhenNotPaused, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn transfer_works_when_not_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        WhenNotPaused::mint(&e, &user1, 100);
+        WhenNotPaused::transfer(&e, &user1, &user2, 50);
+        assert_eq!(Base::balance(&e, &user1), 50);
+        assert_eq!(Base::balance(&e, &user2), 50);
+    });
+}
+
+#[test]
+fn transfer_works_after_unpause() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user1, 100);
+        pause(&e);
+        unpause(&e);
+        WhenNotPaused::transfer(&e, &user1, &user2, 50);
+        assert_eq!(Base::balance(&e, &user2), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn transfer_panics_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user1, 100);
+        pause(&e);
+        WhenNotPaused::transfer(&e, &user1, &user2, 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn transfer_from_panics_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 50, 100);
+        pause(&e);
+        WhenNotPaused::transfer_from(&e, &spender, &owner, &recipient, 50);
+    });
+}
+
+#[test]
+fn approve_works_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        pause(&e);
+        WhenNotPaused::approve(&e, &owner, &spender, 50, 100);
+        assert_eq!(Base::allowance(&e, &owner, &spender), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn burn_panics_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user, 100);
+        pause(&e);
+        WhenNotPaused::burn(&e, &user, 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn mint_panics_when_paused() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        pause(&e);
+        WhenNotPaused::mint(&e, &user, 100);
+    });
+}
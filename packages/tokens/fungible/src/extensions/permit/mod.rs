@@ -0,0 +1,141 @@
+//! # Permit Extension for Fungible Token
+//!
+//! The `permit` module allows token holders to set allowances with an
+//! off-chain ed25519 signature instead of authorizing an `approve` invocation
+//! themselves. Any account (e.g. a relayer) can then submit the signed
+//! approval on behalf of the owner and pay for the transaction fees.
+//!
+//! ## Implementation Notes
+//!
+//! - **Signed message**: The owner signs the SHA-256 hash of the XDR encoding
+//!   of [`PermitMessage`], which can be computed with
+//!   [`crate::Base::permit_digest`]. The message includes the network id and
+//!   the address of the token contract, so a signature cannot be replayed on
+//!   another network or token.
+//! - **Nonces**: Every owner has a nonce that is part of the signed message and
+//!   is incremented with each successful permit, so a signature can be used
+//!   only once.
+//! - **Deadline**: The signature is valid up to (and including) the ledger
+//!   sequence number `deadline`.
+//! - **Signer**: The message must be signed by the ed25519 key that `owner`
+//!   registered explicitly with [`FungiblePermit::set_permit_key`], which
+//!   requires the authorization of `owner`. Owners without a registered key
+//!   cannot use permits.
+//!
+//! ## Security Considerations
+//!
+//! Authority is never derived from the `owner` address: for a "G..." account,
+//! the signature of its master key alone is NOT accepted. Accepting it would
+//! bypass the signers, weights and thresholds of the account (including a
+//! master key whose weight was set to `0`). A registered permit key, on the
+//! other hand, acts independently of the account configuration until it is
+//! replaced or removed, so owners should remove it when it is no longer needed.
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+pub use storage::{PermitMessage, PermitSignature, PermitStorageKey};
+
+use crate::FungibleToken;
+
+/// Permit Trait for Fungible Token
+///
+/// The `FungiblePermit` trait extends the `FungibleToken` trait to provide
+/// signature-based approvals. This trait is designed to be used in
+/// conjunction with the `FungibleToken` trait.
+pub trait FungiblePermit: FungibleToken {
+    /// Returns the current permit nonce of `owner`, which must be included in
+    /// the next message signed by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address for which the nonce is being queried.
+    fn nonces(e: &Env, owner: Address) -> u32;
+
+    /// Returns the ed25519 public key registered by `owner` to sign permits,
+    /// or `None` if `owner` has not registered a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address for which the permit key is being queried.
+    fn permit_key(e: &Env, owner: Address) -> Option<BytesN<32>>;
+
+    /// Registers `public_key` as the ed25519 key allowed to sign permits on
+    /// behalf of `owner`, replacing any previously registered key. Passing
+    /// `None` removes the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address registering the key.
+    /// * `public_key` - The ed25519 public key, or `None` to remove it.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["permit_key", owner: Address]`
+    /// * data - `[public_key: Option<BytesN<32>>]`
+    fn set_permit_key(e: &Env, owner: Address, public_key: Option<BytesN<32>>);
+
+    /// Sets the amount of tokens a `spender` is allowed to spend on behalf of
+    /// an `owner`, authorized by a signature of the permit key of `owner`
+    /// instead of `owner.require_auth()`. Overrides any existing allowance set
+    /// between `spender` and `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens made available to `spender`.
+    /// * `live_until_ledger` - The ledger number at which the allowance
+    ///   expires.
+    /// * `deadline` - The last ledger number at which the signature is valid.
+    /// * `signature` - The ed25519 signature of the permit key of `owner` over
+    ///   the permit digest.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::PermitExpired`] - When the current ledger
+    ///   number is greater than `deadline`.
+    /// * [`crate::FungibleTokenError::InvalidPermitSigner`] - When `owner` has
+    ///   not registered a permit key, or the public key of `signature` is not
+    ///   the registered one.
+    /// * also refer to [`crate::Base::set_allowance`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    fn permit(
+        e: &Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        live_until_ledger: u32,
+        deadline: u32,
+        signature: PermitSignature,
+    );
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when the permit key of an owner is set or removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address whose permit key changed.
+/// * `public_key` - The new permit key, or `None` if it was removed.
+///
+/// # Events
+///
+/// * topics - `["permit_key", owner: Address]`
+/// * data - `[public_key: Option<BytesN<32>>]`
+pub fn emit_permit_key_set(e: &Env, owner: &Address, public_key: &Option<BytesN<32>>) {
+    let topics = (Symbol::new(e, "permit_key"), owner);
+    e.events().publish(topics, public_key.clone())
+}
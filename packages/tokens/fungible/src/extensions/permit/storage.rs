@@ -0,0 +1,216 @@
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, BytesN, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::permit::emit_permit_key_set, fungible::emit_approve, Base, ContractOverrides,
+    FungibleTokenError,
+};
+
+/// Storage keys for the data associated with the permit extension
+#[contracttype]
+pub enum PermitStorageKey {
+    /// Stores the permit nonce of an owner
+    Nonce(Address),
+    /// Stores the ed25519 public key registered by an owner for permits
+    PermitKey(Address),
+}
+
+/// Storage container for an ed25519 signature and the public key that
+/// produced it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermitSignature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+/// The message signed by the owner to authorize a permit. The owner signs
+/// the SHA-256 hash of its XDR encoding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermitMessage {
+    pub network_id: BytesN<32>,
+    pub contract: Address,
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub live_until_ledger: u32,
+    pub nonce: u32,
+    pub deadline: u32,
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the current permit nonce of `owner`. Defaults to `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address for which the nonce is being queried.
+    pub fn nonces(e: &Env, owner: &Address) -> u32 {
+        let key = PermitStorageKey::Nonce(owner.clone());
+        if let Some(nonce) = e.storage().persistent().get::<_, u32>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            nonce
+        } else {
+            0
+        }
+    }
+
+    /// Returns the ed25519 public key registered by `owner` to sign permits,
+    /// or `None` if `owner` has not registered a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address for which the permit key is being queried.
+    pub fn permit_key(e: &Env, owner: &Address) -> Option<BytesN<32>> {
+        let key = PermitStorageKey::PermitKey(owner.clone());
+        let public_key = e.storage().persistent().get::<_, BytesN<32>>(&key);
+        if public_key.is_some() {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        }
+        public_key
+    }
+
+    /// Returns the digest that `owner` has to sign to authorize a permit with
+    /// the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens made available to `spender`.
+    /// * `live_until_ledger` - The ledger number at which the allowance
+    ///   expires.
+    /// * `nonce` - The permit nonce of `owner`.
+    /// * `deadline` - The last ledger number at which the signature is valid.
+    pub fn permit_digest(
+        e: &Env,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        live_until_ledger: u32,
+        nonce: u32,
+        deadline: u32,
+    ) -> BytesN<32> {
+        let message = PermitMessage {
+            network_id: e.ledger().network_id(),
+            contract: e.current_contract_address(),
+            owner: owner.clone(),
+            spender: spender.clone(),
+            amount,
+            live_until_ledger,
+            nonce,
+            deadline,
+        };
+        e.crypto().sha256(&message.to_xdr(e)).to_bytes()
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Registers `public_key` as the ed25519 key allowed to sign permits on
+    /// behalf of `owner`, replacing any previously registered key. Passing
+    /// `None` removes the key, which disables permits for `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address registering the key.
+    /// * `public_key` - The ed25519 public key, or `None` to remove it.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["permit_key", owner: Address]`
+    /// * data - `[public_key: Option<BytesN<32>>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    pub fn set_permit_key(e: &Env, owner: &Address, public_key: &Option<BytesN<32>>) {
+        owner.require_auth();
+
+        let key = PermitStorageKey::PermitKey(owner.clone());
+        match public_key {
+            Some(public_key) => e.storage().persistent().set(&key, public_key),
+            None => e.storage().persistent().remove(&key),
+        }
+
+        emit_permit_key_set(e, owner, public_key);
+    }
+
+    /// Sets the amount of tokens a `spender` is allowed to spend on behalf of
+    /// an `owner`, authorized by an ed25519 signature of the permit key
+    /// registered by `owner` with [`Base::set_permit_key`]. Overrides
+    /// any existing allowance set between `spender` and `owner`. The
+    /// allowance goes through `check_approve` and `after_approve` of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens made available to `spender`.
+    /// * `live_until_ledger` - The ledger number at which the allowance
+    ///   expires.
+    /// * `deadline` - The last ledger number at which the signature is valid.
+    /// * `signature` - The ed25519 signature of the permit key of `owner` over
+    ///   [`Base::permit_digest`].
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::PermitExpired`] - When the current ledger number
+    ///   is greater than `deadline`.
+    /// * [`FungibleTokenError::InvalidPermitSigner`] - When `owner` has not
+    ///   registered a permit key, or the public key of `signature` is not the
+    ///   registered one.
+    /// * refer to [`ContractOverrides::check_approve`] errors.
+    /// * refer to [`Base::set_allowance`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is NOT required, the signature replaces it.
+    /// The invocation panics with a host error if the signature is invalid.
+    ///
+    /// Authority is never derived from the `owner` address itself: a "G..."
+    /// account is not authorized by the signature of its master key, since
+    /// that would bypass the signers, weights and thresholds of the account.
+    /// Only the key registered with [`Base::set_permit_key`] is accepted.
+    pub fn permit<T: ContractOverrides>(
+        e: &Env,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        live_until_ledger: u32,
+        deadline: u32,
+        signature: &PermitSignature,
+    ) {
+        if e.ledger().sequence() > deadline {
+            panic_with_error!(e, FungibleTokenError::PermitExpired);
+        }
+        if Base::permit_key(e, owner).as_ref() != Some(&signature.public_key) {
+            panic_with_error!(e, FungibleTokenError::InvalidPermitSigner);
+        }
+        T::check_approve(e, owner, spender, amount, live_until_ledger);
+
+        let nonce = Base::nonces(e, owner);
+        let digest =
+            Base::permit_digest(e, owner, spender, amount, live_until_ledger, nonce, deadline);
+        e.crypto().ed25519_verify(&signature.public_key, &digest.into(), &signature.signature);
+
+        // NOTE: overflowing requires 2^32 permits from the same owner.
+        let key = PermitStorageKey::Nonce(owner.clone());
+        e.storage().persistent().set(&key, &(nonce + 1));
+
+        Base::set_allowance(e, owner, spender, amount, live_until_ledger);
+        emit_approve(e, owner, spender, amount, live_until_ledger);
+        T::after_approve(e, owner, spender, amount, live_until_ledger);
+    }
+}
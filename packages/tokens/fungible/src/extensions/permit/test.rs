@@ -0,0 +1,271 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    allowance::EnumerableAllowances, blocklist::BlockList, extensions::permit::PermitSignature,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[
+        157, 97, 177, 157, 239, 253, 90, 96, 186, 132, 74, 244, 146, 236, 44, 196, 68, 73, 197,
+        105, 123, 50, 105, 25, 112, 59, 172, 3, 28, 174, 127, 96,
+    ])
+}
+
+fn sign(e: &Env, key: &SigningKey, digest: &BytesN<32>) -> PermitSignature {
+    PermitSignature {
+        public_key: BytesN::from_array(e, key.verifying_key().as_bytes()),
+        signature: BytesN::from_array(e, &key.sign(&digest.to_array()).to_bytes()),
+    }
+}
+
+fn public_key(e: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(e, key.verifying_key().as_bytes())
+}
+
+#[test]
+fn set_permit_key_registers_and_removes_key() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let public_key = public_key(&e, &signing_key());
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::permit_key(&e, &owner), None);
+
+        Base::set_permit_key(&e, &owner, &Some(public_key.clone()));
+        assert_eq!(Base::permit_key(&e, &owner), Some(public_key.clone()));
+        EventAssertion::new(&e, address.clone()).assert_event_count(1);
+    });
+
+    e.as_contract(&address, || {
+        Base::set_permit_key(&e, &owner, &None);
+        assert_eq!(Base::permit_key(&e, &owner), None);
+    });
+}
+
+#[test]
+fn permit_sets_allowance_and_increments_nonce() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        assert_eq!(Base::nonces(&e, &owner), 0);
+
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+
+        assert_eq!(Base::allowance(&e, &owner, &spender), 100);
+        assert_eq!(Base::nonces(&e, &owner), 1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        // the permit key registration and the approval
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_approve(&owner, &spender, 100, 1000);
+    });
+}
+
+#[test]
+fn permit_allowance_can_be_spent_by_relayer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        Base::mint(&e, &owner, 100);
+
+        let digest = Base::permit_digest(&e, &owner, &spender, 60, 1000, 0, 10);
+        Base::permit::<Base>(&e, &owner, &spender, 60, 1000, 10, &sign(&e, &key, &digest));
+
+        Base::transfer_from(&e, &spender, &owner, &recipient, 60);
+        assert_eq!(Base::balance(&e, &recipient), 60);
+        assert_eq!(Base::allowance(&e, &owner, &spender), 0);
+    });
+}
+
+#[test]
+#[should_panic]
+fn permit_replay_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+        let signature = sign(&e, &key, &digest);
+
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &signature);
+        // the nonce has been consumed, so the signature is no longer valid
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn permit_with_tampered_amount_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        Base::permit::<Base>(&e, &owner, &spender, 1000, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn permit_after_deadline_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        e.ledger().set_sequence_number(11);
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn permit_signed_by_other_key_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let other_key = SigningKey::from_bytes(&[7; 32]);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &other_key)));
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
+
+#[test]
+fn permit_runs_after_approve_hook() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+        Base::permit::<EnumerableAllowances>(
+            &e,
+            &owner,
+            &spender,
+            100,
+            1000,
+            10,
+            &sign(&e, &key, &digest),
+        );
+
+        assert_eq!(EnumerableAllowances::spender_count(&e, &owner), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn permit_from_blocked_owner_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let owner = Address::generate(&e);
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+        BlockList::block_user(&e, &owner);
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        Base::permit::<BlockList>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn permit_signed_by_master_key_of_owner_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let spender = Address::generate(&e);
+    // the "G..." account address of the all-7s secret key, which has not
+    // registered a permit key
+    let owner = Address::from_string(&String::from_str(
+        &e,
+        "GDVEU3DD4KOFECV66VIHWEZOYX4ZKR3WV27L464SIIPOU2IUI3JCZA57",
+    ));
+
+    e.as_contract(&address, || {
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn permit_after_key_removed_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let key = signing_key();
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_permit_key(&e, &owner, &Some(public_key(&e, &key)));
+    });
+
+    e.as_contract(&address, || {
+        Base::set_permit_key(&e, &owner, &None);
+        let digest = Base::permit_digest(&e, &owner, &spender, 100, 1000, 0, 10);
+
+        Base::permit::<Base>(&e, &owner, &spender, 100, 1000, 10, &sign(&e, &key, &digest));
+    });
+}
@@ -0,0 +1,104 @@
+//! # Snapshot Extension for Fungible Token
+//!
+//! The `snapshot` module records the balances of all accounts and the total
+//! supply at numbered snapshots, so that they can be queried on-chain later
+//! on, e.g. to compute dividends or airdrops without indexing the `transfer`
+//! events off-chain.
+//!
+//! ## Implementation Notes
+//!
+//! - **Snapshot ids**: Snapshots are numbered starting from `1`. A new snapshot
+//!   is created with [`Snapshot::snapshot`], which does not record any value by
+//!   itself.
+//! - **Lazy recording**: The value of a balance (or of the total supply) at a
+//!   snapshot is recorded on the first change after that snapshot was created,
+//!   in the `before_update` hook of [`Snapshot`]. A balance that has not
+//!   changed since a snapshot is still equal to the current value, so creating
+//!   a snapshot costs the same regardless of the number of holders.
+//! - **Historical lookups**: `balance_of_at` and `total_supply_at` use a binary
+//!   search over the recorded values, so the cost of a lookup grows
+//!   logarithmically with the number of recorded values.
+//!
+//! ## Usage
+//!
+//! - **IMPORTANT**: Every change of balances must go through the functions of
+//!   [`crate::ContractOverrides`] for a contract type that includes
+//!   [`Snapshot`] (e.g. [`Snapshot::mint`] instead of [`crate::Base::mint`]) or
+//!   must be preceded by a call to [`Snapshot::update_snapshots`], otherwise
+//!   the recorded values will be wrong.
+//! - [`Snapshot::snapshot`] does not enforce authorization, so it has to be
+//!   exposed by the contract with its own access control.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env};
+pub use storage::{Snapshot, SnapshotStorageKey, SnapshotValue};
+
+use crate::FungibleToken;
+
+/// Snapshot Trait for Fungible Token
+///
+/// The `FungibleSnapshot` trait extends the `FungibleToken` trait to provide
+/// historical balances and total supply at numbered snapshots. This trait is
+/// designed to be used in conjunction with the `FungibleToken` trait.
+///
+/// The values are recorded by [`Snapshot`], so `ContractType` must be either
+/// [`Snapshot`] or a [`crate::Stack`] that contains it.
+pub trait FungibleSnapshot: FungibleToken {
+    /// Returns the id of the latest snapshot, or `0` if no snapshot has been
+    /// created yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn current_snapshot_id(e: &Env) -> u32;
+
+    /// Returns the balance of `account` at the time the snapshot
+    /// `snapshot_id` was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::SnapshotInvalidId`] - When `snapshot_id`
+    ///   is `0` or greater than the id of the latest snapshot.
+    fn balance_of_at(e: &Env, account: Address, snapshot_id: u32) -> i128;
+
+    /// Returns the total supply at the time the snapshot `snapshot_id` was
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::SnapshotInvalidId`] - When `snapshot_id`
+    ///   is `0` or greater than the id of the latest snapshot.
+    fn total_supply_at(e: &Env, snapshot_id: u32) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a snapshot is created.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `snapshot_id` - The id of the new snapshot.
+///
+/// # Events
+///
+/// * topics - `["snapshot"]`
+/// * data - `[snapshot_id: u32]`
+pub fn emit_snapshot(e: &Env, snapshot_id: u32) {
+    let topics = (symbol_short!("snapshot"),);
+    e.events().publish(topics, snapshot_id)
+}
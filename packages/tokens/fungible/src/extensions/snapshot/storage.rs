@@ -0,0 +1,300 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{
+    extensions::snapshot::emit_snapshot,
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct Snapshot;
+
+impl ContractOverrides for Snapshot {
+    fn before_update(e: &Env, from: Option<&Address>, to: Option<&Address>, _amount: i128) {
+        Snapshot::update_snapshots(e, from, to);
+    }
+}
+
+/// Storage container for a balance (or total supply) and the id of the
+/// snapshot it belongs to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotValue {
+    pub id: u32,
+    pub value: i128,
+}
+
+/// Storage keys for the data associated with the snapshot extension
+#[contracttype]
+pub enum SnapshotStorageKey {
+    /// Stores the id of the latest snapshot
+    CurrentId,
+    /// Stores the number of recorded balances of an account
+    NumAccountValues(Address),
+    /// Stores a recorded balance of an account
+    AccountValue(Address /* account */, u32 /* index */),
+    /// Stores the number of recorded total supplies
+    NumTotalSupplyValues,
+    /// Stores a recorded total supply
+    TotalSupplyValue(u32 /* index */),
+}
+
+impl Snapshot {
+    // ################## QUERY STATE ##################
+
+    /// Returns the id of the latest snapshot, or `0` if no snapshot has been
+    /// created yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn current_snapshot_id(e: &Env) -> u32 {
+        e.storage().instance().get(&SnapshotStorageKey::CurrentId).unwrap_or(0)
+    }
+
+    /// Returns the balance of `account` at the time the snapshot
+    /// `snapshot_id` was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::SnapshotInvalidId`] - When `snapshot_id` is `0`
+    ///   or greater than the id of the latest snapshot.
+    pub fn balance_of_at(e: &Env, account: &Address, snapshot_id: u32) -> i128 {
+        check_snapshot_id(e, snapshot_id);
+        let len = get_len(e, &SnapshotStorageKey::NumAccountValues(account.clone()));
+        lower_lookup(e, len, snapshot_id, |index| {
+            SnapshotStorageKey::AccountValue(account.clone(), index)
+        })
+        .unwrap_or_else(|| Base::balance(e, account))
+    }
+
+    /// Returns the total supply at the time the snapshot `snapshot_id` was
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::SnapshotInvalidId`] - When `snapshot_id` is `0`
+    ///   or greater than the id of the latest snapshot.
+    pub fn total_supply_at(e: &Env, snapshot_id: u32) -> i128 {
+        check_snapshot_id(e, snapshot_id);
+        let len = get_len(e, &SnapshotStorageKey::NumTotalSupplyValues);
+        lower_lookup(e, len, snapshot_id, SnapshotStorageKey::TotalSupplyValue)
+            .unwrap_or_else(|| Base::total_supply(e))
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MathOverflow`] - When the snapshot id overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["snapshot"]`
+    /// * data - `[snapshot_id: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can create
+    /// snapshots.
+    pub fn snapshot(e: &Env) -> u32 {
+        let Some(id) = Snapshot::current_snapshot_id(e).checked_add(1) else {
+            panic_with_error!(e, FungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&SnapshotStorageKey::CurrentId, &id);
+        emit_snapshot(e, id);
+        id
+    }
+
+    /// Records the current balances of `from` and `to` and the current total
+    /// supply for the latest snapshot, if they have not been recorded since
+    /// it was created.
+    ///
+    /// Called by the `before_update` hook of [`Snapshot`]. Must be called
+    /// before every balance change that does not go through
+    /// [`ContractOverrides::update`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address whose balance decreases, or `None` for mints.
+    /// * `to` - The address whose balance increases, or `None` for burns.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update_snapshots(e: &Env, from: Option<&Address>, to: Option<&Address>) {
+        let id = Snapshot::current_snapshot_id(e);
+        if id == 0 {
+            return;
+        }
+
+        if from.is_none() || to.is_none() {
+            record_value(
+                e,
+                id,
+                &SnapshotStorageKey::NumTotalSupplyValues,
+                SnapshotStorageKey::TotalSupplyValue,
+                || Base::total_supply(e),
+            );
+        }
+        for account in [from, to].into_iter().flatten() {
+            record_value(
+                e,
+                id,
+                &SnapshotStorageKey::NumAccountValues(account.clone()),
+                |index| SnapshotStorageKey::AccountValue(account.clone(), index),
+                || Base::balance(e, account),
+            );
+        }
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// This is a wrapper around [`Base::transfer()`] that also records the
+    /// balances of `from` and `to` for the latest snapshot.
+    ///
+    /// Please refer to [`Base::transfer`] for the inline documentation.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        <Snapshot as ContractOverrides>::transfer(e, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::transfer_from()`] that also records
+    /// the balances of `from` and `to` for the latest snapshot.
+    ///
+    /// Please refer to [`Base::transfer_from`] for the inline documentation.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        <Snapshot as ContractOverrides>::transfer_from(e, spender, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::mint()`] that also records the
+    /// balance of `to` and the total supply for the latest snapshot.
+    ///
+    /// Please refer to [`Base::mint`] for the inline documentation.
+    pub fn mint(e: &Env, to: &Address, amount: i128) {
+        <Snapshot as ContractOverrides>::mint(e, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::snapshot::FungibleSnapshot`]
+    ///
+    /// Please refer to [`Base::burn`] for the inline documentation.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        <Snapshot as ContractOverrides>::burn(e, from, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn_from()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::snapshot::FungibleSnapshot`]
+    ///
+    /// Please refer to [`Base::burn_from`] for the inline documentation.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        <Snapshot as ContractOverrides>::burn_from(e, spender, from, amount);
+    }
+}
+
+// ################## HELPERS ##################
+
+/// Panics if the snapshot `snapshot_id` has not been created.
+fn check_snapshot_id(e: &Env, snapshot_id: u32) {
+    if snapshot_id == 0 || snapshot_id > Snapshot::current_snapshot_id(e) {
+        panic_with_error!(e, FungibleTokenError::SnapshotInvalidId);
+    }
+}
+
+/// Returns the number of values stored under `len_key`, extending its TTL if
+/// present.
+fn get_len(e: &Env, len_key: &SnapshotStorageKey) -> u32 {
+    if let Some(len) = e.storage().persistent().get::<_, u32>(len_key) {
+        e.storage().persistent().extend_ttl(
+            len_key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        len
+    } else {
+        0
+    }
+}
+
+/// Returns the value stored under `key`, extending its TTL.
+///
+/// NOTE: callers only query indices below the stored length, so the value is
+/// expected to exist.
+fn get_value(e: &Env, key: &SnapshotStorageKey) -> SnapshotValue {
+    let value: SnapshotValue = e
+        .storage()
+        .persistent()
+        .get(key)
+        .expect("values are recorded for every index below the length");
+    e.storage().persistent().extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+    value
+}
+
+/// Returns the first value recorded for a snapshot id greater than or equal
+/// to `snapshot_id`, or `None` if there is none (i.e. the value has not
+/// changed since that snapshot).
+fn lower_lookup(
+    e: &Env,
+    len: u32,
+    snapshot_id: u32,
+    value_key: impl Fn(u32) -> SnapshotStorageKey,
+) -> Option<i128> {
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if get_value(e, &value_key(mid)).id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == len {
+        None
+    } else {
+        Some(get_value(e, &value_key(low)).value)
+    }
+}
+
+/// Records the result of `current` for the snapshot `id`, unless a value has
+/// already been recorded for it.
+fn record_value(
+    e: &Env,
+    id: u32,
+    len_key: &SnapshotStorageKey,
+    value_key: impl Fn(u32) -> SnapshotStorageKey,
+    current: impl FnOnce() -> i128,
+) {
+    let len = get_len(e, len_key);
+    if len > 0 && get_value(e, &value_key(len - 1)).id >= id {
+        return;
+    }
+
+    let value = SnapshotValue { id, value: current() };
+    e.storage().persistent().set(&value_key(len), &value);
+    e.storage().persistent().set(len_key, &(len + 1));
+}
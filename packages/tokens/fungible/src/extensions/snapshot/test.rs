@@ -0,0 +1,152 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::snapshot::storage::{Snapshot, SnapshotStorageKey},
+    votes::Votes,
+    Base, Stack,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn snapshot_increments_id() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(Snapshot::current_snapshot_id(&e), 0);
+        assert_eq!(Snapshot::snapshot(&e), 1);
+        assert_eq!(Snapshot::snapshot(&e), 2);
+        assert_eq!(Snapshot::current_snapshot_id(&e), 2);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn balances_and_total_supply_at_snapshots() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Snapshot::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e);
+        Snapshot::transfer(&e, &alice, &bob, 30);
+        Snapshot::snapshot(&e);
+        Snapshot::mint(&e, &bob, 50);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 1), 100);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, 1), 0);
+        assert_eq!(Snapshot::total_supply_at(&e, 1), 100);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 2), 70);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, 2), 30);
+        assert_eq!(Snapshot::total_supply_at(&e, 2), 100);
+
+        assert_eq!(Base::balance(&e, &bob), 80);
+        assert_eq!(Base::total_supply(&e), 150);
+    });
+}
+
+#[test]
+fn unchanged_balance_returns_current_value() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Snapshot::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e);
+        Snapshot::snapshot(&e);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 1), 100);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 2), 100);
+        assert_eq!(Snapshot::total_supply_at(&e, 2), 100);
+    });
+}
+
+#[test]
+fn values_are_recorded_once_per_snapshot() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        // no snapshot yet, nothing to record
+        Snapshot::mint(&e, &alice, 100);
+        assert!(!e
+            .storage()
+            .persistent()
+            .has(&SnapshotStorageKey::NumAccountValues(alice.clone())));
+
+        Snapshot::snapshot(&e);
+        Snapshot::mint(&e, &alice, 10);
+        Snapshot::burn(&e, &alice, 20);
+
+        let len: u32 = e
+            .storage()
+            .persistent()
+            .get(&SnapshotStorageKey::NumAccountValues(alice.clone()))
+            .unwrap();
+        assert_eq!(len, 1);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 1), 100);
+        assert_eq!(Snapshot::total_supply_at(&e, 1), 100);
+        assert_eq!(Base::total_supply(&e), 90);
+    });
+}
+
+#[test]
+fn snapshot_stacks_with_votes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::delegate(&e, &alice, &alice);
+    });
+
+    e.as_contract(&address, || {
+        <Stack<Snapshot, Votes> as crate::ContractOverrides>::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e);
+        <Stack<Snapshot, Votes> as crate::ContractOverrides>::mint(&e, &alice, 50);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, 1), 100);
+        assert_eq!(Votes::get_votes(&e, &alice), 150);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #128)")]
+fn balance_of_at_zero_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Snapshot::snapshot(&e);
+        Snapshot::balance_of_at(&e, &alice, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #128)")]
+fn total_supply_at_future_snapshot_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Snapshot::snapshot(&e);
+        Snapshot::total_supply_at(&e, 2);
+    });
+}
@@ -0,0 +1,327 @@
+//! # Vault Extension for Fungible Token
+//!
+//! The `vault` module turns a fungible token into a tokenized vault, similar
+//! to the ERC-4626 standard: the token represents shares of an underlying
+//! SEP-41 asset held by the contract. Accounts deposit assets in exchange for
+//! shares, and redeem shares in exchange for assets.
+//!
+//! ## Implementation Notes
+//!
+//! - **Exchange rate**: Assets and shares are converted with the ratio between
+//!   the total supply of shares and the amount of assets held by the vault.
+//! - **Rounding**: Conversions always round in favor of the vault, i.e. an
+//!   account never receives more shares (or assets) than it is entitled to, and
+//!   never pays fewer assets (or shares) than it has to. Custom flows can
+//!   choose the rounding direction with [`Vault::convert_to_shares_rounded`]
+//!   and [`Vault::convert_to_assets_rounded`].
+//! - **Inflation attack protection**: The conversions use a virtual offset of
+//!   `10^decimals_offset` shares and `1` asset, which makes the "donation" (or
+//!   inflation) attack unprofitable for the attacker. A greater offset
+//!   increases the cost of the attack, at the expense of a higher precision of
+//!   the shares. The offset can be configured with
+//!   [`Vault::set_decimals_offset`].
+//! - **Decimals**: The decimals of the shares should be set to the decimals of
+//!   the asset plus the decimals offset when setting the token metadata.
+//!
+//! ## Usage
+//!
+//! The underlying asset has to be set once with [`Vault::set_asset`], usually
+//! in the constructor of the contract. Shares are minted and burned with the
+//! `check_mint`, `check_burn` and [`crate::ContractOverrides::update`] of the
+//! contract type passed to the functions of [`Vault`], so the vault can be
+//! combined with contract types that keep track of balance changes (e.g.
+//! [`crate::votes::Votes`]).
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env};
+pub use storage::{Rounding, Vault, VaultStorageKey, MAX_DECIMALS_OFFSET};
+
+use crate::FungibleToken;
+
+/// Vault Trait for Fungible Token
+///
+/// The `FungibleVault` trait extends the `FungibleToken` trait to provide the
+/// functionality of a tokenized vault over an underlying SEP-41 asset. This
+/// trait is designed to be used in conjunction with the `FungibleToken` trait.
+pub trait FungibleVault: FungibleToken {
+    /// Returns the address of the underlying asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultAssetNotSet`] - When the asset has
+    ///   not been set.
+    fn query_asset(e: &Env) -> Address;
+
+    /// Returns the total amount of the underlying asset held by the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultAssetNotSet`] - When the asset has
+    ///   not been set.
+    fn total_assets(e: &Env) -> i128;
+
+    /// Returns the amount of shares that the vault would exchange for
+    /// `assets`, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to convert.
+    fn convert_to_shares(e: &Env, assets: i128) -> i128;
+
+    /// Returns the amount of assets that the vault would exchange for
+    /// `shares`, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to convert.
+    fn convert_to_assets(e: &Env, shares: i128) -> i128;
+
+    /// Returns the maximum amount of assets that can be deposited for
+    /// `receiver`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the shares.
+    fn max_deposit(e: &Env, receiver: Address) -> i128;
+
+    /// Returns the amount of shares that a deposit of `assets` would mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to deposit.
+    fn preview_deposit(e: &Env, assets: i128) -> i128;
+
+    /// Deposits `assets` of the underlying asset from `from` and mints the
+    /// corresponding shares to `receiver`. Returns the amount of shares
+    /// minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to deposit.
+    /// * `receiver` - The address receiving the shares.
+    /// * `from` - The address providing the assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultExceededMaxDeposit`] - When `assets`
+    ///   is greater than [`FungibleVault::max_deposit`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit", from: Address, receiver: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn deposit(e: &Env, assets: i128, receiver: Address, from: Address) -> i128;
+
+    /// Returns the maximum amount of shares that can be minted for
+    /// `receiver`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the shares.
+    fn max_mint(e: &Env, receiver: Address) -> i128;
+
+    /// Returns the amount of assets that minting `shares` would cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to mint.
+    fn preview_mint(e: &Env, shares: i128) -> i128;
+
+    /// Mints exactly `shares` to `receiver` by depositing the required
+    /// amount of the underlying asset from `from`. Returns the amount of
+    /// assets deposited.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to mint.
+    /// * `receiver` - The address receiving the shares.
+    /// * `from` - The address providing the assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultExceededMaxMint`] - When `shares` is
+    ///   greater than [`FungibleVault::max_mint`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit", from: Address, receiver: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn mint(e: &Env, shares: i128, receiver: Address, from: Address) -> i128;
+
+    /// Returns the maximum amount of assets that can be withdrawn from the
+    /// shares of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the shares.
+    fn max_withdraw(e: &Env, owner: Address) -> i128;
+
+    /// Returns the amount of shares that a withdrawal of `assets` would burn.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to withdraw.
+    fn preview_withdraw(e: &Env, assets: i128) -> i128;
+
+    /// Burns the shares of `owner` required to withdraw exactly `assets` of
+    /// the underlying asset to `receiver`. Returns the amount of shares
+    /// burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to withdraw.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address holding the shares.
+    /// * `operator` - The address authorizing the withdrawal, either `owner` or
+    ///   an address having an allowance over the shares of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultExceededMaxWithdraw`] - When
+    ///   `assets` is greater than [`FungibleVault::max_withdraw`].
+    /// * [`crate::FungibleTokenError::InsufficientAllowance`] - When `operator`
+    ///   is not `owner` and does not have enough allowance.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    fn withdraw(
+        e: &Env,
+        assets: i128,
+        receiver: Address,
+        owner: Address,
+        operator: Address,
+    ) -> i128;
+
+    /// Returns the maximum amount of shares that `owner` can redeem.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the shares.
+    fn max_redeem(e: &Env, owner: Address) -> i128;
+
+    /// Returns the amount of assets that redeeming `shares` would return.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to redeem.
+    fn preview_redeem(e: &Env, shares: i128) -> i128;
+
+    /// Burns exactly `shares` of `owner` and sends the corresponding amount
+    /// of the underlying asset to `receiver`. Returns the amount of assets
+    /// sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to redeem.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address holding the shares.
+    /// * `operator` - The address authorizing the redemption, either `owner` or
+    ///   an address having an allowance over the shares of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::VaultExceededMaxRedeem`] - When `shares`
+    ///   is greater than [`FungibleVault::max_redeem`].
+    /// * [`crate::FungibleTokenError::InsufficientAllowance`] - When `operator`
+    ///   is not `owner` and does not have enough allowance.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    fn redeem(e: &Env, shares: i128, receiver: Address, owner: Address, operator: Address) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when assets are deposited into the vault.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address providing the assets.
+/// * `receiver` - The address receiving the shares.
+/// * `assets` - The amount of assets deposited.
+/// * `shares` - The amount of shares minted.
+///
+/// # Events
+///
+/// * topics - `["deposit", from: Address, receiver: Address]`
+/// * data - `[assets: i128, shares: i128]`
+pub fn emit_deposit(e: &Env, from: &Address, receiver: &Address, assets: i128, shares: i128) {
+    let topics = (symbol_short!("deposit"), from, receiver);
+    e.events().publish(topics, (assets, shares))
+}
+
+/// Emits an event when assets are withdrawn from the vault.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `operator` - The address authorizing the withdrawal.
+/// * `receiver` - The address receiving the assets.
+/// * `owner` - The address whose shares are burned.
+/// * `assets` - The amount of assets withdrawn.
+/// * `shares` - The amount of shares burned.
+///
+/// # Events
+///
+/// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+///   Address]`
+/// * data - `[assets: i128, shares: i128]`
+pub fn emit_withdraw(
+    e: &Env,
+    operator: &Address,
+    receiver: &Address,
+    owner: &Address,
+    assets: i128,
+    shares: i128,
+) {
+    let topics = (symbol_short!("withdraw"), operator, receiver, owner);
+    e.events().publish(topics, (assets, shares))
+}
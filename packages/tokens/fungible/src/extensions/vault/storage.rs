@@ -0,0 +1,597 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env, I256};
+
+use crate::{
+    burnable::emit_burn,
+    extensions::vault::{emit_deposit, emit_withdraw},
+    fungible::emit_mint,
+    Base, ContractOverrides, FungibleTokenError,
+};
+
+/// The maximum decimals offset, so that the virtual shares fit comfortably in
+/// an `i128`.
+pub const MAX_DECIMALS_OFFSET: u32 = 18;
+
+/// Storage keys for the data associated with the vault extension
+#[contracttype]
+pub enum VaultStorageKey {
+    /// Stores the address of the underlying asset
+    Asset,
+    /// Stores the decimals offset used for the virtual shares
+    DecimalsOffset,
+}
+
+/// The direction in which the result of a conversion is rounded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rounding {
+    /// Round towards zero.
+    Floor,
+    /// Round away from zero.
+    Ceil,
+}
+
+pub struct Vault;
+
+impl Vault {
+    // ################## QUERY STATE ##################
+
+    /// Returns the address of the underlying asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultAssetNotSet`] - When the asset has not been
+    ///   set.
+    pub fn query_asset(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&VaultStorageKey::Asset)
+            .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::VaultAssetNotSet))
+    }
+
+    /// Returns the decimals offset used for the virtual shares. Defaults to
+    /// `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn decimals_offset(e: &Env) -> u32 {
+        e.storage().instance().get(&VaultStorageKey::DecimalsOffset).unwrap_or(0)
+    }
+
+    /// Returns the total amount of the underlying asset held by the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::query_asset`] errors.
+    pub fn total_assets(e: &Env) -> i128 {
+        TokenClient::new(e, &Vault::query_asset(e)).balance(&e.current_contract_address())
+    }
+
+    /// Returns the amount of shares that the vault would exchange for
+    /// `assets`, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to convert.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::convert_to_shares_rounded`] errors.
+    pub fn convert_to_shares(e: &Env, assets: i128) -> i128 {
+        Vault::convert_to_shares_rounded(e, assets, Rounding::Floor)
+    }
+
+    /// Returns the amount of assets that the vault would exchange for
+    /// `shares`, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to convert.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::convert_to_assets_rounded`] errors.
+    pub fn convert_to_assets(e: &Env, shares: i128) -> i128 {
+        Vault::convert_to_assets_rounded(e, shares, Rounding::Floor)
+    }
+
+    /// Returns the amount of shares that the vault would exchange for
+    /// `assets`, rounded in the direction of `rounding`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to convert.
+    /// * `rounding` - The rounding direction.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `assets < 0`.
+    /// * [`FungibleTokenError::MathOverflow`] - When the result does not fit in
+    ///   an `i128`.
+    /// * refer to [`Vault::query_asset`] errors.
+    pub fn convert_to_shares_rounded(e: &Env, assets: i128, rounding: Rounding) -> i128 {
+        let total_supply = I256::from_i128(e, Base::total_supply(e))
+            .add(&I256::from_i128(e, 10).pow(Vault::decimals_offset(e)));
+        let total_assets = I256::from_i128(e, Vault::total_assets(e)).add(&I256::from_i128(e, 1));
+        mul_div(e, assets, &total_supply, &total_assets, rounding)
+    }
+
+    /// Returns the amount of assets that the vault would exchange for
+    /// `shares`, rounded in the direction of `rounding`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to convert.
+    /// * `rounding` - The rounding direction.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `shares < 0`.
+    /// * [`FungibleTokenError::MathOverflow`] - When the result does not fit in
+    ///   an `i128`.
+    /// * refer to [`Vault::query_asset`] errors.
+    pub fn convert_to_assets_rounded(e: &Env, shares: i128, rounding: Rounding) -> i128 {
+        let total_supply = I256::from_i128(e, Base::total_supply(e))
+            .add(&I256::from_i128(e, 10).pow(Vault::decimals_offset(e)));
+        let total_assets = I256::from_i128(e, Vault::total_assets(e)).add(&I256::from_i128(e, 1));
+        mul_div(e, shares, &total_assets, &total_supply, rounding)
+    }
+
+    /// Returns the maximum amount of assets that can be deposited for
+    /// `receiver`. Unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the shares.
+    pub fn max_deposit(_e: &Env, _receiver: &Address) -> i128 {
+        i128::MAX
+    }
+
+    /// Returns the maximum amount of shares that can be minted for
+    /// `receiver`. Unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the shares.
+    pub fn max_mint(_e: &Env, _receiver: &Address) -> i128 {
+        i128::MAX
+    }
+
+    /// Returns the maximum amount of assets that can be withdrawn from the
+    /// shares of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the shares.
+    pub fn max_withdraw(e: &Env, owner: &Address) -> i128 {
+        Vault::convert_to_assets(e, Base::balance(e, owner))
+    }
+
+    /// Returns the maximum amount of shares that `owner` can redeem.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the shares.
+    pub fn max_redeem(e: &Env, owner: &Address) -> i128 {
+        Base::balance(e, owner)
+    }
+
+    /// Returns the amount of shares that a deposit of `assets` would mint,
+    /// rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to deposit.
+    pub fn preview_deposit(e: &Env, assets: i128) -> i128 {
+        Vault::convert_to_shares_rounded(e, assets, Rounding::Floor)
+    }
+
+    /// Returns the amount of assets that minting `shares` would cost, rounded
+    /// up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to mint.
+    pub fn preview_mint(e: &Env, shares: i128) -> i128 {
+        Vault::convert_to_assets_rounded(e, shares, Rounding::Ceil)
+    }
+
+    /// Returns the amount of shares that a withdrawal of `assets` would burn,
+    /// rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to withdraw.
+    pub fn preview_withdraw(e: &Env, assets: i128) -> i128 {
+        Vault::convert_to_shares_rounded(e, assets, Rounding::Ceil)
+    }
+
+    /// Returns the amount of assets that redeeming `shares` would return,
+    /// rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to redeem.
+    pub fn preview_redeem(e: &Env, shares: i128) -> i128 {
+        Vault::convert_to_assets_rounded(e, shares, Rounding::Floor)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the underlying asset of the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `asset` - The address of the SEP-41 asset.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultAssetAlreadySet`] - When the asset has
+    ///   already been set.
+    ///
+    /// # Notes
+    ///
+    /// We recommend using this function in the constructor of your smart
+    /// contract.
+    pub fn set_asset(e: &Env, asset: &Address) {
+        if e.storage().instance().has(&VaultStorageKey::Asset) {
+            panic_with_error!(e, FungibleTokenError::VaultAssetAlreadySet);
+        }
+        e.storage().instance().set(&VaultStorageKey::Asset, asset);
+    }
+
+    /// Sets the decimals offset used for the virtual shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `offset` - The decimals offset.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultInvalidDecimalsOffset`] - When `offset` is
+    ///   greater than [`MAX_DECIMALS_OFFSET`].
+    ///
+    /// # Notes
+    ///
+    /// Changing the offset changes the exchange rate, so it should only be set
+    /// in the constructor of your smart contract.
+    pub fn set_decimals_offset(e: &Env, offset: u32) {
+        if offset > MAX_DECIMALS_OFFSET {
+            panic_with_error!(e, FungibleTokenError::VaultInvalidDecimalsOffset);
+        }
+        e.storage().instance().set(&VaultStorageKey::DecimalsOffset, &offset);
+    }
+
+    /// Deposits `assets` of the underlying asset from `from` and mints the
+    /// corresponding shares to `receiver`. Returns the amount of shares
+    /// minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to deposit.
+    /// * `receiver` - The address receiving the shares.
+    /// * `from` - The address providing the assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultExceededMaxDeposit`] - When `assets` is
+    ///   greater than [`Vault::max_deposit`].
+    /// * refer to [`Vault::convert_to_shares_rounded`] errors.
+    /// * refer to [`Vault::deposit_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["deposit", from: Address, receiver: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn deposit<T: ContractOverrides>(
+        e: &Env,
+        assets: i128,
+        receiver: &Address,
+        from: &Address,
+    ) -> i128 {
+        from.require_auth();
+        if assets > Vault::max_deposit(e, receiver) {
+            panic_with_error!(e, FungibleTokenError::VaultExceededMaxDeposit);
+        }
+        let shares = Vault::preview_deposit(e, assets);
+        Vault::deposit_assets::<T>(e, from, receiver, assets, shares);
+        shares
+    }
+
+    /// Mints exactly `shares` to `receiver` by depositing the required amount
+    /// of the underlying asset from `from`. Returns the amount of assets
+    /// deposited.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to mint.
+    /// * `receiver` - The address receiving the shares.
+    /// * `from` - The address providing the assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultExceededMaxMint`] - When `shares` is
+    ///   greater than [`Vault::max_mint`].
+    /// * refer to [`Vault::convert_to_assets_rounded`] errors.
+    /// * refer to [`Vault::deposit_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["deposit", from: Address, receiver: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn mint<T: ContractOverrides>(
+        e: &Env,
+        shares: i128,
+        receiver: &Address,
+        from: &Address,
+    ) -> i128 {
+        from.require_auth();
+        if shares > Vault::max_mint(e, receiver) {
+            panic_with_error!(e, FungibleTokenError::VaultExceededMaxMint);
+        }
+        let assets = Vault::preview_mint(e, shares);
+        Vault::deposit_assets::<T>(e, from, receiver, assets, shares);
+        assets
+    }
+
+    /// Burns the shares of `owner` required to withdraw exactly `assets` of
+    /// the underlying asset to `receiver`. Returns the amount of shares
+    /// burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of assets to withdraw.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address holding the shares.
+    /// * `operator` - The address authorizing the withdrawal.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultExceededMaxWithdraw`] - When `assets` is
+    ///   greater than [`Vault::max_withdraw`].
+    /// * refer to [`Vault::convert_to_shares_rounded`] errors.
+    /// * refer to [`Vault::withdraw_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required. If `operator` is not `owner`,
+    /// the shares are deducted from the allowance of `operator` over the
+    /// shares of `owner`.
+    pub fn withdraw<T: ContractOverrides>(
+        e: &Env,
+        assets: i128,
+        receiver: &Address,
+        owner: &Address,
+        operator: &Address,
+    ) -> i128 {
+        operator.require_auth();
+        if assets > Vault::max_withdraw(e, owner) {
+            panic_with_error!(e, FungibleTokenError::VaultExceededMaxWithdraw);
+        }
+        let shares = Vault::preview_withdraw(e, assets);
+        Vault::withdraw_assets::<T>(e, operator, receiver, owner, assets, shares);
+        shares
+    }
+
+    /// Burns exactly `shares` of `owner` and sends the corresponding amount of
+    /// the underlying asset to `receiver`. Returns the amount of assets sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to redeem.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address holding the shares.
+    /// * `operator` - The address authorizing the redemption.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::VaultExceededMaxRedeem`] - When `shares` is
+    ///   greater than [`Vault::max_redeem`].
+    /// * refer to [`Vault::convert_to_assets_rounded`] errors.
+    /// * refer to [`Vault::withdraw_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required. If `operator` is not `owner`,
+    /// the shares are deducted from the allowance of `operator` over the
+    /// shares of `owner`.
+    pub fn redeem<T: ContractOverrides>(
+        e: &Env,
+        shares: i128,
+        receiver: &Address,
+        owner: &Address,
+        operator: &Address,
+    ) -> i128 {
+        operator.require_auth();
+        if shares > Vault::max_redeem(e, owner) {
+            panic_with_error!(e, FungibleTokenError::VaultExceededMaxRedeem);
+        }
+        let assets = Vault::preview_redeem(e, shares);
+        Vault::withdraw_assets::<T>(e, operator, receiver, owner, assets, shares);
+        assets
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Low-level function that transfers `assets` from `from` to the vault
+    /// and mints `shares` to `receiver` with `check_mint` and
+    /// [`ContractOverrides::update`] of `T`, without handling authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address providing the assets.
+    /// * `receiver` - The address receiving the shares.
+    /// * `assets` - The amount of assets to deposit.
+    /// * `shares` - The amount of shares to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `assets < 0`.
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["deposit", from: Address, receiver: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// The transfer of the underlying asset requires the authorization of
+    /// `from` in the asset contract.
+    pub fn deposit_assets<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        receiver: &Address,
+        assets: i128,
+        shares: i128,
+    ) {
+        if assets < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        T::check_mint(e, receiver, shares);
+        // The assets are transferred before minting, as in ERC-4626, so that
+        // a malicious asset cannot observe the vault with minted but unbacked
+        // shares.
+        TokenClient::new(e, &Vault::query_asset(e)).transfer(
+            from,
+            &e.current_contract_address(),
+            &assets,
+        );
+        T::update(e, None, Some(receiver), shares);
+        emit_mint(e, receiver, shares);
+        emit_deposit(e, from, receiver, assets, shares);
+    }
+
+    /// Low-level function that burns `shares` of `owner` with `check_burn` and
+    /// [`ContractOverrides::update`] of `T` and transfers `assets` from the
+    /// vault to `receiver`, without handling authorization.
+    /// If `operator` is not `owner`, the shares are deducted from the
+    /// allowance of `operator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address authorizing the withdrawal.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address holding the shares.
+    /// * `assets` - The amount of assets to withdraw.
+    /// * `shares` - The amount of shares to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `assets < 0`.
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    pub fn withdraw_assets<T: ContractOverrides>(
+        e: &Env,
+        operator: &Address,
+        receiver: &Address,
+        owner: &Address,
+        assets: i128,
+        shares: i128,
+    ) {
+        if assets < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        T::check_burn(e, owner, shares);
+        if operator != owner {
+            Base::spend_allowance(e, owner, operator, shares);
+        }
+        // The shares are burned before transferring the assets, as in ERC-4626.
+        T::update(e, Some(owner), None, shares);
+        emit_burn(e, owner, shares);
+        TokenClient::new(e, &Vault::query_asset(e)).transfer(
+            &e.current_contract_address(),
+            receiver,
+            &assets,
+        );
+        emit_withdraw(e, operator, receiver, owner, assets, shares);
+    }
+}
+
+// ################## HELPERS ##################
+
+/// Returns `x * numerator / denominator` rounded in the direction of
+/// `rounding`. The intermediate product is computed with 256 bits, so it
+/// cannot overflow.
+fn mul_div(e: &Env, x: i128, numerator: &I256, denominator: &I256, rounding: Rounding) -> i128 {
+    if x < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+    let product = I256::from_i128(e, x).mul(numerator);
+    let mut result = product.div(denominator);
+    if rounding == Rounding::Ceil && product.rem_euclid(denominator) != I256::from_i128(e, 0) {
+        result = result.add(&I256::from_i128(e, 1));
+    }
+    result.to_i128().unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
@@ -0,0 +1,281 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    blocklist::BlockList,
+    extensions::vault::storage::{Rounding, Vault},
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, users: &[&Address], amount: i128) -> (Address, Address) {
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    for user in users {
+        StellarAssetClient::new(e, &asset).mint(user, &amount);
+    }
+    let vault = e.register(MockContract, ());
+    e.as_contract(&vault, || Vault::set_asset(e, &asset));
+    (vault, asset)
+}
+
+#[test]
+fn deposit_and_redeem_at_initial_rate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (vault, asset) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::query_asset(&e), asset);
+        assert_eq!(Vault::deposit::<Base>(&e, 100, &user, &user), 100);
+        assert_eq!(Base::balance(&e, &user), 100);
+        assert_eq!(Vault::total_assets(&e), 100);
+        assert_eq!(Vault::max_withdraw(&e, &user), 100);
+        assert_eq!(Vault::max_redeem(&e, &user), 100);
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::redeem::<Base>(&e, 40, &user, &user, &user), 40);
+        assert_eq!(Base::balance(&e, &user), 60);
+        assert_eq!(Base::total_supply(&e), 60);
+    });
+    assert_eq!(TokenClient::new(&e, &asset).balance(&user), 940);
+}
+
+#[test]
+fn deposit_emits_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &receiver, &user);
+
+        let mut event_assert = EventAssertion::new(&e, vault.clone());
+        // asset transfer + mint + deposit
+        event_assert.assert_event_count(3);
+        event_assert.assert_fungible_mint(&receiver, 100);
+    });
+}
+
+#[test]
+fn mint_and_withdraw_round_in_favor_of_vault() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let (vault, asset) = setup(&e, &[&user1, &user2], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &user1, &user1);
+    });
+    // yield accrues to the vault: 1 share is now worth ~2 assets
+    StellarAssetClient::new(&e, &asset).mint(&vault, &100);
+
+    e.as_contract(&vault, || {
+        // depositing 10 assets mints 10 * 101 / 201 = 5.02 -> 5 shares
+        assert_eq!(Vault::preview_deposit(&e, 10), 5);
+        assert_eq!(Vault::convert_to_shares_rounded(&e, 10, Rounding::Ceil), 6);
+        // minting 10 shares costs 10 * 201 / 101 = 19.9 -> 20 assets
+        assert_eq!(Vault::preview_mint(&e, 10), 20);
+        assert_eq!(Vault::mint::<Base>(&e, 10, &user2, &user2), 20);
+        assert_eq!(Base::balance(&e, &user2), 10);
+    });
+
+    e.as_contract(&vault, || {
+        // withdrawing 19 assets burns ceil(19 * 111 / 221) = 10 shares
+        assert_eq!(Vault::preview_withdraw(&e, 19), 10);
+        assert_eq!(Vault::withdraw::<Base>(&e, 19, &user2, &user2, &user2), 10);
+        assert_eq!(Base::balance(&e, &user2), 0);
+    });
+}
+
+#[test]
+fn withdraw_by_operator_spends_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    let (vault, asset) = setup(&e, &[&owner], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &owner, &owner);
+    });
+
+    e.as_contract(&vault, || {
+        Base::approve(&e, &owner, &operator, 50, 100);
+    });
+
+    e.as_contract(&vault, || {
+        Vault::withdraw::<Base>(&e, 30, &receiver, &owner, &operator);
+        assert_eq!(Base::allowance(&e, &owner, &operator), 20);
+        assert_eq!(Base::balance(&e, &owner), 70);
+    });
+    assert_eq!(TokenClient::new(&e, &asset).balance(&receiver), 30);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn redeem_by_operator_without_allowance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&owner], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &owner, &owner);
+    });
+
+    e.as_contract(&vault, || {
+        Vault::redeem::<Base>(&e, 10, &operator, &owner, &operator);
+    });
+}
+
+#[test]
+fn decimals_offset_mitigates_inflation_attack() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attacker = Address::generate(&e);
+    let victim = Address::generate(&e);
+    let (vault, asset) = setup(&e, &[&attacker, &victim], 1_000_000);
+    e.as_contract(&vault, || Vault::set_decimals_offset(&e, 6));
+
+    e.as_contract(&vault, || {
+        // the attacker deposits 1 unit and donates a large amount to the vault
+        assert_eq!(Vault::deposit::<Base>(&e, 1, &attacker, &attacker), 1_000_000);
+    });
+    TokenClient::new(&e, &asset).transfer(&attacker, &vault, &10_000);
+
+    e.as_contract(&vault, || {
+        // the victim still receives shares for their deposit
+        assert!(Vault::deposit::<Base>(&e, 10_000, &victim, &victim) > 0);
+        // and can redeem almost all of it
+        assert!(Vault::max_withdraw(&e, &victim) >= 9_990);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #118)")]
+fn set_asset_twice_panics() {
+    let e = Env::default();
+    let (vault, asset) = setup(&e, &[], 0);
+
+    e.as_contract(&vault, || {
+        Vault::set_asset(&e, &asset);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #119)")]
+fn query_asset_not_set_panics() {
+    let e = Env::default();
+    let vault = e.register(MockContract, ());
+
+    e.as_contract(&vault, || {
+        Vault::query_asset(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn invalid_decimals_offset_panics() {
+    let e = Env::default();
+    let vault = e.register(MockContract, ());
+
+    e.as_contract(&vault, || {
+        Vault::set_decimals_offset(&e, 19);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #123)")]
+fn withdraw_more_than_max_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &user, &user);
+    });
+
+    e.as_contract(&vault, || {
+        Vault::withdraw::<Base>(&e, 101, &user, &user, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn redeem_more_than_max_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, 100, &user, &user);
+    });
+
+    e.as_contract(&vault, || {
+        Vault::redeem::<Base>(&e, 101, &user, &user, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn negative_deposit_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<Base>(&e, -1, &user, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn deposit_to_blocked_receiver_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        BlockList::block_user(&e, &receiver);
+        Vault::deposit::<BlockList>(&e, 100, &receiver, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn redeem_from_blocked_owner_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (vault, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&vault, || {
+        Vault::deposit::<BlockList>(&e, 100, &user, &user);
+    });
+
+    e.as_contract(&vault, || {
+        BlockList::block_user(&e, &user);
+        Vault::redeem::<BlockList>(&e, 10, &user, &user, &user);
+    });
+}
@@ -0,0 +1,167 @@
+//! # Votes Extension for Fungible Token
+//!
+//! The `votes` module keeps track of the voting power of accounts, making the
+//! token usable as a governance token. Voting power is derived from token
+//! balances, but it is only taken into account once it is delegated: an
+//! account that wants to vote with its own tokens must delegate to itself.
+//!
+//! ## Implementation Notes
+//!
+//! - **Checkpoints**: Every time the voting power of a delegate or the total
+//!   supply changes, a checkpoint is pushed with the current ledger sequence
+//!   number. Multiple changes within the same ledger overwrite the last
+//!   checkpoint instead of creating a new one.
+//! - **Historical lookups**: `get_past_votes` and `get_past_total_supply` use a
+//!   binary search over the checkpoints, so the cost of a lookup grows
+//!   logarithmically with the number of checkpoints.
+//! - **Finalized ledgers only**: Historical lookups are only permitted for
+//!   ledgers strictly before the current one, because the values for the
+//!   current ledger can still change within the same ledger.
+//!
+//! ## Usage
+//!
+//! - **IMPORTANT**: Every change of balances must go through the functions of
+//!   [`crate::ContractOverrides`] for a contract type that includes [`Votes`]
+//!   (e.g. [`Votes::mint`] instead of [`crate::Base::mint`]) or must be
+//!   followed by a call to [`Votes::transfer_voting_units`], otherwise the
+//!   voting power will get out of sync with the balances.
+//! - The voting units are moved in the `after_update` hook, so [`Votes`] can be
+//!   combined with other extensions through [`crate::Stack`].
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol};
+pub use storage::{Checkpoint, Votes, VotesStorageKey};
+
+use crate::FungibleToken;
+
+/// Votes Trait for Fungible Token
+///
+/// The `FungibleVotes` trait extends the `FungibleToken` trait to provide
+/// delegation of voting power and historical checkpoints of both the voting
+/// power of each account and the total supply. This trait is designed to be
+/// used in conjunction with the `FungibleToken` trait.
+///
+/// Tokens do not count as votes until their holder delegates them, either to
+/// another account or to itself.
+///
+/// The voting units are tracked by [`Votes`], so `ContractType` must be either
+/// [`Votes`] or a [`crate::Stack`] that contains it.
+pub trait FungibleVotes: FungibleToken {
+    /// Returns the current amount of votes delegated to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the votes are being queried.
+    fn get_votes(e: &Env, account: Address) -> i128;
+
+    /// Returns the amount of votes that were delegated to `account` at the
+    /// end of the ledger `ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the votes are being queried.
+    /// * `ledger` - The ledger sequence number to query.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::FutureLookup`] - When `ledger` is not
+    ///   strictly less than the current ledger sequence number.
+    fn get_past_votes(e: &Env, account: Address, ledger: u32) -> i128;
+
+    /// Returns the total supply of tokens at the end of the ledger `ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ledger` - The ledger sequence number to query.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::FutureLookup`] - When `ledger` is not
+    ///   strictly less than the current ledger sequence number.
+    fn get_past_total_supply(e: &Env, ledger: u32) -> i128;
+
+    /// Returns the delegate chosen by `account`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the delegate is being queried.
+    fn delegates(e: &Env, account: Address) -> Option<Address>;
+
+    /// Delegates all the voting power of `account` to `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address delegating its voting power.
+    /// * `delegatee` - The address receiving the voting power.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["delegate_changed", delegator: Address]`
+    /// * data - `[from_delegate: Option<Address>, to_delegate: Address]`
+    ///
+    /// and for each delegate whose voting power changed:
+    ///
+    /// * topics - `["delegate_votes_changed", delegate: Address]`
+    /// * data - `[previous_votes: i128, new_votes: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `account` is required.
+    fn delegate(e: &Env, account: Address, delegatee: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when an account changes its delegate.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `delegator` - The address changing its delegate.
+/// * `from_delegate` - The previous delegate, if any.
+/// * `to_delegate` - The new delegate.
+///
+/// # Events
+///
+/// * topics - `["delegate_changed", delegator: Address]`
+/// * data - `[from_delegate: Option<Address>, to_delegate: Address]`
+pub fn emit_delegate_changed(
+    e: &Env,
+    delegator: &Address,
+    from_delegate: Option<Address>,
+    to_delegate: &Address,
+) {
+    let topics = (Symbol::new(e, "delegate_changed"), delegator);
+    e.events().publish(topics, (from_delegate, to_delegate.clone()))
+}
+
+/// Emits an event when the voting power of a delegate changes.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `delegate` - The address whose voting power changed.
+/// * `previous_votes` - The voting power before the change.
+/// * `new_votes` - The voting power after the change.
+///
+/// # Events
+///
+/// * topics - `["delegate_votes_changed", delegate: Address]`
+/// * data - `[previous_votes: i128, new_votes: i128]`
+pub fn emit_delegate_votes_changed(
+    e: &Env,
+    delegate: &Address,
+    previous_votes: i128,
+    new_votes: i128,
+) {
+    let topics = (Symbol::new(e, "delegate_votes_changed"), delegate);
+    e.events().publish(topics, (previous_votes, new_votes))
+}
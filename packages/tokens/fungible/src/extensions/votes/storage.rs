@@ -0,0 +1,425 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{
+    extensions::votes::{emit_delegate_changed, emit_delegate_votes_changed},
+    overrides::{Base, ContractOverrides},
+    FungibleTokenError,
+};
+
+pub struct Votes;
+
+impl ContractOverrides for Votes {
+    fn after_update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        Votes::transfer_voting_units(e, from, to, amount);
+    }
+}
+
+/// Storage container for an amount of votes (or total supply) and the ledger
+/// sequence number at which it was recorded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub votes: i128,
+}
+
+/// Storage keys for the data associated with the votes extension
+#[contracttype]
+pub enum VotesStorageKey {
+    /// Stores the delegate of an account
+    Delegatee(Address),
+    /// Stores the number of checkpoints of a delegate
+    NumCheckpoints(Address),
+    /// Stores a checkpoint of a delegate
+    DelegateCheckpoint(Address /* delegate */, u32 /* index */),
+    /// Stores the number of total supply checkpoints
+    NumTotalSupplyCheckpoints,
+    /// Stores a checkpoint of the total supply
+    TotalSupplyCheckpoint(u32 /* index */),
+}
+
+impl Votes {
+    // ################## QUERY STATE ##################
+
+    /// Returns the current amount of votes delegated to `account`. Defaults
+    /// to `0` if `account` has never been delegated to.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the votes are being queried.
+    pub fn get_votes(e: &Env, account: &Address) -> i128 {
+        let len = get_len(e, &VotesStorageKey::NumCheckpoints(account.clone()));
+        if len == 0 {
+            return 0;
+        }
+        get_checkpoint(e, &VotesStorageKey::DelegateCheckpoint(account.clone(), len - 1)).votes
+    }
+
+    /// Returns the amount of votes that were delegated to `account` at the
+    /// end of the ledger `ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the votes are being queried.
+    /// * `ledger` - The ledger sequence number to query.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::FutureLookup`] - When `ledger` is not strictly
+    ///   less than the current ledger sequence number.
+    pub fn get_past_votes(e: &Env, account: &Address, ledger: u32) -> i128 {
+        check_past_ledger(e, ledger);
+        let len = get_len(e, &VotesStorageKey::NumCheckpoints(account.clone()));
+        upper_lookup(e, len, ledger, |index| {
+            VotesStorageKey::DelegateCheckpoint(account.clone(), index)
+        })
+    }
+
+    /// Returns the total supply of tokens at the end of the ledger `ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ledger` - The ledger sequence number to query.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::FutureLookup`] - When `ledger` is not strictly
+    ///   less than the current ledger sequence number.
+    pub fn get_past_total_supply(e: &Env, ledger: u32) -> i128 {
+        check_past_ledger(e, ledger);
+        let len = get_len(e, &VotesStorageKey::NumTotalSupplyCheckpoints);
+        upper_lookup(e, len, ledger, VotesStorageKey::TotalSupplyCheckpoint)
+    }
+
+    /// Returns the delegate chosen by `account`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the delegate is being queried.
+    pub fn delegates(e: &Env, account: &Address) -> Option<Address> {
+        let key = VotesStorageKey::Delegatee(account.clone());
+        let delegatee = e.storage().persistent().get::<_, Address>(&key);
+        if delegatee.is_some() {
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_EXTEND_AMOUNT,
+            );
+        }
+        delegatee
+    }
+
+    /// Returns the number of checkpoints recorded for `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the checkpoints are being queried.
+    pub fn num_checkpoints(e: &Env, account: &Address) -> u32 {
+        get_len(e, &VotesStorageKey::NumCheckpoints(account.clone()))
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Delegates all the voting power of `account` to `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address delegating its voting power.
+    /// * `delegatee` - The address receiving the voting power.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Votes::set_delegate`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["delegate_changed", delegator: Address]`
+    /// * data - `[from_delegate: Option<Address>, to_delegate: Address]`
+    ///
+    /// and for each delegate whose voting power changed:
+    ///
+    /// * topics - `["delegate_votes_changed", delegate: Address]`
+    /// * data - `[previous_votes: i128, new_votes: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `account` is required.
+    pub fn delegate(e: &Env, account: &Address, delegatee: &Address) {
+        account.require_auth();
+        Votes::set_delegate(e, account, delegatee);
+    }
+
+    /// Delegates all the voting power of `account` to `delegatee` and moves
+    /// the votes from the previous delegate (if any) to `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address delegating its voting power.
+    /// * `delegatee` - The address receiving the voting power.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MathOverflow`] - When the votes of `delegatee`
+    ///   overflow.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["delegate_changed", delegator: Address]`
+    /// * data - `[from_delegate: Option<Address>, to_delegate: Address]`
+    ///
+    /// and for each delegate whose voting power changed:
+    ///
+    /// * topics - `["delegate_votes_changed", delegate: Address]`
+    /// * data - `[previous_votes: i128, new_votes: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn set_delegate(e: &Env, account: &Address, delegatee: &Address) {
+        let from_delegate = Votes::delegates(e, account);
+
+        let key = VotesStorageKey::Delegatee(account.clone());
+        e.storage().persistent().set(&key, delegatee);
+
+        emit_delegate_changed(e, account, from_delegate.clone(), delegatee);
+
+        move_delegate_votes(e, from_delegate.as_ref(), Some(delegatee), Base::balance(e, account));
+    }
+
+    /// Moves `amount` of voting units from the delegate of `from` to the
+    /// delegate of `to`, and records the change in total supply when minting
+    /// (`from` is `None`) or burning (`to` is `None`).
+    ///
+    /// Called by the `after_update` hook of [`Votes`]. Must be called after
+    /// every balance change that does not go through
+    /// [`ContractOverrides::update`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address whose balance decreased, or `None` for mints.
+    /// * `to` - The address whose balance increased, or `None` for burns.
+    /// * `amount` - The amount of voting units moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MathOverflow`] - When the recorded total supply
+    ///   or the votes of a delegate overflow.
+    ///
+    /// # Events
+    ///
+    /// For each delegate whose voting power changed:
+    ///
+    /// * topics - `["delegate_votes_changed", delegate: Address]`
+    /// * data - `[previous_votes: i128, new_votes: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn transfer_voting_units(
+        e: &Env,
+        from: Option<&Address>,
+        to: Option<&Address>,
+        amount: i128,
+    ) {
+        if from.is_none() {
+            push_checkpoint(
+                e,
+                &VotesStorageKey::NumTotalSupplyCheckpoints,
+                VotesStorageKey::TotalSupplyCheckpoint,
+                amount,
+            );
+        }
+        if to.is_none() {
+            push_checkpoint(
+                e,
+                &VotesStorageKey::NumTotalSupplyCheckpoints,
+                VotesStorageKey::TotalSupplyCheckpoint,
+                -amount,
+            );
+        }
+
+        let from_delegate = from.and_then(|account| Votes::delegates(e, account));
+        let to_delegate = to.and_then(|account| Votes::delegates(e, account));
+        move_delegate_votes(e, from_delegate.as_ref(), to_delegate.as_ref(), amount);
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// This is a wrapper around [`Base::transfer()`] that also moves the
+    /// voting power of the delegates of `from` and `to`.
+    ///
+    /// Please refer to [`Base::transfer`] for the inline documentation.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        <Votes as ContractOverrides>::transfer(e, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::transfer_from()`] that also moves the
+    /// voting power of the delegates of `from` and `to`.
+    ///
+    /// Please refer to [`Base::transfer_from`] for the inline documentation.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        <Votes as ContractOverrides>::transfer_from(e, spender, from, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::mint()`] that also increases the
+    /// voting power of the delegate of `to` and records the new total
+    /// supply.
+    ///
+    /// Please refer to [`Base::mint`] for the inline documentation.
+    pub fn mint(e: &Env, to: &Address, amount: i128) {
+        <Votes as ContractOverrides>::mint(e, to, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::votes::FungibleVotes`]
+    ///
+    /// Please refer to [`Base::burn`] for the inline documentation.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        <Votes as ContractOverrides>::burn(e, from, amount);
+    }
+
+    /// This is a wrapper around [`Base::burn_from()`] to enable
+    /// the compatibility across [`crate::burnable::FungibleBurnable`]
+    /// with [`crate::votes::FungibleVotes`]
+    ///
+    /// Please refer to [`Base::burn_from`] for the inline documentation.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        <Votes as ContractOverrides>::burn_from(e, spender, from, amount);
+    }
+}
+
+// ################## HELPERS ##################
+
+/// Panics if `ledger` is not strictly before the current ledger.
+fn check_past_ledger(e: &Env, ledger: u32) {
+    if ledger >= e.ledger().sequence() {
+        panic_with_error!(e, FungibleTokenError::FutureLookup);
+    }
+}
+
+/// Returns the number of checkpoints stored under `len_key`, extending its TTL
+/// if present.
+fn get_len(e: &Env, len_key: &VotesStorageKey) -> u32 {
+    if let Some(len) = e.storage().persistent().get::<_, u32>(len_key) {
+        e.storage().persistent().extend_ttl(
+            len_key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        len
+    } else {
+        0
+    }
+}
+
+/// Returns the checkpoint stored under `key`, extending its TTL.
+///
+/// NOTE: callers only query indices below the stored length, so the checkpoint
+/// is expected to exist.
+fn get_checkpoint(e: &Env, key: &VotesStorageKey) -> Checkpoint {
+    let checkpoint: Checkpoint = e
+        .storage()
+        .persistent()
+        .get(key)
+        .expect("checkpoints are recorded for every index below the length");
+    e.storage().persistent().extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+    checkpoint
+}
+
+/// Returns the votes of the last checkpoint recorded at or before `ledger`,
+/// or `0` if there is none.
+fn upper_lookup(
+    e: &Env,
+    len: u32,
+    ledger: u32,
+    checkpoint_key: impl Fn(u32) -> VotesStorageKey,
+) -> i128 {
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if get_checkpoint(e, &checkpoint_key(mid)).ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    if high == 0 {
+        0
+    } else {
+        get_checkpoint(e, &checkpoint_key(high - 1)).votes
+    }
+}
+
+/// Adds `delta` to the latest checkpoint stored under `len_key` and records
+/// the result for the current ledger. Returns the previous and the new votes.
+fn push_checkpoint(
+    e: &Env,
+    len_key: &VotesStorageKey,
+    checkpoint_key: impl Fn(u32) -> VotesStorageKey,
+    delta: i128,
+) -> (i128, i128) {
+    let ledger = e.ledger().sequence();
+    let len = get_len(e, len_key);
+
+    let last = if len > 0 { Some(get_checkpoint(e, &checkpoint_key(len - 1))) } else { None };
+    let previous = last.as_ref().map_or(0, |checkpoint| checkpoint.votes);
+    let Some(votes) = previous.checked_add(delta) else {
+        panic_with_error!(e, FungibleTokenError::MathOverflow);
+    };
+
+    let checkpoint = Checkpoint { ledger, votes };
+    match last {
+        // Multiple changes within the same ledger overwrite the last checkpoint.
+        Some(last) if last.ledger == ledger => {
+            e.storage().persistent().set(&checkpoint_key(len - 1), &checkpoint);
+        }
+        _ => {
+            e.storage().persistent().set(&checkpoint_key(len), &checkpoint);
+            e.storage().persistent().set(len_key, &(len + 1));
+        }
+    }
+
+    (previous, votes)
+}
+
+/// Moves `amount` of votes from `from` to `to` and emits an event for each
+/// delegate whose voting power changed.
+fn move_delegate_votes(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    if from == to || amount == 0 {
+        return;
+    }
+
+    if let Some(delegate) = from {
+        let (previous, new) = push_checkpoint(
+            e,
+            &VotesStorageKey::NumCheckpoints(delegate.clone()),
+            |index| VotesStorageKey::DelegateCheckpoint(delegate.clone(), index),
+            -amount,
+        );
+        emit_delegate_votes_changed(e, delegate, previous, new);
+    }
+
+    if let Some(delegate) = to {
+        let (previous, new) = push_checkpoint(
+            e,
+            &VotesStorageKey::NumCheckpoints(delegate.clone()),
+            |index| VotesStorageKey::DelegateCheckpoint(delegate.clone(), index),
+            amount,
+        );
+        emit_delegate_votes_changed(e, delegate, previous, new);
+    }
+}
@@ -0,0 +1,227 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{extensions::votes::storage::Votes, Base};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn delegate_to_self_grants_votes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &user, 100);
+        // Tokens do not count as votes before delegation
+        assert_eq!(Votes::get_votes(&e, &user), 0);
+        assert_eq!(Votes::delegates(&e, &user), None);
+
+        Votes::delegate(&e, &user, &user);
+
+        assert_eq!(Votes::delegates(&e, &user), Some(user.clone()));
+        assert_eq!(Votes::get_votes(&e, &user), 100);
+        assert_eq!(Votes::num_checkpoints(&e, &user), 1);
+    });
+}
+
+#[test]
+fn delegate_emits_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &user, 100);
+        Votes::delegate(&e, &user, &delegatee);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        // mint + delegate_changed + delegate_votes_changed
+        event_assert.assert_event_count(3);
+    });
+}
+
+#[test]
+fn redelegate_moves_votes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+    let delegatee1 = Address::generate(&e);
+    let delegatee2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &user, 100);
+        Votes::delegate(&e, &user, &delegatee1);
+        assert_eq!(Votes::get_votes(&e, &delegatee1), 100);
+    });
+
+    e.as_contract(&address, || {
+        Votes::delegate(&e, &user, &delegatee2);
+        assert_eq!(Votes::get_votes(&e, &delegatee1), 0);
+        assert_eq!(Votes::get_votes(&e, &delegatee2), 100);
+        assert_eq!(Votes::get_votes(&e, &user), 0);
+    });
+}
+
+#[test]
+fn transfer_moves_votes_between_delegates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &user1, 100);
+        Votes::delegate(&e, &user1, &user1);
+        Votes::delegate(&e, &user2, &user2);
+    });
+
+    e.as_contract(&address, || {
+        Votes::transfer(&e, &user1, &user2, 30);
+
+        assert_eq!(Base::balance(&e, &user1), 70);
+        assert_eq!(Base::balance(&e, &user2), 30);
+        assert_eq!(Votes::get_votes(&e, &user1), 70);
+        assert_eq!(Votes::get_votes(&e, &user2), 30);
+    });
+}
+
+#[test]
+fn transfer_from_moves_votes_between_delegates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &owner, 100);
+        Votes::delegate(&e, &owner, &delegatee);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 100);
+
+        // recipient has no delegate, so the votes are simply removed
+        Votes::transfer_from(&e, &spender, &owner, &recipient, 40);
+
+        assert_eq!(Votes::get_votes(&e, &delegatee), 60);
+        assert_eq!(Votes::get_votes(&e, &recipient), 0);
+    });
+}
+
+#[test]
+fn burn_and_burn_from_reduce_votes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Votes::mint(&e, &owner, 100);
+        Votes::delegate(&e, &owner, &owner);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 100);
+    });
+
+    e.as_contract(&address, || {
+        Votes::burn(&e, &owner, 10);
+    });
+
+    e.as_contract(&address, || {
+        Votes::burn_from(&e, &spender, &owner, 20);
+
+        assert_eq!(Base::total_supply(&e), 70);
+        assert_eq!(Votes::get_votes(&e, &owner), 70);
+    });
+}
+
+#[test]
+fn past_votes_and_total_supply_are_checkpointed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&address, || {
+        Votes::mint(&e, &user1, 100);
+        Votes::delegate(&e, &user1, &user1);
+        Votes::delegate(&e, &user2, &user2);
+    });
+
+    e.ledger().set_sequence_number(20);
+    e.as_contract(&address, || {
+        Votes::transfer(&e, &user1, &user2, 40);
+        Votes::mint(&e, &user2, 10);
+    });
+
+    e.ledger().set_sequence_number(30);
+    e.as_contract(&address, || {
+        Votes::burn(&e, &user1, 60);
+    });
+
+    e.ledger().set_sequence_number(40);
+    e.as_contract(&address, || {
+        assert_eq!(Votes::get_past_votes(&e, &user1, 9), 0);
+        assert_eq!(Votes::get_past_votes(&e, &user1, 10), 100);
+        assert_eq!(Votes::get_past_votes(&e, &user1, 19), 100);
+        assert_eq!(Votes::get_past_votes(&e, &user1, 20), 60);
+        assert_eq!(Votes::get_past_votes(&e, &user1, 35), 0);
+
+        assert_eq!(Votes::get_past_votes(&e, &user2, 15), 0);
+        assert_eq!(Votes::get_past_votes(&e, &user2, 25), 50);
+
+        assert_eq!(Votes::get_past_total_supply(&e, 5), 0);
+        assert_eq!(Votes::get_past_total_supply(&e, 10), 100);
+        assert_eq!(Votes::get_past_total_supply(&e, 20), 110);
+        assert_eq!(Votes::get_past_total_supply(&e, 39), 50);
+
+        // changes within the same ledger share a single checkpoint
+        assert_eq!(Votes::num_checkpoints(&e, &user2), 1);
+        assert_eq!(Votes::num_checkpoints(&e, &user1), 3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn get_past_votes_for_current_ledger_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&address, || {
+        Votes::get_past_votes(&e, &user, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn get_past_total_supply_for_future_ledger_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&address, || {
+        Votes::get_past_total_supply(&e, 11);
+    });
+}
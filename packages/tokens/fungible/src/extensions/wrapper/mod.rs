@@ -0,0 +1,109 @@
+//! # Wrapper Extension for Fungible Token
+//!
+//! The `wrapper` module turns a fungible token into a 1:1 wrapper of an
+//! underlying SEP-41 token (e.g. a Stellar Asset Contract), similar to the
+//! `ERC20Wrapper` of OpenZeppelin. Accounts lock the underlying token in the
+//! contract to mint the same amount of wrapped tokens, and burn wrapped tokens
+//! to get the underlying token back.
+//!
+//! This allows to layer the other extensions, e.g. `AllowList`, `BlockList` or
+//! `Votes`, on top of an existing asset without any involvement of its issuer.
+//!
+//! ## Implementation Notes
+//!
+//! - **Contract types**: The wrapped tokens are minted and burned with the
+//!   default implementations of [`crate::ContractOverrides`] of the contract
+//!   type passed to the functions of [`Wrapper`], so the checks and update
+//!   hooks of the contract type (e.g. [`crate::votes::Votes`]) apply to them.
+//! - **Decimals**: The decimals of the wrapped token should be set to the
+//!   decimals of the underlying token when setting the token metadata.
+//! - **Recovery**: Underlying tokens sent to the contract directly (i.e.
+//!   without [`Wrapper::deposit_for`]) are not backing any wrapped tokens. They
+//!   can be recovered with [`Wrapper::recover`], which has no authorization and
+//!   therefore has to be exposed by the contract with its own access control.
+//!
+//! ## Usage
+//!
+//! The underlying token has to be set once with [`Wrapper::set_underlying`],
+//! usually in the constructor of the contract.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env};
+pub use storage::{Wrapper, WrapperStorageKey};
+
+use crate::FungibleToken;
+
+/// Wrapper Trait for Fungible Token
+///
+/// The `FungibleWrapper` trait extends the `FungibleToken` trait to provide
+/// the functionality of a 1:1 wrapper of an underlying SEP-41 token. This
+/// trait is designed to be used in conjunction with the `FungibleToken`
+/// trait.
+pub trait FungibleWrapper: FungibleToken {
+    /// Returns the address of the underlying token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::WrapperUnderlyingNotSet`] - When the
+    ///   underlying token has not been set.
+    fn underlying(e: &Env) -> Address;
+
+    /// Locks `amount` of the underlying token of `from` in the contract and
+    /// mints the same amount of wrapped tokens to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address providing the underlying tokens.
+    /// * `account` - The address receiving the wrapped tokens.
+    /// * `amount` - The amount of tokens to wrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::WrapperInvalidAddress`] - When `from` is
+    ///   the contract itself.
+    /// * also refer to [`crate::ContractOverrides::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn deposit_for(e: &Env, from: Address, account: Address, amount: i128);
+
+    /// Burns `amount` of wrapped tokens of `from` and sends the same amount of
+    /// the underlying token to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the wrapped tokens.
+    /// * `account` - The address receiving the underlying tokens.
+    /// * `amount` - The amount of tokens to unwrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::FungibleTokenError::WrapperInvalidAddress`] - When `account`
+    ///   is the contract itself.
+    /// * also refer to [`crate::ContractOverrides::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn withdraw_to(e: &Env, from: Address, account: Address, amount: i128);
+}
@@ -0,0 +1,189 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env};
+
+use crate::{ContractOverrides, FungibleTokenError};
+
+/// Storage keys for the data associated with the wrapper extension
+#[contracttype]
+pub enum WrapperStorageKey {
+    /// Stores the address of the underlying token
+    Underlying,
+}
+
+pub struct Wrapper;
+
+impl Wrapper {
+    // ################## QUERY STATE ##################
+
+    /// Returns the address of the underlying token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::WrapperUnderlyingNotSet`] - When the underlying
+    ///   token has not been set.
+    pub fn underlying(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&WrapperStorageKey::Underlying)
+            .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::WrapperUnderlyingNotSet))
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the underlying token of the wrapper.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `underlying` - The address of the SEP-41 token to wrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::WrapperUnderlyingAlreadySet`] - When the
+    ///   underlying token has already been set.
+    /// * [`FungibleTokenError::WrapperInvalidAddress`] - When `underlying` is
+    ///   the contract itself.
+    ///
+    /// # Notes
+    ///
+    /// We recommend using this function in the constructor of your smart
+    /// contract.
+    pub fn set_underlying(e: &Env, underlying: &Address) {
+        if e.storage().instance().has(&WrapperStorageKey::Underlying) {
+            panic_with_error!(e, FungibleTokenError::WrapperUnderlyingAlreadySet);
+        }
+        if *underlying == e.current_contract_address() {
+            panic_with_error!(e, FungibleTokenError::WrapperInvalidAddress);
+        }
+        e.storage().instance().set(&WrapperStorageKey::Underlying, underlying);
+    }
+
+    /// Locks `amount` of the underlying token of `from` in the contract and
+    /// mints the same amount of wrapped tokens to `account` with
+    /// [`ContractOverrides::mint`] of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address providing the underlying tokens.
+    /// * `account` - The address receiving the wrapped tokens.
+    /// * `amount` - The amount of tokens to wrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::WrapperInvalidAddress`] - When `from` is the
+    ///   contract itself.
+    /// * [`FungibleTokenError::WrapperUnderlyingNotSet`] - When the underlying
+    ///   token has not been set.
+    /// * refer to [`ContractOverrides::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn deposit_for<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        account: &Address,
+        amount: i128,
+    ) {
+        let contract = e.current_contract_address();
+        if *from == contract {
+            panic_with_error!(e, FungibleTokenError::WrapperInvalidAddress);
+        }
+        from.require_auth();
+
+        TokenClient::new(e, &Wrapper::underlying(e)).transfer(from, &contract, &amount);
+        T::mint(e, account, amount);
+    }
+
+    /// Burns `amount` of wrapped tokens of `from` with
+    /// [`ContractOverrides::burn`] of `T` and sends the same amount of the
+    /// underlying token to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the wrapped tokens.
+    /// * `account` - The address receiving the underlying tokens.
+    /// * `amount` - The amount of tokens to unwrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::WrapperInvalidAddress`] - When `account` is the
+    ///   contract itself.
+    /// * [`FungibleTokenError::WrapperUnderlyingNotSet`] - When the underlying
+    ///   token has not been set.
+    /// * refer to [`ContractOverrides::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn withdraw_to<T: ContractOverrides>(
+        e: &Env,
+        from: &Address,
+        account: &Address,
+        amount: i128,
+    ) {
+        let contract = e.current_contract_address();
+        if *account == contract {
+            panic_with_error!(e, FungibleTokenError::WrapperInvalidAddress);
+        }
+
+        T::burn(e, from, amount);
+        TokenClient::new(e, &Wrapper::underlying(e)).transfer(&contract, account, &amount);
+    }
+
+    /// Mints wrapped tokens to `account` for the underlying tokens held by
+    /// the contract in excess of the total supply, e.g. tokens transferred to
+    /// the contract by mistake. Returns the amount of wrapped tokens minted,
+    /// which is `0` (and nothing is minted) when there is no excess.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address receiving the wrapped tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::WrapperUnderlyingNotSet`] - When the underlying
+    ///   token has not been set.
+    /// * refer to [`ContractOverrides::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can recover
+    /// the excess tokens.
+    pub fn recover<T: ContractOverrides>(e: &Env, account: &Address) -> i128 {
+        let balance =
+            TokenClient::new(e, &Wrapper::underlying(e)).balance(&e.current_contract_address());
+        let value = balance - T::total_supply(e);
+        // The total supply can exceed the balance if the underlying token
+        // charges fees or its balances can decrease (e.g. rebasing tokens).
+        if value <= 0 {
+            return 0;
+        }
+        T::mint(e, account, value);
+        value
+    }
+}
@@ -0,0 +1,214 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{extensions::wrapper::storage::Wrapper, votes::Votes, Base};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, users: &[&Address], amount: i128) -> (Address, Address) {
+    let underlying = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    for user in users {
+        StellarAssetClient::new(e, &underlying).mint(user, &amount);
+    }
+    let wrapper = e.register(MockContract, ());
+    e.as_contract(&wrapper, || Wrapper::set_underlying(e, &underlying));
+    (wrapper, underlying)
+}
+
+#[test]
+fn deposit_for_and_withdraw_to() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let account = Address::generate(&e);
+    let (wrapper, underlying) = setup(&e, &[&user], 1_000);
+    let underlying_client = TokenClient::new(&e, &underlying);
+
+    e.as_contract(&wrapper, || {
+        assert_eq!(Wrapper::underlying(&e), underlying);
+        Wrapper::deposit_for::<Base>(&e, &user, &account, 100);
+        assert_eq!(Base::balance(&e, &account), 100);
+        assert_eq!(Base::total_supply(&e), 100);
+    });
+    assert_eq!(underlying_client.balance(&user), 900);
+    assert_eq!(underlying_client.balance(&wrapper), 100);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::withdraw_to::<Base>(&e, &account, &user, 40);
+        assert_eq!(Base::balance(&e, &account), 60);
+        assert_eq!(Base::total_supply(&e), 60);
+    });
+    assert_eq!(underlying_client.balance(&user), 940);
+    assert_eq!(underlying_client.balance(&wrapper), 60);
+}
+
+#[test]
+fn deposit_for_emits_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &user, &user, 100);
+
+        let mut event_assert = EventAssertion::new(&e, wrapper.clone());
+        // underlying transfer + mint
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_mint(&user, 100);
+    });
+}
+
+#[test]
+fn deposit_for_runs_contract_type_hooks() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Votes::delegate(&e, &user, &user);
+    });
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Votes>(&e, &user, &user, 100);
+        assert_eq!(Votes::get_votes(&e, &user), 100);
+    });
+
+    e.as_contract(&wrapper, || {
+        Wrapper::withdraw_to::<Votes>(&e, &user, &user, 30);
+        assert_eq!(Votes::get_votes(&e, &user), 70);
+    });
+}
+
+#[test]
+fn recover_mints_excess_underlying() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let (wrapper, underlying) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &user, &user, 100);
+    });
+    // sent directly, without wrapping
+    TokenClient::new(&e, &underlying).transfer(&user, &wrapper, &50);
+
+    e.as_contract(&wrapper, || {
+        assert_eq!(Wrapper::recover::<Base>(&e, &admin), 50);
+        assert_eq!(Base::balance(&e, &admin), 50);
+        assert_eq!(Base::total_supply(&e), 150);
+    });
+}
+
+#[test]
+fn recover_without_excess_mints_nothing() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &user, &user, 100);
+        // unbacked wrapped tokens, so the total supply exceeds the balance
+        Base::mint(&e, &user, 50);
+    });
+
+    e.as_contract(&wrapper, || {
+        assert_eq!(Wrapper::recover::<Base>(&e, &admin), 0);
+        assert_eq!(Base::balance(&e, &admin), 0);
+        assert_eq!(Base::total_supply(&e), 150);
+
+        let event_assert = EventAssertion::new(&e, wrapper.clone());
+        event_assert.assert_event_count(0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn withdraw_to_more_than_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &user, &user, 100);
+    });
+
+    e.as_contract(&wrapper, || {
+        Wrapper::withdraw_to::<Base>(&e, &user, &user, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #125)")]
+fn set_underlying_twice_panics() {
+    let e = Env::default();
+    let (wrapper, underlying) = setup(&e, &[], 0);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::set_underlying(&e, &underlying);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #126)")]
+fn underlying_not_set_panics() {
+    let e = Env::default();
+    let wrapper = e.register(MockContract, ());
+
+    e.as_contract(&wrapper, || {
+        Wrapper::underlying(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #127)")]
+fn set_underlying_to_self_panics() {
+    let e = Env::default();
+    let wrapper = e.register(MockContract, ());
+
+    e.as_contract(&wrapper, || {
+        Wrapper::set_underlying(&e, &wrapper);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #127)")]
+fn deposit_for_from_self_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (wrapper, _) = setup(&e, &[], 0);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &wrapper, &wrapper, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #127)")]
+fn withdraw_to_self_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let user = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &[&user], 1_000);
+
+    e.as_contract(&wrapper, || {
+        Wrapper::deposit_for::<Base>(&e, &user, &user, 100);
+    });
+
+    e.as_contract(&wrapper, || {
+        Wrapper::withdraw_to::<Base>(&e, &user, &wrapper, 100);
+    });
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, symbol_short, Address, Env, String};
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env, String};
 
 use crate::ContractOverrides;
 
@@ -47,15 +47,20 @@ use crate::ContractOverrides;
 ///   [`crate::extensions::burnable::FungibleBurnable`]) trait
 /// * [`crate::extensions::allowlist::AllowList`] (enabling the compatibility
 ///   and overrides for [`crate::extensions::allowlist::FungibleAllowList`])
-///   trait, incompatible with [`crate::extensions::blocklist::BlockList`]
 ///   trait.
 /// * [`crate::extensions::blocklist::BlockList`] (enabling the compatibility
 ///   and overrides for [`crate::extensions::blocklist::FungibleBlockList`])
-///   trait, incompatible with [`crate::extensions::allowlist::AllowList`]
 ///   trait.
+/// * [`crate::extensions::pausable::WhenNotPaused`] (rejecting transfers, burns
+///   and mints while the contract is paused).
+/// * [`crate::extensions::capped::Capped`] (enforcing the supply cap on mints).
+/// * [`crate::extensions::votes::Votes`] (enabling the compatibility and
+///   overrides for [`crate::extensions::votes::FungibleVotes`]) trait.
+/// * [`crate::Stack`] (combining the checks and update hooks of multiple
+///   contract types, e.g. `Stack<AllowList, Stack<WhenNotPaused, Votes>>`).
 ///
 /// You can find the default implementations of this trait for `Base`,
-/// `Allowlist`, and `Blocklist` by navigating to:
+/// `Allowlist`, `Blocklist`, and `Votes` by navigating to:
 /// `ContractType::{method_name}`. For example, if you want to find how
 /// [`FungibleToken::transfer`] is implemented for the `Allowlist` contract
 /// type, you can find it using
@@ -65,7 +70,8 @@ pub trait FungibleToken {
     /// base trait based on the extensions implemented. You should use
     /// [`crate::Base`] as the type if you are not using
     /// [`crate::extensions::allowlist::AllowList`] or
-    /// [`crate::extensions::blocklist::BlockList`] extensions.
+    /// [`crate::extensions::blocklist::BlockList`] extensions, and
+    /// [`crate::Stack`] to enable multiple extensions at once.
     type ContractType: ContractOverrides;
 
     /// Returns the total amount of tokens in circulation.
@@ -229,10 +235,91 @@ pub enum FungibleTokenError {
     UserNotAllowed = 113,
     /// The user is blocked and cannot perform this operation
     UserBlocked = 114,
+    /// Indicates a lookup of past votes or total supply at a ledger that is not
+    /// yet finalized.
+    FutureLookup = 115,
+    /// Indicates that the deadline of a permit has passed.
+    PermitExpired = 116,
+    /// Indicates that a permit was not signed by the registered permit key of
+    /// the owner.
+    InvalidPermitSigner = 117,
+    /// Indicates that the underlying asset of a vault was already set.
+    VaultAssetAlreadySet = 118,
+    /// Indicates access to the underlying asset of a vault before it was set.
+    VaultAssetNotSet = 119,
+    /// Indicates a decimals offset of a vault greater than
+    /// `MAX_DECIMALS_OFFSET`.
+    VaultInvalidDecimalsOffset = 120,
+    /// Indicates an attempt to deposit more assets than the maximum allowed.
+    VaultExceededMaxDeposit = 121,
+    /// Indicates an attempt to mint more shares than the maximum allowed.
+    VaultExceededMaxMint = 122,
+    /// Indicates an attempt to withdraw more assets than the maximum allowed.
+    VaultExceededMaxWithdraw = 123,
+    /// Indicates an attempt to redeem more shares than the maximum allowed.
+    VaultExceededMaxRedeem = 124,
+    /// Indicates that the underlying token of a wrapper was already set.
+    WrapperUnderlyingAlreadySet = 125,
+    /// Indicates access to the underlying token of a wrapper before it was
+    /// set.
+    WrapperUnderlyingNotSet = 126,
+    /// Indicates an invalid underlying token, sender or receiver for a
+    /// wrapper, e.g. the wrapper contract itself.
+    WrapperInvalidAddress = 127,
+    /// Indicates a lookup of a snapshot that has not been created yet.
+    SnapshotInvalidId = 128,
+    /// Indicates a transfer fee rate greater than `MAX_FEE_BASIS_POINTS`.
+    FeeInvalidRate = 129,
+    /// Indicates an attempt to move more tokens than the unfrozen balance of
+    /// an account.
+    InsufficientUnfrozenBalance = 130,
+    /// Indicates an attempt to freeze more tokens than the unfrozen balance
+    /// of an account.
+    FreezeExceedsBalance = 131,
+    /// Indicates an attempt to unfreeze more tokens than are frozen.
+    UnfreezeExceedsFrozen = 132,
+    /// Indicates a mint by an account that is not a minter.
+    MinterNotFound = 133,
+    /// Indicates a mint of more tokens than the available quota of a minter.
+    MinterQuotaExceeded = 134,
+    /// Indicates an attempt to update the cap below the current total supply.
+    CapBelowSupply = 135,
+    /// Indicates a scheduled cap increase that is not after the current
+    /// ledger and the previously scheduled increase, or that does not raise
+    /// the cap.
+    CapInvalidSchedule = 136,
+    /// Indicates a transfer or mint that would raise the balance of an account
+    /// above the maximum balance.
+    MaxBalanceExceeded = 137,
+    /// Indicates an attempt to move more tokens than the balance of an account
+    /// in a partition.
+    InsufficientPartitionBalance = 138,
+    /// Indicates an attempt to move more tokens than the balance of an account
+    /// outside of its partitions.
+    InsufficientUnpartitionedBalance = 139,
+    /// Indicates an operator that is not authorized for the partition of an
+    /// account.
+    NotPartitionOperator = 140,
+    /// Indicates a reward token that is not registered for dividends.
+    DividendUnknownRewardToken = 141,
+    /// Indicates a reward token that is already registered for dividends.
+    DividendRewardTokenExists = 142,
+    /// Indicates a distribution of dividends while the total supply is zero.
+    DividendNoSupply = 143,
 }
 
 // ################## EVENTS ##################
 
+/// Data of the `transfer` event when the tokens are sent to a muxed account.
+/// It serializes to the map `{ amount, to_muxed_id }` emitted by the Stellar
+/// Asset Contracts for transfers to muxed addresses (CAP-67).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MuxedTransferData {
+    pub amount: i128,
+    pub to_muxed_id: u64,
+}
+
 /// Emits an event indicating a transfer of tokens.
 ///
 /// # Arguments
@@ -251,6 +338,26 @@ pub fn emit_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
     e.events().publish(topics, amount)
 }
 
+/// Emits an event indicating a transfer of tokens to a muxed account.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `to_muxed_id` - The muxed id of `to`, identifying the sub-account that
+///   receives the tokens (e.g. a customer account of an exchange).
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `MuxedTransferData { amount: i128, to_muxed_id: u64 }`
+pub fn emit_transfer_muxed(e: &Env, from: &Address, to: &Address, to_muxed_id: u64, amount: i128) {
+    let topics = (symbol_short!("transfer"), from, to);
+    e.events().publish(topics, MuxedTransferData { amount, to_muxed_id })
+}
+
 /// Emits an event indicating an allowance was set.
 ///
 /// # Arguments
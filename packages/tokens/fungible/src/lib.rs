@@ -35,9 +35,39 @@
 //!
 //! - Metadata: Provides additional information about the token, such as name,
 //!   symbol, and decimals.
+//! - Allowance: Increases and decreases allowances without overwriting them,
+//!   and optionally keeps track of the spenders of every owner.
+//! - Batch: Transfers tokens to multiple recipients in a single invocation.
 //! - Burnable: Enables token holders to destroy their tokens, reducing the
 //!   total supply.
 //! - Capped: Enables the contract to set a maximum limit on the total supply.
+//! - AllowList / BlockList: Restrict which accounts can transfer, receive or
+//!   approve tokens.
+//! - Clawback: Enables an authorized account to claw back tokens or force
+//!   transfers, similar to the clawback of Stellar Asset Contracts.
+//! - Dividend: Distributes deposits of reward tokens to the holders pro rata to
+//!   their balances, which they can claim at any time.
+//! - Fee: Deducts a fee from transfers and sends it to a fee collector.
+//! - Freezable: Freezes a part of the balance of an account, which cannot be
+//!   transferred or burned until it is unfrozen.
+//! - MaxBalance: Limits the amount of tokens a single account can hold.
+//! - Mintable: Lets multiple minters mint tokens, each one within a quota that
+//!   refills over a window of ledgers.
+//! - Muxed: Transfers tokens to muxed accounts, so that the receiver can
+//!   attribute them to one of its sub-accounts.
+//! - Partition: Splits balances into named partitions (tranches), similar to
+//!   ERC-1400, with partition-aware transfers and operators.
+//! - Pausable: Rejects transfers, burns and mints while the contract is paused.
+//! - Permit: Enables token holders to set allowances with an off-chain
+//!   signature that anyone can submit on their behalf.
+//! - Votes: Enables the delegation of voting power and keeps track of
+//!   historical voting power and total supply for governance.
+//! - Snapshot: Records the balances and the total supply at numbered snapshots,
+//!   so that they can be queried later on.
+//! - Vault: Turns the token into shares of an underlying SEP-41 asset, similar
+//!   to ERC-4626.
+//! - Wrapper: Wraps an underlying SEP-41 token 1:1, so that extensions can be
+//!   layered on top of existing assets.
 //!
 //! ## Compatibility and Compliance
 //!
@@ -61,6 +91,9 @@
 //! - **Composable Design**: The modular structure encourages developers to
 //!   extend functionality by combining provided primitives or creating custom
 //!   extensions.
+//! - **Stacking Extensions**: Extensions that restrict token operations (e.g.
+//!   allowlist, batch, blocklist, pausable and capped) can be enabled together
+//!   by using [`Stack`] as the `ContractType` of [`FungibleToken`].
 //! - **TTL management**: This library handles the TTL of only `temporary` and
 //!   `persistent` storage entries declared by the library. The `instance` TTL
 //!   management is left to the implementor due to flexibility. The library
@@ -75,9 +108,15 @@ mod overrides;
 mod storage;
 mod utils;
 
-pub use extensions::{allowlist, blocklist, burnable, capped};
-pub use fungible::{emit_approve, emit_transfer, FungibleToken, FungibleTokenError};
-pub use overrides::{Base, ContractOverrides};
+pub use extensions::{
+    allowance, allowlist, batch, blocklist, burnable, capped, clawback, dividend, fee, freezable,
+    max_balance, mintable, muxed, partition, pausable, permit, snapshot, vault, votes, wrapper,
+};
+pub use fungible::{
+    emit_approve, emit_transfer, emit_transfer_muxed, FungibleToken, FungibleTokenError,
+    MuxedTransferData,
+};
+pub use overrides::{Base, ContractOverrides, Stack};
 pub use storage::{AllowanceData, AllowanceKey, StorageKey};
 pub use utils::{sac_admin_generic, sac_admin_wrapper};
 
@@ -1,5 +1,12 @@
+use core::marker::PhantomData;
+
 use soroban_sdk::{Address, Env, String};
 
+use crate::{
+    burnable::emit_burn,
+    fungible::{emit_mint, emit_transfer, emit_transfer_muxed},
+};
+
 /// Based on the extension, some default behavior of [`crate::FungibleToken`]
 /// might have to be overridden. This is a helper trait that allows us this
 /// override mechanism that favors the DevX.
@@ -58,7 +65,209 @@ use soroban_sdk::{Address, Env, String};
 ///     /* and so on */
 /// }
 /// ```
+///
+/// # Checks
+///
+/// Extensions that only need to restrict who can transfer, approve, burn or
+/// mint tokens (e.g. [`crate::allowlist::AllowList`]) should override the
+/// `check_*` functions instead of the state changing functions. The default
+/// implementations of `transfer`, `transfer_from`, `approve`, `burn`,
+/// `burn_from` and `mint` run the respective check before delegating to
+/// [`Base`], and contract types that only override checks can be combined with
+/// [`Stack`].
+///
+/// # Update Hooks
+///
+/// Every balance change of the default implementations (`transfer`,
+/// `transfer_from`, `burn`, `burn_from` and `mint`) goes through
+/// [`ContractOverrides::update`], which calls `before_update`, then
+/// [`Base::update`] and finally `after_update`. Extensions that have to keep
+/// additional accounting in sync with the balances (e.g.
+/// [`crate::votes::Votes`]) should override these hooks, so that the
+/// accounting applies to all the balance changing functions at once.
+/// Similarly, `after_approve` is called after every allowance change made by
+/// `approve`, `increase_allowance`, `decrease_allowance` and the allowance
+/// spending functions (e.g. for [`crate::allowance::EnumerableAllowances`]).
 pub trait ContractOverrides {
+    /// Called before `transfer` and `transfer_from`. Should panic if the
+    /// transfer is not permitted.
+    fn check_transfer(_e: &Env, _from: &Address, _to: &Address, _amount: i128) {}
+
+    /// Called before `approve` and `increase_allowance`. Should panic if the
+    /// approval is not permitted. For `increase_allowance`, `amount` is the
+    /// amount added to the allowance.
+    fn check_approve(
+        _e: &Env,
+        _owner: &Address,
+        _spender: &Address,
+        _amount: i128,
+        _live_until_ledger: u32,
+    ) {
+    }
+
+    /// Called after every allowance change made by `approve`,
+    /// `increase_allowance`, `decrease_allowance` and
+    /// [`ContractOverrides::spend_allowance`], with the resulting allowance.
+    fn after_approve(
+        _e: &Env,
+        _owner: &Address,
+        _spender: &Address,
+        _amount: i128,
+        _live_until_ledger: u32,
+    ) {
+    }
+
+    /// Called before `burn` and `burn_from`. Should panic if the burn is not
+    /// permitted.
+    fn check_burn(_e: &Env, _from: &Address, _amount: i128) {}
+
+    /// Called before `mint`. Should panic if the mint is not permitted.
+    fn check_mint(_e: &Env, _to: &Address, _amount: i128) {}
+
+    /// Called before every balance change made by
+    /// [`ContractOverrides::update`]. `from` is `None` for mints and `to`
+    /// is `None` for burns.
+    fn before_update(_e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {}
+
+    /// Called after every balance change made by [`ContractOverrides::update`].
+    /// `from` is `None` for mints and `to` is `None` for burns.
+    fn after_update(_e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {}
+
+    /// Wraps [`Base::update`] with the `before_update` and `after_update`
+    /// hooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens, or `None` for mints.
+    /// * `to` - The address receiving the tokens, or `None` for burns.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        Self::before_update(e, from, to, amount);
+        Base::update(e, from, to, amount);
+        Self::after_update(e, from, to, amount);
+    }
+
+    /// Whether the contract type overrides
+    /// [`ContractOverrides::update_transfer_with`]. [`Stack`] takes that
+    /// function from `Top` only, so it refuses to compile when the contract
+    /// type overriding it is not at the top of the stack.
+    const OVERRIDES_UPDATE_TRANSFER: bool = false;
+
+    /// Deducts `amount` from the allowance of `spender` on the tokens of
+    /// `owner` with [`Base::spend_allowance`], then calls `after_approve` with
+    /// the remaining allowance. It is called by `transfer_from`, `burn_from`
+    /// and batch transfers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens to be deducted from the allowance.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::spend_allowance`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn spend_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+        Base::spend_allowance(e, owner, spender, amount);
+        let allowance = Base::allowance_data(e, owner, spender);
+        Self::after_approve(e, owner, spender, allowance.amount, allowance.live_until_ledger);
+    }
+
+    /// Moves `amount` of tokens from `from` to `to` with
+    /// [`ContractOverrides::update_transfer_with`], using the `update` of
+    /// `Self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `to_muxed_id` - The muxed id of the receiver, if any.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::update_transfer_with`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`ContractOverrides::update_transfer_with`] events.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn update_transfer(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) {
+        Self::update_transfer_with::<Self>(e, from, to, to_muxed_id, amount);
+    }
+
+    /// Moves `amount` of tokens from `from` to `to` with
+    /// [`ContractOverrides::update`] of `T` and emits the `transfer` event. It
+    /// is called for every transfer once the checks and the authorization are
+    /// done, including every leg of a batch transfer, so contract types that
+    /// change how transferred tokens are moved (e.g. [`crate::fee::Fee`])
+    /// override this function, and set
+    /// [`ContractOverrides::OVERRIDES_UPDATE_TRANSFER`]. `T` is the contract
+    /// type whose update hooks apply, i.e. `Self`, or the enclosing [`Stack`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `to_muxed_id` - The muxed id of the receiver, if any.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`ContractOverrides::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`, or `MuxedTransferData` if `to_muxed_id` is
+    ///   set
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn update_transfer_with<T: ContractOverrides + ?Sized>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) {
+        T::update(e, Some(from), Some(to), amount);
+        match to_muxed_id {
+            Some(to_muxed_id) => emit_transfer_muxed(e, from, to, to_muxed_id, amount),
+            None => emit_transfer(e, from, to, amount),
+        }
+    }
+
     fn total_supply(e: &Env) -> i128 {
         Base::total_supply(e)
     }
@@ -72,15 +281,62 @@ pub trait ContractOverrides {
     }
 
     fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-        Base::transfer(e, from, to, amount);
+        Self::check_transfer(e, from, to, amount);
+        from.require_auth();
+        Self::update_transfer(e, from, to, None, amount);
+    }
+
+    fn transfer_muxed(e: &Env, from: &Address, to: &Address, to_muxed_id: u64, amount: i128) {
+        Self::check_transfer(e, from, to, amount);
+        from.require_auth();
+        Self::update_transfer(e, from, to, Some(to_muxed_id), amount);
     }
 
     fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
-        Base::transfer_from(e, spender, from, to, amount);
+        Self::check_transfer(e, from, to, amount);
+        spender.require_auth();
+        Self::spend_allowance(e, from, spender, amount);
+        Self::update_transfer(e, from, to, None, amount);
     }
 
     fn approve(e: &Env, owner: &Address, spender: &Address, amount: i128, live_until_ledger: u32) {
+        Self::check_approve(e, owner, spender, amount, live_until_ledger);
         Base::approve(e, owner, spender, amount, live_until_ledger);
+        Self::after_approve(e, owner, spender, amount, live_until_ledger);
+    }
+
+    fn increase_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+        let live_until_ledger = Base::allowance_data(e, owner, spender).live_until_ledger;
+        Self::check_approve(e, owner, spender, amount, live_until_ledger);
+        let new_amount = Base::increase_allowance(e, owner, spender, amount);
+        Self::after_approve(e, owner, spender, new_amount, live_until_ledger);
+    }
+
+    fn decrease_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+        let live_until_ledger = Base::allowance_data(e, owner, spender).live_until_ledger;
+        let new_amount = Base::decrease_allowance(e, owner, spender, amount);
+        Self::after_approve(e, owner, spender, new_amount, live_until_ledger);
+    }
+
+    fn burn(e: &Env, from: &Address, amount: i128) {
+        Self::check_burn(e, from, amount);
+        from.require_auth();
+        Self::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        Self::check_burn(e, from, amount);
+        spender.require_auth();
+        Self::spend_allowance(e, from, spender, amount);
+        Self::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+
+    fn mint(e: &Env, to: &Address, amount: i128) {
+        Self::check_mint(e, to, amount);
+        Self::update(e, None, Some(to), amount);
+        emit_mint(e, to, amount);
     }
 
     fn decimals(e: &Env) -> u32 {
@@ -101,3 +357,97 @@ pub struct Base;
 
 // No override required for the `Base` contract type.
 impl ContractOverrides for Base {}
+
+/// Contract type that combines the checks and update hooks of two contract
+/// types, so that multiple extensions can be enabled on the same token. Deeper
+/// stacks are built by nesting, for example:
+///
+/// ```ignore
+/// impl FungibleToken for ExampleContract {
+///     type ContractType = Stack<AllowList, Stack<WhenNotPaused, Votes>>;
+/// }
+/// ```
+///
+/// The checks and the `before_update` hook of `Top` run before the ones of
+/// `Rest`, while the `after_update` and `after_approve` hooks of `Top` run
+/// after the ones of `Rest`. Transfers move the tokens with
+/// [`ContractOverrides::update_transfer_with`] of `Top`, which goes through
+/// the combined update hooks, so a contract type overriding it (e.g.
+/// [`crate::fee::Fee`]) must be the top of the stack, as in
+/// `Stack<Fee, Stack<AllowList, Votes>>`. Stacks with such a contract type
+/// anywhere else do not compile. The other state changing functions use the
+/// default implementations of [`ContractOverrides`].
+///
+/// # Notes
+///
+/// Only the `check_*`, `before_update`, `after_update`, `after_approve` and
+/// `update_transfer_with` functions are combined. Overrides of any other
+/// function of `Top` or `Rest` are ignored.
+pub struct Stack<Top, Rest>(PhantomData<(Top, Rest)>);
+
+impl<Top: ContractOverrides, Rest: ContractOverrides> ContractOverrides for Stack<Top, Rest> {
+    const OVERRIDES_UPDATE_TRANSFER: bool = Top::OVERRIDES_UPDATE_TRANSFER;
+
+    fn check_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        Top::check_transfer(e, from, to, amount);
+        Rest::check_transfer(e, from, to, amount);
+    }
+
+    fn check_approve(
+        e: &Env,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        live_until_ledger: u32,
+    ) {
+        Top::check_approve(e, owner, spender, amount, live_until_ledger);
+        Rest::check_approve(e, owner, spender, amount, live_until_ledger);
+    }
+
+    fn after_approve(
+        e: &Env,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        live_until_ledger: u32,
+    ) {
+        Rest::after_approve(e, owner, spender, amount, live_until_ledger);
+        Top::after_approve(e, owner, spender, amount, live_until_ledger);
+    }
+
+    fn check_burn(e: &Env, from: &Address, amount: i128) {
+        Top::check_burn(e, from, amount);
+        Rest::check_burn(e, from, amount);
+    }
+
+    fn check_mint(e: &Env, to: &Address, amount: i128) {
+        Top::check_mint(e, to, amount);
+        Rest::check_mint(e, to, amount);
+    }
+
+    fn before_update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        Top::before_update(e, from, to, amount);
+        Rest::before_update(e, from, to, amount);
+    }
+
+    fn after_update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        Rest::after_update(e, from, to, amount);
+        Top::after_update(e, from, to, amount);
+    }
+
+    fn update_transfer_with<T: ContractOverrides + ?Sized>(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) {
+        const {
+            assert!(
+                !Rest::OVERRIDES_UPDATE_TRANSFER,
+                "a contract type overriding `update_transfer_with` must be the top of the stack"
+            )
+        };
+        Top::update_transfer_with::<T>(e, from, to, to_muxed_id, amount);
+    }
+}
@@ -2,7 +2,7 @@ use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, St
 use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
 
 use crate::{
-    fungible::{emit_approve, emit_mint, emit_transfer, FungibleTokenError},
+    fungible::{emit_approve, emit_mint, emit_transfer, emit_transfer_muxed, FungibleTokenError},
     Base,
 };
 
@@ -215,6 +215,97 @@ impl Base {
         emit_approve(e, owner, spender, amount, live_until_ledger);
     }
 
+    /// Increases the amount of tokens a `spender` is allowed to spend on
+    /// behalf of an `owner` by `amount`, while keeping the `live_until_ledger`
+    /// of the existing allowance. Unlike [`Base::approve`], it does not
+    /// overwrite allowance spent in the meantime by `spender`. Returns the new
+    /// allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens added to the allowance.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
+    /// * [`FungibleTokenError::MathOverflow`] - Occurs when the new allowance
+    ///   overflows.
+    /// * also refer to [`set_allowance`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    /// * An allowance that does not exist or has expired cannot be increased,
+    ///   as it has no `live_until_ledger` to keep. Use [`Base::approve`]
+    ///   instead.
+    pub fn increase_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) -> i128 {
+        owner.require_auth();
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        let live_until_ledger = Base::allowance_data(e, owner, spender).live_until_ledger;
+        let Some(new_amount) = Base::allowance(e, owner, spender).checked_add(amount) else {
+            panic_with_error!(e, FungibleTokenError::MathOverflow);
+        };
+
+        Base::set_allowance(e, owner, spender, new_amount, live_until_ledger);
+        emit_approve(e, owner, spender, new_amount, live_until_ledger);
+        new_amount
+    }
+
+    /// Decreases the amount of tokens a `spender` is allowed to spend on
+    /// behalf of an `owner` by `amount`, while keeping the `live_until_ledger`
+    /// of the existing allowance. Returns the new allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address authorized to spend the tokens.
+    /// * `amount` - The amount of tokens removed from the allowance.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InsufficientAllowance`] - Occurs when `amount`
+    ///   is greater than the current allowance.
+    /// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", from: Address, spender: Address]`
+    /// * data - `[amount: i128, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `owner` is required.
+    pub fn decrease_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) -> i128 {
+        owner.require_auth();
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        let live_until_ledger = Base::allowance_data(e, owner, spender).live_until_ledger;
+        let current_amount = Base::allowance(e, owner, spender);
+        if current_amount < amount {
+            panic_with_error!(e, FungibleTokenError::InsufficientAllowance);
+        }
+        // NOTE: can't underflow because of the check above.
+        let new_amount = current_amount - amount;
+
+        Base::set_allowance(e, owner, spender, new_amount, live_until_ledger);
+        emit_approve(e, owner, spender, new_amount, live_until_ledger);
+        new_amount
+    }
+
     /// Sets the amount of tokens a `spender` is allowed to spend on behalf of
     /// an `owner`. Overrides any existing allowance set between `spender`
     /// and `owner`. Doesn't handle authorization, nor event emission.
@@ -352,6 +443,37 @@ impl Base {
         emit_transfer(e, from, to, amount);
     }
 
+    /// Transfers `amount` of tokens from `from` to the muxed account
+    /// `to_muxed_id` of `to`. The balance of `to` is credited, and
+    /// `to_muxed_id` is included in the `transfer` event so that the receiver
+    /// (e.g. an exchange) can attribute the tokens to one of its sub-accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `to_muxed_id` - The muxed id of `to`.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `MuxedTransferData { amount: i128, to_muxed_id: u64 }`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer_muxed(e: &Env, from: &Address, to: &Address, to_muxed_id: u64, amount: i128) {
+        from.require_auth();
+        Base::update(e, Some(from), Some(to), amount);
+        emit_transfer_muxed(e, from, to, to_muxed_id, amount);
+    }
+
     /// Transfers `amount` of tokens from `from` to `to` using the
     /// allowance mechanism. `amount` is then deducted from `spender`s
     /// allowance.
@@ -9,12 +9,15 @@ use soroban_sdk::{
         storage::{Instance, Persistent},
         Address as _, AuthorizedFunction, Events, Ledger,
     },
-    vec, Address, Env, IntoVal, String,
+    vec, Address, Env, IntoVal, String, Symbol, Vec,
 };
 use stellar_constants::{BALANCE_EXTEND_AMOUNT, INSTANCE_EXTEND_AMOUNT, INSTANCE_TTL_THRESHOLD};
 use stellar_event_assertion::EventAssertion;
 
-use crate::{Base, StorageKey};
+use crate::{
+    allowlist::AllowList, blocklist::BlockList, capped::Capped, pausable::WhenNotPaused,
+    votes::Votes, Base, ContractOverrides, Stack, StorageKey,
+};
 
 #[contract]
 struct MockContract;
@@ -110,6 +113,106 @@ fn approve_handles_expiry() {
     });
 }
 
+#[test]
+fn increase_allowance_keeps_live_until_ledger() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        let new_amount = Base::increase_allowance(&e, &owner, &spender, 30);
+        assert_eq!(new_amount, 80);
+        assert_eq!(Base::allowance(&e, &owner, &spender), 80);
+        assert_eq!(Base::allowance_data(&e, &owner, &spender).live_until_ledger, 1000);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_approve(&owner, &spender, 80, 1000);
+    });
+}
+
+#[test]
+fn decrease_allowance_keeps_live_until_ledger() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        let new_amount = Base::decrease_allowance(&e, &owner, &spender, 20);
+        assert_eq!(new_amount, 30);
+        assert_eq!(Base::allowance(&e, &owner, &spender), 30);
+        assert_eq!(Base::allowance_data(&e, &owner, &spender).live_until_ledger, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn decrease_allowance_below_zero_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::decrease_allowance(&e, &owner, &spender, 51);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn increase_expired_allowance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, 50, 2);
+    });
+
+    e.ledger().set_sequence_number(3);
+
+    e.as_contract(&address, || {
+        Base::increase_allowance(&e, &owner, &spender, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn increase_allowance_overflow_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve(&e, &owner, &spender, i128::MAX, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::increase_allowance(&e, &owner, &spender, 1);
+    });
+}
+
 #[test]
 fn spend_allowance_reduces_amount() {
     let e = Env::default();
@@ -720,3 +823,335 @@ fn metadata_update() {
         assert_eq!(Base::symbol(&e), String::from_str(&e, "UPD"));
     });
 }
+
+type Layered = Stack<AllowList, Stack<BlockList, Stack<WhenNotPaused, Capped>>>;
+
+fn setup_layered(e: &Env, address: &Address, users: &[&Address]) {
+    e.as_contract(address, || {
+        crate::capped::set_cap(e, 1000);
+        for user in users {
+            AllowList::allow_user(e, user);
+        }
+    });
+}
+
+#[test]
+fn stack_transfer_runs_all_layers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup_layered(&e, &address, &[&user1, &user2]);
+
+    e.as_contract(&address, || {
+        Layered::mint(&e, &user1, 100);
+        Layered::transfer(&e, &user1, &user2, 40);
+        assert_eq!(Layered::balance(&e, &user1), 60);
+        assert_eq!(Layered::balance(&e, &user2), 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn stack_transfer_checks_allowlist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup_layered(&e, &address, &[&user1]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user1, 100);
+        Layered::transfer(&e, &user1, &user2, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn stack_transfer_checks_blocklist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup_layered(&e, &address, &[&user1, &user2]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user1, 100);
+        BlockList::block_user(&e, &user2);
+        Layered::transfer(&e, &user1, &user2, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn stack_transfer_checks_pausable() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup_layered(&e, &address, &[&user1, &user2]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &user1, 100);
+        stellar_pausable::pause(&e);
+        Layered::transfer(&e, &user1, &user2, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn stack_transfer_from_checks_allowlist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    setup_layered(&e, &address, &[&owner]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 50, 100);
+        Layered::transfer_from(&e, &spender, &owner, &recipient, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn stack_transfer_from_checks_blocklist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    setup_layered(&e, &address, &[&owner, &recipient]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 50, 100);
+        BlockList::block_user(&e, &owner);
+        Layered::transfer_from(&e, &spender, &owner, &recipient, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn stack_transfer_from_checks_pausable() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    setup_layered(&e, &address, &[&owner, &recipient]);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 100);
+        Base::approve(&e, &owner, &spender, 50, 100);
+        stellar_pausable::pause(&e);
+        Layered::transfer_from(&e, &spender, &owner, &recipient, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn stack_approve_checks_allowlist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    setup_layered(&e, &address, &[]);
+
+    e.as_contract(&address, || {
+        Layered::approve(&e, &owner, &spender, 50, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn stack_approve_checks_blocklist() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    setup_layered(&e, &address, &[&owner]);
+
+    e.as_contract(&address, || {
+        BlockList::block_user(&e, &owner);
+        Layered::approve(&e, &owner, &spender, 50, 100);
+    });
+}
+
+#[test]
+fn stack_approve_works_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    setup_layered(&e, &address, &[&owner]);
+
+    e.as_contract(&address, || {
+        stellar_pausable::pause(&e);
+        Layered::approve(&e, &owner, &spender, 50, 100);
+        assert_eq!(Layered::allowance(&e, &owner, &spender), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn stack_mint_checks_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+    setup_layered(&e, &address, &[&user]);
+
+    e.as_contract(&address, || {
+        Layered::mint(&e, &user, 1001);
+    });
+}
+
+const HOOK_LOG: Symbol = symbol_short!("HOOK_LOG");
+
+fn record_hook(e: &Env, entry: Symbol) {
+    let mut log: Vec<Symbol> = e.storage().instance().get(&HOOK_LOG).unwrap_or(Vec::new(e));
+    log.push_back(entry);
+    e.storage().instance().set(&HOOK_LOG, &log);
+}
+
+fn hook_log(e: &Env) -> Vec<Symbol> {
+    e.storage().instance().get(&HOOK_LOG).unwrap_or(Vec::new(e))
+}
+
+struct HookA;
+
+impl ContractOverrides for HookA {
+    fn before_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        record_hook(e, symbol_short!("a_before"));
+    }
+
+    fn after_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        record_hook(e, symbol_short!("a_after"));
+    }
+}
+
+struct HookB;
+
+impl ContractOverrides for HookB {
+    fn before_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        record_hook(e, symbol_short!("b_before"));
+    }
+
+    fn after_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _amount: i128) {
+        record_hook(e, symbol_short!("b_after"));
+    }
+}
+
+#[test]
+fn update_hooks_run_for_all_balance_changes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        HookA::mint(&e, &owner, 100);
+        HookA::approve(&e, &owner, &spender, 50, 100);
+        // approvals do not change balances
+        assert_eq!(hook_log(&e).len(), 2);
+    });
+
+    e.as_contract(&address, || {
+        HookA::transfer(&e, &owner, &recipient, 10);
+        HookA::transfer_from(&e, &spender, &owner, &recipient, 10);
+    });
+
+    e.as_contract(&address, || {
+        HookA::burn(&e, &owner, 10);
+        HookA::burn_from(&e, &spender, &owner, 10);
+
+        assert_eq!(hook_log(&e).len(), 10);
+        assert_eq!(Base::balance(&e, &owner), 60);
+        assert_eq!(Base::total_supply(&e), 80);
+    });
+}
+
+#[test]
+fn update_hooks_emit_events_after_update() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        HookA::mint(&e, &owner, 100);
+        HookA::transfer(&e, &owner, &recipient, 10);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_mint(&owner, 100);
+        event_assert.assert_fungible_transfer(&owner, &recipient, 10);
+    });
+}
+
+#[test]
+fn stack_update_hooks_run_in_order() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Stack::<HookA, HookB>::mint(&e, &user, 100);
+
+        assert_eq!(
+            hook_log(&e),
+            vec![
+                &e,
+                symbol_short!("a_before"),
+                symbol_short!("b_before"),
+                symbol_short!("b_after"),
+                symbol_short!("a_after"),
+            ]
+        );
+    });
+}
+
+#[test]
+fn stack_with_votes_moves_voting_units() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    type PausableVotes = Stack<WhenNotPaused, Votes>;
+
+    e.as_contract(&address, || {
+        PausableVotes::mint(&e, &owner, 100);
+        Votes::delegate(&e, &owner, &owner);
+        assert_eq!(Votes::get_votes(&e, &owner), 100);
+    });
+
+    e.as_contract(&address, || {
+        PausableVotes::approve(&e, &owner, &spender, 50, 100);
+    });
+
+    e.as_contract(&address, || {
+        PausableVotes::transfer(&e, &owner, &recipient, 10);
+        PausableVotes::burn_from(&e, &spender, &owner, 20);
+
+        assert_eq!(Votes::get_votes(&e, &owner), 70);
+        assert_eq!(Base::total_supply(&e), 80);
+    });
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::MultiToken;
+
+/// Burnable Trait for Multi Token
+///
+/// The `MultiTokenBurnable` trait extends the `MultiToken` trait to provide
+/// the capability to burn tokens. This trait is designed to be used in
+/// conjunction with the `MultiToken` trait.
+///
+/// The default implementations of the functions of this trait are provided by
+/// [`crate::ContractOverrides`], so they go through the checks and update
+/// hooks of the contract type:
+///
+/// ```ignore
+/// impl MultiTokenBurnable for ExampleContract {
+///     fn burn(e: &Env, from: Address, id: u32, amount: i128) {
+///         Self::ContractType::burn(e, &from, id, amount);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`MultiTokenBurnable`] trait for your Smart Contract,
+/// you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl MultiTokenBurnable for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait MultiTokenBurnable: MultiToken {
+    /// Destroys `amount` of tokens with `id` from `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   burn more tokens than `from` current balance.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[id: u32, amount: i128]`
+    fn burn(e: &Env, from: Address, id: u32, amount: i128);
+
+    /// Destroys `amount` of tokens with `id` from `from` on behalf of `from`.
+    /// `spender` must be `from` or one of its approved operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::MissingApprovalForAll`] - When `spender` is
+    ///   not an approved operator of `from`.
+    /// * also refer to [`MultiTokenBurnable::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[id: u32, amount: i128]`
+    fn burn_from(e: &Env, spender: Address, from: Address, id: u32, amount: i128);
+
+    /// Destroys `amounts[i]` of tokens with `ids[i]` from `from`, for every
+    /// `i`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::LengthMismatch`] - When `ids` and `amounts`
+    ///   have different lengths.
+    /// * also refer to [`MultiTokenBurnable::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+    fn burn_batch(e: &Env, from: Address, ids: Vec<u32>, amounts: Vec<i128>);
+
+    /// Destroys `amounts[i]` of tokens with `ids[i]` from `from`, for every
+    /// `i`, on behalf of `from`. `spender` must be `from` or one of its
+    /// approved operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::MissingApprovalForAll`] - When `spender` is
+    ///   not an approved operator of `from`.
+    /// * also refer to [`MultiTokenBurnable::burn_batch`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+    fn burn_batch_from(e: &Env, spender: Address, from: Address, ids: Vec<u32>, amounts: Vec<i128>);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a burn of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `id` - The identifier of the token type.
+/// * `amount` - The amount of tokens burned.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[id: u32, amount: i128]`
+pub fn emit_burn(e: &Env, from: &Address, id: u32, amount: i128) {
+    let topics = (symbol_short!("burn"), from);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a burn of multiple token types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens burned.
+///
+/// # Events
+///
+/// * topics - `["burn_batch", from: Address]`
+/// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+pub fn emit_burn_batch(e: &Env, from: &Address, ids: &Vec<u32>, amounts: &Vec<i128>) {
+    let topics = (Symbol::new(e, "burn_batch"), from);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
@@ -0,0 +1,103 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn burn_reduces_balance_and_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, 100);
+        Base::burn(&e, &account, 1, 40);
+
+        assert_eq!(Base::balance(&e, &account, 1), 60);
+        assert_eq!(Base::total_supply(&e, 1), 60);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn burn_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_from(&e, &operator, &owner, 1, 100);
+        assert_eq!(Base::balance(&e, &owner, 1), 0);
+        assert_eq!(Base::total_supply(&e, 1), 0);
+    });
+}
+
+#[test]
+fn burn_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 10]);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 50, 5]);
+        assert_eq!(Base::total_supply(&e, 1), 50);
+        assert_eq!(Base::total_supply(&e, 2), 5);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_batch_from(&e, &operator, &owner, &vec![&e, 1, 2], &vec![&e, 50, 5]);
+        assert_eq!(Base::balance(&e, &owner, 1), 0);
+        assert_eq!(Base::balance(&e, &owner, 2), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn burn_more_than_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, 100);
+        Base::burn(&e, &account, 1, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn burn_from_without_approval_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::burn_from(&e, &spender, &owner, 1, 10);
+    });
+}
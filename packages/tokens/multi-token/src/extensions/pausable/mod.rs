@@ -0,0 +1,24 @@
+//! # Pausable Extension for Multi Token
+//!
+//! The `pausable` module provides [`WhenNotPaused`], a contract type that
+//! rejects transfers, burns and mints of every token type while the contract
+//! is paused. The pause state is managed by the `stellar-pausable` package, so
+//! the contract is expected to implement the `stellar_pausable::Pausable`
+//! trait as well.
+//!
+//! Operator approvals are not restricted, because they do not move any tokens.
+//!
+//! `WhenNotPaused` can be used on its own or combined with other contract
+//! types through [`crate::Stack`]:
+//!
+//! ```ignore
+//! impl MultiToken for ExampleContract {
+//!     type ContractType = WhenNotPaused;
+//! }
+//! ```
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+pub use storage::WhenNotPaused;
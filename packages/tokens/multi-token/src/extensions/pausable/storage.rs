@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env};
+use stellar_pausable::when_not_paused;
+
+use crate::overrides::ContractOverrides;
+
+/// Contract type that requires the contract to be not paused for transfers,
+/// burns and mints.
+///
+/// # Errors
+///
+/// * `stellar_pausable::PausableError::EnforcedPause` - When the contract is
+///   paused.
+pub struct WhenNotPaused;
+
+impl ContractOverrides for WhenNotPaused {
+    fn check_transfer(e: &Env, _from: &Address, _to: &Address, _id: u32, _amount: i128) {
+        when_not_paused(e);
+    }
+
+    fn check_burn(e: &Env, _from: &Address, _id: u32, _amount: i128) {
+        when_not_paused(e);
+    }
+
+    fn check_mint(e: &Env, _to: &Address, _id: u32, _amount: i128) {
+        when_not_paused(e);
+    }
+}
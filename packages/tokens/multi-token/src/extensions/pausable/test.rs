@@ -0,0 +1,70 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_pausable::{pause, unpause};
+
+use crate::{extensions::pausable::storage::WhenNotPaused, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn transfer_works_after_unpause() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        WhenNotPaused::mint(&e, &from, 1, 100);
+        pause(&e);
+        unpause(&e);
+        WhenNotPaused::transfer(&e, &from, &to, 1, 50);
+        assert_eq!(Base::balance(&e, &to, 1), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn batch_transfer_panics_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &from, &vec![&e, 1, 2], &vec![&e, 100, 10]);
+        pause(&e);
+        WhenNotPaused::batch_transfer(&e, &from, &to, &vec![&e, 1, 2], &vec![&e, 1, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn burn_panics_when_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, 100);
+        pause(&e);
+        WhenNotPaused::burn(&e, &account, 1, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn mint_panics_when_paused() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        pause(&e);
+        WhenNotPaused::mint(&e, &account, 1, 100);
+    });
+}
@@ -0,0 +1,72 @@
+//! # Multi Token Contract Module.
+//!
+//! Implements utilities for handling multi tokens in a Soroban contract.
+//!
+//! A multi token contract manages any number of token types, each identified
+//! by an `id`, in a single contract. Every token type can be fungible (e.g.
+//! in-game currencies), non-fungible (a supply of 1) or semi-fungible (e.g.
+//! items with a limited supply), which avoids deploying one contract per token
+//! type.
+//!
+//! ## Design Overview
+//!
+//! This module is structured to provide flexibility to developers by splitting
+//! functionalities into higher-level and lower-level operations:
+//!
+//! - **High-Level Functions**: These include all necessary checks,
+//!   verifications, authorizations, state-changing logic, and event emissions.
+//!   They are provided by [`ContractOverrides`], so that extensions can
+//!   override them through the contract type of [`MultiToken`].
+//!
+//! - **Low-Level Functions**: These offer granular control for developers who
+//!   need to compose their own workflows. Such functions expose internal
+//!   mechanisms and require the caller to handle verifications and
+//!   authorizations manually.
+//!
+//! ## Structure
+//!
+//! The base module includes:
+//!
+//! - Balances keyed by account and token id
+//! - Total supply of every token id
+//! - Single and batch transfers
+//! - Operator approvals
+//! - URIs, either per token id or composed from a base URI
+//!
+//! The following optional extensions are available:
+//!
+//! - *Burnable* enables token holders to destroy their tokens, reducing the
+//!   total supply of the token ids.
+//! - *Pausable* rejects transfers, burns and mints while the contract is
+//!   paused.
+//!
+//! ## Compatibility and Compliance
+//!
+//! The ERC-1155 interface is adapted to Stellar Ecosystem, with the following
+//! differences:
+//!
+//! - `transfer()` and `batch_transfer()` are made available for consistency
+//!   with the Fungible and Non-Fungible Token interfaces, next to
+//!   `transfer_from()` and `batch_transfer_from()` for operators.
+//! - Operator approvals expire at `live_until_ledger`, like the approvals of
+//!   Non-Fungible Tokens.
+//! - Balances are `i128`, like the balances of Fungible Tokens.
+//! - Acceptance checks on the receiver are not performed, the caller is
+//!   responsible to confirm that the recipient is capable of receiving the
+//!   tokens.
+#![no_std]
+
+mod extensions;
+mod multi_token;
+mod overrides;
+mod storage;
+
+pub use extensions::{burnable, pausable};
+pub use multi_token::{
+    emit_approve_for_all, emit_mint, emit_mint_batch, emit_transfer, emit_transfer_batch, emit_uri,
+    MultiToken, MultiTokenError,
+};
+pub use overrides::{Base, ContractOverrides, Stack};
+pub use storage::MultiTokenStorageKey;
+
+mod test;
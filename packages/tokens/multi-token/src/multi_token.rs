@@ -0,0 +1,379 @@
+use soroban_sdk::{contracterror, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ContractOverrides;
+
+/// Max. number of digits in a token ID (u32)
+pub const MAX_NUM_DIGITS: usize = 10;
+
+/// Max. allowed length for a base uri.
+pub const MAX_BASE_URI_LEN: usize = 200;
+
+/// Vanilla Multi Token Trait
+///
+/// The `MultiToken` trait defines the core functionality for multi tokens,
+/// where a single contract manages any number of token types, each identified
+/// by an `id`. Every token type can be fungible (e.g. in-game currencies),
+/// non-fungible (a supply of 1) or semi-fungible (e.g. items with a limited
+/// supply).
+///
+/// Event for `mint` is defined, but `mint` function itself is not included
+/// as a method in this trait because it is not a part of the standard,
+/// the function signature may change depending on the implementation.
+///
+/// We do provide the functions [`crate::ContractOverrides::mint`] and
+/// [`crate::ContractOverrides::mint_batch`] for this purpose.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`MultiToken`] trait for your Smart Contract,
+/// you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl MultiToken for MyContract {
+///     ContractType = {Your Contract Type Here};
+///
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait MultiToken {
+    /// Helper type that allows us to override some of the functionality of the
+    /// base trait based on the extensions implemented. You should use
+    /// [`crate::Base`] as the type if you are not using any extension that
+    /// provides a contract type (e.g. [`crate::pausable::WhenNotPaused`]).
+    type ContractType: ContractOverrides;
+
+    /// Returns the amount of tokens with `id` held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `id` - The identifier of the token type.
+    fn balance(e: &Env, account: Address, id: u32) -> i128;
+
+    /// Returns the balances of multiple `(account, id)` pairs, where the
+    /// `i`-th balance is the one of `accounts[i]` for `ids[i]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `accounts` - The addresses for which the balances are being queried.
+    /// * `ids` - The identifiers of the token types.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `ids` have
+    ///   different lengths.
+    fn balance_of_batch(e: &Env, accounts: Vec<Address>, ids: Vec<u32>) -> Vec<i128>;
+
+    /// Returns the total amount of tokens with `id` in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The identifier of the token type.
+    fn total_supply(e: &Env, id: u32) -> i128;
+
+    /// Transfers `amount` of tokens with `id` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[id: u32, amount: i128]`
+    fn transfer(e: &Env, from: Address, to: Address, id: u32, amount: i128);
+
+    /// Transfers `amount` of tokens with `id` from `from` to `to` on behalf of
+    /// `from`. `spender` must be `from` or one of its approved operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::MissingApprovalForAll`] - When `spender` is not an
+    ///   approved operator of `from`.
+    /// * also refer to [`MultiToken::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[id: u32, amount: i128]`
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, id: u32, amount: i128);
+
+    /// Transfers `amounts[i]` of tokens with `ids[i]` from `from` to `to`, for
+    /// every `i`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `ids` and `amounts` have
+    ///   different lengths.
+    /// * also refer to [`MultiToken::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+    fn batch_transfer(e: &Env, from: Address, to: Address, ids: Vec<u32>, amounts: Vec<i128>);
+
+    /// Transfers `amounts[i]` of tokens with `ids[i]` from `from` to `to`, for
+    /// every `i`, on behalf of `from`. `spender` must be `from` or one of its
+    /// approved operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::MissingApprovalForAll`] - When `spender` is not an
+    ///   approved operator of `from`.
+    /// * also refer to [`MultiToken::batch_transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<u32>,
+        amounts: Vec<i128>,
+    );
+
+    /// Approve or remove `operator` as an operator for the owner.
+    ///
+    /// Operators can call `transfer_from()` and `batch_transfer_from()` for
+    /// any token held by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - Account to add to the set of authorized operators.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    ///   If `live_until_ledger` is `0`, the approval is revoked.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+    ///   less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32);
+
+    /// Returns whether the `operator` is allowed to manage all the tokens of
+    /// `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - Account to be checked.
+    fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool;
+
+    /// Returns the Uniform Resource Identifier (URI) for the token type with
+    /// `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The identifier of the token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetUri`] - If neither a URI for `id` nor a base
+    ///   URI is set.
+    fn uri(e: &Env, id: u32) -> String;
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MultiTokenError {
+    /// Indicates an error related to the current balance of account from which
+    /// tokens are expected to be transferred.
+    InsufficientBalance = 300,
+    /// Indicates a failure with the `spender`s approval. Used in transfers.
+    MissingApprovalForAll = 301,
+    /// Indicates an invalid value for `live_until_ledger` when setting
+    /// approvals.
+    InvalidLiveUntilLedger = 302,
+    /// Indicates an error when an input that must be >= 0
+    LessThanZero = 303,
+    /// Indicates overflow when adding two values
+    MathOverflow = 304,
+    /// Indicates that the lengths of the ids and the amounts (or accounts) of
+    /// a batch differ.
+    LengthMismatch = 305,
+    /// Indicates access to an unset URI.
+    UnsetUri = 306,
+    /// Indicates the length of the base URI exceeds the maximum allowed.
+    BaseUriMaxLenExceeded = 307,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a transfer of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `id` - The identifier of the token type.
+/// * `amount` - The amount of tokens transferred.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[id: u32, amount: i128]`
+pub fn emit_transfer(e: &Env, from: &Address, to: &Address, id: u32, amount: i128) {
+    let topics = (symbol_short!("transfer"), from, to);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a transfer of multiple token types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens transferred.
+///
+/// # Events
+///
+/// * topics - `["transfer_batch", from: Address, to: Address]`
+/// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+pub fn emit_transfer_batch(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    ids: &Vec<u32>,
+    amounts: &Vec<i128>,
+) {
+    let topics = (Symbol::new(e, "transfer_batch"), from, to);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
+
+/// Emits an event when `owner` enables `operator` to manage all of its
+/// tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `operator` - The address of the operator.
+/// * `live_until_ledger` - The ledger number at which the approval expires. If
+///   `live_until_ledger` is `0`, the approval is revoked.
+///
+/// # Events
+///
+/// * topics - `["approve_for_all", owner: Address]`
+/// * data - `[operator: Address, live_until_ledger: u32]`
+pub fn emit_approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+    let topics = (Symbol::new(e, "approve_for_all"), owner);
+    e.events().publish(topics, (operator, live_until_ledger))
+}
+
+/// Emits an event indicating a mint of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `id` - The identifier of the token type.
+/// * `amount` - The amount of tokens minted.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[id: u32, amount: i128]`
+pub fn emit_mint(e: &Env, to: &Address, id: u32, amount: i128) {
+    let topics = (symbol_short!("mint"), to);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a mint of multiple token types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens minted.
+///
+/// # Events
+///
+/// * topics - `["mint_batch", to: Address]`
+/// * data - `[ids: Vec<u32>, amounts: Vec<i128>]`
+pub fn emit_mint_batch(e: &Env, to: &Address, ids: &Vec<u32>, amounts: &Vec<i128>) {
+    let topics = (Symbol::new(e, "mint_batch"), to);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
+
+/// Emits an event when the URI of the token type with `id` is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `id` - The identifier of the token type.
+/// * `uri` - The new URI.
+///
+/// # Events
+///
+/// * topics - `["uri", id: u32]`
+/// * data - `[uri: String]`
+pub fn emit_uri(e: &Env, id: u32, uri: &String) {
+    let topics = (symbol_short!("uri"), id);
+    e.events().publish(topics, uri.clone())
+}
@@ -0,0 +1,313 @@
+use core::marker::PhantomData;
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::{
+    burnable::{emit_burn, emit_burn_batch},
+    multi_token::{emit_mint, emit_mint_batch, emit_transfer, emit_transfer_batch},
+};
+
+/// Based on the extension, some default behavior of [`crate::MultiToken`]
+/// might have to be overridden. This is a helper trait that allows us this
+/// override mechanism that favors the DevX.
+///
+/// The way to provide different default implementations for different
+/// extensions is by implementing the trait for different types (unit structs),
+/// and letting the `MultiToken` trait switch between them with its
+/// `ContractType` associated type, exactly like the `ContractOverrides` traits
+/// of the fungible and non-fungible token packages.
+///
+/// Example:
+///
+/// ```rust
+/// impl MultiToken for ExampleContract {
+///     type ContractType = Base;
+///
+///     fn balance(e: &Env, account: Address, id: u32) -> i128 {
+///         Self::ContractType::balance(e, &account, id)
+///     }
+///
+///     fn transfer(e: &Env, from: Address, to: Address, id: u32, amount: i128) {
+///         Self::ContractType::transfer(e, &from, &to, id, amount);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+///
+/// # Checks
+///
+/// Extensions that only need to restrict who can transfer, burn or mint
+/// tokens (e.g. [`crate::pausable::WhenNotPaused`]) should override the
+/// `check_*` functions instead of the state changing functions. The default
+/// implementations run the respective check for every token type they move,
+/// and contract types that only override checks can be combined with
+/// [`Stack`].
+///
+/// # Update Hooks
+///
+/// Every balance change of the default implementations goes through
+/// [`ContractOverrides::update`], once per token type for batches, which calls
+/// `before_update`, then [`Base::update`] and finally `after_update`.
+/// Extensions that have to keep additional accounting in sync with the
+/// balances should override these hooks, so that the accounting applies to
+/// all the balance changing functions at once.
+pub trait ContractOverrides {
+    /// Called before every transfer of tokens with `id`, including the ones of
+    /// a batch. Should panic if the transfer is not permitted.
+    fn check_transfer(_e: &Env, _from: &Address, _to: &Address, _id: u32, _amount: i128) {}
+
+    /// Called before every burn of tokens with `id`, including the ones of a
+    /// batch. Should panic if the burn is not permitted.
+    fn check_burn(_e: &Env, _from: &Address, _id: u32, _amount: i128) {}
+
+    /// Called before every mint of tokens with `id`, including the ones of a
+    /// batch. Should panic if the mint is not permitted.
+    fn check_mint(_e: &Env, _to: &Address, _id: u32, _amount: i128) {}
+
+    /// Called before every balance change made by
+    /// [`ContractOverrides::update`]. `from` is `None` for mints and `to`
+    /// is `None` for burns.
+    fn before_update(
+        _e: &Env,
+        _from: Option<&Address>,
+        _to: Option<&Address>,
+        _id: u32,
+        _amount: i128,
+    ) {
+    }
+
+    /// Called after every balance change made by [`ContractOverrides::update`].
+    /// `from` is `None` for mints and `to` is `None` for burns.
+    fn after_update(
+        _e: &Env,
+        _from: Option<&Address>,
+        _to: Option<&Address>,
+        _id: u32,
+        _amount: i128,
+    ) {
+    }
+
+    /// Wraps [`Base::update`] with the `before_update` and `after_update`
+    /// hooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens, or `None` for mints.
+    /// * `to` - The address receiving the tokens, or `None` for burns.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, id: u32, amount: i128) {
+        Self::before_update(e, from, to, id, amount);
+        Base::update(e, from, to, id, amount);
+        Self::after_update(e, from, to, id, amount);
+    }
+
+    fn balance(e: &Env, account: &Address, id: u32) -> i128 {
+        Base::balance(e, account, id)
+    }
+
+    fn balance_of_batch(e: &Env, accounts: &Vec<Address>, ids: &Vec<u32>) -> Vec<i128> {
+        Base::balance_of_batch(e, accounts, ids)
+    }
+
+    fn total_supply(e: &Env, id: u32) -> i128 {
+        Base::total_supply(e, id)
+    }
+
+    fn transfer(e: &Env, from: &Address, to: &Address, id: u32, amount: i128) {
+        Self::check_transfer(e, from, to, id, amount);
+        from.require_auth();
+        Self::update(e, Some(from), Some(to), id, amount);
+        emit_transfer(e, from, to, id, amount);
+    }
+
+    fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        id: u32,
+        amount: i128,
+    ) {
+        Self::check_transfer(e, from, to, id, amount);
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Self::update(e, Some(from), Some(to), id, amount);
+        emit_transfer(e, from, to, id, amount);
+    }
+
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Base::check_batch(e, ids, amounts);
+        from.require_auth();
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            Self::check_transfer(e, from, to, id, amount);
+            Self::update(e, Some(from), Some(to), id, amount);
+        }
+        emit_transfer_batch(e, from, to, ids, amounts);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::check_batch(e, ids, amounts);
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            Self::check_transfer(e, from, to, id, amount);
+            Self::update(e, Some(from), Some(to), id, amount);
+        }
+        emit_transfer_batch(e, from, to, ids, amounts);
+    }
+
+    fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, owner, operator, live_until_ledger);
+    }
+
+    fn is_approved_for_all(e: &Env, owner: &Address, operator: &Address) -> bool {
+        Base::is_approved_for_all(e, owner, operator)
+    }
+
+    fn uri(e: &Env, id: u32) -> String {
+        Base::uri(e, id)
+    }
+
+    fn burn(e: &Env, from: &Address, id: u32, amount: i128) {
+        Self::check_burn(e, from, id, amount);
+        from.require_auth();
+        Self::update(e, Some(from), None, id, amount);
+        emit_burn(e, from, id, amount);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, id: u32, amount: i128) {
+        Self::check_burn(e, from, id, amount);
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Self::update(e, Some(from), None, id, amount);
+        emit_burn(e, from, id, amount);
+    }
+
+    fn burn_batch(e: &Env, from: &Address, ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Base::check_batch(e, ids, amounts);
+        from.require_auth();
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            Self::check_burn(e, from, id, amount);
+            Self::update(e, Some(from), None, id, amount);
+        }
+        emit_burn_batch(e, from, ids, amounts);
+    }
+
+    fn burn_batch_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::check_batch(e, ids, amounts);
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            Self::check_burn(e, from, id, amount);
+            Self::update(e, Some(from), None, id, amount);
+        }
+        emit_burn_batch(e, from, ids, amounts);
+    }
+
+    /// Creates `amount` of tokens with `id` and assigns them to `to`.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    fn mint(e: &Env, to: &Address, id: u32, amount: i128) {
+        Self::check_mint(e, to, id, amount);
+        Self::update(e, None, Some(to), id, amount);
+        emit_mint(e, to, id, amount);
+    }
+
+    /// Creates `amounts[i]` of tokens with `ids[i]`, for every `i`, and
+    /// assigns them to `to`.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    fn mint_batch(e: &Env, to: &Address, ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Base::check_batch(e, ids, amounts);
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            Self::check_mint(e, to, id, amount);
+            Self::update(e, None, Some(to), id, amount);
+        }
+        emit_mint_batch(e, to, ids, amounts);
+    }
+}
+
+/// Default marker type
+pub struct Base;
+
+// No override required for the `Base` contract type.
+impl ContractOverrides for Base {}
+
+/// Contract type that combines the checks and update hooks of two contract
+/// types, so that multiple extensions can be enabled on the same token. Deeper
+/// stacks are built by nesting, for example `Stack<A, Stack<B, C>>`.
+///
+/// The checks and the `before_update` hook of `Top` run before the ones of
+/// `Rest`, while the `after_update` hook of `Top` runs after the one of
+/// `Rest`. The state changing functions use the default implementations of
+/// [`ContractOverrides`].
+///
+/// # Notes
+///
+/// Only the `check_*`, `before_update` and `after_update` functions are
+/// combined. Overrides of any other function of `Top` or `Rest` are ignored.
+pub struct Stack<Top, Rest>(PhantomData<(Top, Rest)>);
+
+impl<Top: ContractOverrides, Rest: ContractOverrides> ContractOverrides for Stack<Top, Rest> {
+    fn check_transfer(e: &Env, from: &Address, to: &Address, id: u32, amount: i128) {
+        Top::check_transfer(e, from, to, id, amount);
+        Rest::check_transfer(e, from, to, id, amount);
+    }
+
+    fn check_burn(e: &Env, from: &Address, id: u32, amount: i128) {
+        Top::check_burn(e, from, id, amount);
+        Rest::check_burn(e, from, id, amount);
+    }
+
+    fn check_mint(e: &Env, to: &Address, id: u32, amount: i128) {
+        Top::check_mint(e, to, id, amount);
+        Rest::check_mint(e, to, id, amount);
+    }
+
+    fn before_update(e: &Env, from: Option<&Address>, to: Option<&Address>, id: u32, amount: i128) {
+        Top::before_update(e, from, to, id, amount);
+        Rest::before_update(e, from, to, id, amount);
+    }
+
+    fn after_update(e: &Env, from: Option<&Address>, to: Option<&Address>, id: u32, amount: i128) {
+        Rest::after_update(e, from, to, id, amount);
+        Top::after_update(e, from, to, id, amount);
+    }
+}
@@ -0,0 +1,387 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    multi_token::{
+        emit_approve_for_all, emit_uri, MultiTokenError, MAX_BASE_URI_LEN, MAX_NUM_DIGITS,
+    },
+    Base,
+};
+
+/// Storage keys for the data associated with `MultiToken`
+#[contracttype]
+pub enum MultiTokenStorageKey {
+    Balance(Address, u32 /* id */),
+    TotalSupply(u32 /* id */),
+    ApprovalForAll(Address /* owner */, Address /* operator */),
+    BaseUri,
+    TokenUri(u32 /* id */),
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the amount of tokens with `id` held by `account`. Defaults to
+    /// `0` if no balance is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `id` - The identifier of the token type.
+    pub fn balance(e: &Env, account: &Address, id: u32) -> i128 {
+        let key = MultiTokenStorageKey::Balance(account.clone(), id);
+        if let Some(balance) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            balance
+        } else {
+            0
+        }
+    }
+
+    /// Returns the balances of multiple `(account, id)` pairs, where the
+    /// `i`-th balance is the one of `accounts[i]` for `ids[i]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `accounts` - The addresses for which the balances are being queried.
+    /// * `ids` - The identifiers of the token types.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `ids` have
+    ///   different lengths.
+    pub fn balance_of_batch(e: &Env, accounts: &Vec<Address>, ids: &Vec<u32>) -> Vec<i128> {
+        if accounts.len() != ids.len() {
+            panic_with_error!(e, MultiTokenError::LengthMismatch);
+        }
+        let mut balances = Vec::new(e);
+        for (account, id) in accounts.iter().zip(ids.iter()) {
+            balances.push_back(Base::balance(e, &account, id));
+        }
+        balances
+    }
+
+    /// Returns the total amount of tokens with `id` in circulation. Defaults
+    /// to `0` if no supply is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The identifier of the token type.
+    pub fn total_supply(e: &Env, id: u32) -> i128 {
+        let key = MultiTokenStorageKey::TotalSupply(id);
+        if let Some(supply) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            supply
+        } else {
+            0
+        }
+    }
+
+    /// Returns whether the operator is allowed to manage all the tokens of
+    /// the owner:
+    /// * `true` - If the operator has a valid, non-expired approval
+    /// * `false` - If there is no approval or if the approval has expired
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address to check for approval status.
+    pub fn is_approved_for_all(e: &Env, owner: &Address, operator: &Address) -> bool {
+        let key = MultiTokenStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+        match e.storage().temporary().get::<_, u32>(&key) {
+            Some(live_until_ledger) => live_until_ledger >= e.ledger().sequence(),
+            None => false,
+        }
+    }
+
+    /// Returns the base URI, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn base_uri(e: &Env) -> Option<String> {
+        e.storage().instance().get(&MultiTokenStorageKey::BaseUri)
+    }
+
+    /// Returns the URI for the token type with `id`: the URI set for `id` if
+    /// any, otherwise the base URI followed by `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The identifier of the token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetUri`] - If neither a URI for `id` nor a base
+    ///   URI is set.
+    pub fn uri(e: &Env, id: u32) -> String {
+        let key = MultiTokenStorageKey::TokenUri(id);
+        if let Some(uri) = e.storage().persistent().get::<_, String>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            return uri;
+        }
+
+        let Some(base_uri) = Base::base_uri(e) else {
+            panic_with_error!(e, MultiTokenError::UnsetUri);
+        };
+        Base::compose_uri_for_token(e, base_uri, id)
+    }
+
+    /// Composes and returns a URI for a specific `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The base URI. Assumes it's valid and ends by `/`.
+    /// * `id` - The identifier of the token type.
+    pub fn compose_uri_for_token(e: &Env, base_uri: String, id: u32) -> String {
+        let len = base_uri.len() as usize;
+
+        if len > 0 {
+            // account for potentially the max num of digits of the type representing
+            // `id` (currently `u32`)
+            let uri = &mut [0u8; MAX_BASE_URI_LEN + MAX_NUM_DIGITS];
+
+            let (id, digits) = Base::token_id_to_string(e, id);
+
+            base_uri.copy_into_slice(&mut uri[..len]);
+            let end = len + digits;
+            id.copy_into_slice(&mut uri[len..end]);
+
+            String::from_bytes(e, &uri[..end])
+        } else {
+            String::from_str(e, "")
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets or removes operator approval for managing all the tokens of the
+    /// owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operator` - The address being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    ///   If `live_until_ledger` is `0`, the approval is revoked.
+    ///   `live_until_ledger` argument is implicitly bounded by the maximum
+    ///   allowed TTL extension for a temporary storage entry and specifying a
+    ///   higher value will cause the code to panic.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+    ///   less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    pub fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+        owner.require_auth();
+
+        let key = MultiTokenStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+
+        if live_until_ledger == 0 {
+            e.storage().temporary().remove(&key);
+            emit_approve_for_all(e, owner, operator, live_until_ledger);
+            return;
+        }
+
+        let current_ledger = e.ledger().sequence();
+        if live_until_ledger < current_ledger {
+            panic_with_error!(e, MultiTokenError::InvalidLiveUntilLedger);
+        }
+
+        e.storage().temporary().set(&key, &live_until_ledger);
+
+        let live_for = live_until_ledger - current_ledger;
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+
+        emit_approve_for_all(e, owner, operator, live_until_ledger);
+    }
+
+    /// Sets the base URI, used for the token types without their own URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The base URI, assuming it's a valid URI and ends with
+    ///   `/`.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `base_uri` exceeds the maximum allowed.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_base_uri(e: &Env, base_uri: String) {
+        if base_uri.len() as usize > MAX_BASE_URI_LEN {
+            panic_with_error!(e, MultiTokenError::BaseUriMaxLenExceeded)
+        }
+        e.storage().instance().set(&MultiTokenStorageKey::BaseUri, &base_uri);
+    }
+
+    /// Sets the URI of the token type with `id`, overriding the base URI for
+    /// this token type.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The identifier of the token type.
+    /// * `uri` - The URI of the token type.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["uri", id: u32]`
+    /// * data - `[uri: String]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_token_uri(e: &Env, id: u32, uri: &String) {
+        e.storage().persistent().set(&MultiTokenStorageKey::TokenUri(id), uri);
+        emit_uri(e, id, uri);
+    }
+
+    /// Low-level function for handling transfers, mints and burns of tokens
+    /// with `id`, without handling authorization. Updates the balances and
+    /// the total supply of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens, or `None` for mints.
+    /// * `to` - The address receiving the tokens, or `None` for burns.
+    /// * `id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`MultiTokenError::MathOverflow`] - When the total supply of `id`
+    ///   overflows.
+    pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, id: u32, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, MultiTokenError::LessThanZero);
+        }
+
+        if let Some(account) = from {
+            let from_balance = Base::balance(e, account, id);
+            if from_balance < amount {
+                panic_with_error!(e, MultiTokenError::InsufficientBalance);
+            }
+            // can't underflow because of the check above
+            e.storage()
+                .persistent()
+                .set(&MultiTokenStorageKey::Balance(account.clone(), id), &(from_balance - amount));
+        } else {
+            // `from` is None, so we're minting tokens
+            let Some(supply) = Base::total_supply(e, id).checked_add(amount) else {
+                panic_with_error!(e, MultiTokenError::MathOverflow);
+            };
+            e.storage().persistent().set(&MultiTokenStorageKey::TotalSupply(id), &supply);
+        }
+
+        if let Some(account) = to {
+            // can't overflow because the total supply of `id` would have
+            // overflowed first
+            let to_balance = Base::balance(e, account, id) + amount;
+            e.storage()
+                .persistent()
+                .set(&MultiTokenStorageKey::Balance(account.clone(), id), &to_balance);
+        } else {
+            // `to` is None, so we're burning tokens; can't underflow because
+            // the supply is at least the balance of `from`
+            let supply = Base::total_supply(e, id) - amount;
+            e.storage().persistent().set(&MultiTokenStorageKey::TotalSupply(id), &supply);
+        }
+    }
+
+    /// Low-level function for checking that `spender` can move the tokens of
+    /// `owner`, without handling authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to move the tokens.
+    /// * `owner` - The address holding the tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::MissingApprovalForAll`] - If `spender` is neither
+    ///   `owner` nor an approved operator of `owner`.
+    pub fn check_spender_approval(e: &Env, spender: &Address, owner: &Address) {
+        if spender != owner && !Base::is_approved_for_all(e, owner, spender) {
+            panic_with_error!(e, MultiTokenError::MissingApprovalForAll);
+        }
+    }
+
+    /// Low-level function for checking that `ids` and `amounts` of a batch
+    /// have the same length.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - If the lengths differ.
+    pub fn check_batch(e: &Env, ids: &Vec<u32>, amounts: &Vec<i128>) {
+        if ids.len() != amounts.len() {
+            panic_with_error!(e, MultiTokenError::LengthMismatch);
+        }
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Converts `u32` to `String` and returns it alongside the
+    /// number of digits.
+    fn token_id_to_string(e: &Env, value: u32) -> (String, usize) {
+        if value == 0 {
+            return (String::from_str(e, "0"), 1);
+        }
+
+        let mut digits: usize = 0;
+        let mut temp: u32 = value;
+
+        while temp > 0 {
+            digits += 1;
+            temp /= 10;
+        }
+
+        let mut slice: [u8; MAX_NUM_DIGITS] = [0u8; MAX_NUM_DIGITS];
+        let mut index = digits;
+        temp = value;
+
+        while temp > 0 {
+            index -= 1;
+            // 48 is the '0' ASCII character
+            slice[index] = (48 + temp % 10) as u8;
+            temp /= 10;
+        }
+
+        (String::from_bytes(e, &slice[..digits]), digits)
+    }
+}
@@ -0,0 +1,286 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_tracks_balances_and_supply() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, 100);
+        Base::mint(&e, &account, 2, 1);
+
+        assert_eq!(Base::balance(&e, &account, 1), 100);
+        assert_eq!(Base::balance(&e, &account, 2), 1);
+        assert_eq!(Base::balance(&e, &account, 3), 0);
+        assert_eq!(Base::total_supply(&e, 1), 100);
+        assert_eq!(Base::total_supply(&e, 2), 1);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 1, 100);
+        Base::transfer(&e, &from, &to, 1, 40);
+
+        assert_eq!(Base::balance(&e, &from, 1), 60);
+        assert_eq!(Base::balance(&e, &to, 1), 40);
+        assert_eq!(Base::total_supply(&e, 1), 100);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn transfer_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_from(&e, &operator, &owner, &to, 1, 30);
+        assert_eq!(Base::balance(&e, &owner, 1), 70);
+        assert_eq!(Base::balance(&e, &to, 1), 30);
+    });
+}
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &from, &vec![&e, 1, 2, 3], &vec![&e, 100, 10, 1]);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer(&e, &from, &to, &vec![&e, 1, 3], &vec![&e, 50, 1]);
+
+        let balances = Base::balance_of_batch(
+            &e,
+            &vec![&e, from.clone(), from.clone(), to.clone(), to.clone()],
+            &vec![&e, 1, 3, 1, 3],
+        );
+        assert_eq!(balances, vec![&e, 50, 0, 50, 1]);
+        assert_eq!(Base::total_supply(&e, 3), 1);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+fn batch_transfer_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 10]);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(&e, &operator, &owner, &to, &vec![&e, 1, 2], &vec![&e, 10, 10]);
+        assert_eq!(Base::balance(&e, &to, 1), 10);
+        assert_eq!(Base::balance(&e, &to, 2), 10);
+        assert_eq!(Base::balance(&e, &owner, 2), 0);
+    });
+}
+
+#[test]
+fn approve_for_all_expires_and_revokes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 100);
+    });
+
+    e.ledger().set_sequence_number(101);
+
+    e.as_contract(&address, || {
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 200);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 0);
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+    });
+}
+
+#[test]
+fn uri_from_base_uri_and_token_uri() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_base_uri(&e, String::from_str(&e, "https://game.io/items/"));
+        Base::set_token_uri(&e, 7, &String::from_str(&e, "ipfs://legendary-sword"));
+
+        assert_eq!(Base::uri(&e, 42), String::from_str(&e, "https://game.io/items/42"));
+        assert_eq!(Base::uri(&e, 0), String::from_str(&e, "https://game.io/items/0"));
+        assert_eq!(Base::uri(&e, 7), String::from_str(&e, "ipfs://legendary-sword"));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")]
+fn uri_unset_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::uri(&e, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #307)")]
+fn set_base_uri_too_long_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let base_uri = String::from_str(&e, &"a".repeat(201));
+        Base::set_base_uri(&e, base_uri);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn transfer_insufficient_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 1, 100);
+        Base::mint(&e, &from, 2, 100);
+        Base::transfer(&e, &from, &to, 1, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn transfer_from_without_approval_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::transfer_from(&e, &spender, &owner, &spender, 1, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn approve_for_all_in_the_past_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 9);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn mint_negative_amount_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn mint_supply_overflow_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &account, 1, i128::MAX);
+        Base::mint(&e, &account, 1, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn batch_transfer_length_mismatch_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &from, 1, 100);
+        Base::batch_transfer(&e, &from, &to, &vec![&e, 1, 2], &vec![&e, 10]);
+    });
+}
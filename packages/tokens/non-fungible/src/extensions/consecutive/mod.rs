@@ -60,7 +60,8 @@
 //!   in large batches.
 //! - **IMPORTANT**: For minting tokens ONLY the function `batch_mint` provided
 //!   in this extension must be used. Using other minting functions will break
-//!   the logic of tracking ownership.
+//!   the logic of tracking ownership, so `mint` of [`crate::ContractOverrides`]
+//!   panics for [`Consecutive`].
 pub mod storage;
 use soroban_sdk::{Address, Env, Symbol};
 pub use storage::Consecutive;
@@ -7,7 +7,6 @@ use stellar_constants::{
 };
 
 use crate::{
-    burnable::emit_burn,
     emit_transfer,
     extensions::consecutive::emit_consecutive_mint,
     sequential::{self as sequential},
@@ -25,12 +24,16 @@ impl ContractOverrides for Consecutive {
         Consecutive::token_uri(e, token_id)
     }
 
-    fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
-        Consecutive::transfer(e, from, to, token_id);
+    fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, token_id: u32) {
+        Self::before_update(e, from, to, token_id);
+        Consecutive::update(e, from, to, token_id);
+        Self::after_update(e, from, to, token_id);
     }
 
-    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
-        Consecutive::transfer_from(e, spender, from, to, token_id);
+    fn mint(e: &Env, _to: &Address, _token_id: u32) {
+        // Tokens minted outside of `batch_mint` would break the ownership
+        // inference, since their ids are not reserved by the counter.
+        panic_with_error!(e, NonFungibleTokenError::MintNotSupported);
     }
 
     fn approve(
@@ -196,24 +199,37 @@ impl Consecutive {
     ///     admin.require_auth();
     ///
     ///     // 2. Only then call the actual mint function
-    ///     Consecutive::batch_mint(e, &to, amount);
+    ///     Consecutive::batch_mint::<Consecutive>(e, &to, amount);
     /// }
     /// ```
     ///
     /// Failing to add proper authorization could allow anyone to mint tokens!
-    pub fn batch_mint(e: &Env, to: &Address, amount: u32) -> u32 {
+    ///
+    /// # Notes
+    ///
+    /// The `before_update` and `after_update` hooks of `T` run for every
+    /// minted token, so their cost grows with `amount`.
+    pub fn batch_mint<T: ContractOverrides>(e: &Env, to: &Address, amount: u32) -> u32 {
         if amount == 0 || amount > MAX_TOKENS_IN_BATCH as u32 {
             panic_with_error!(&e, NonFungibleTokenError::InvalidAmount);
         }
 
         let first_id = sequential::increment_token_id(e, amount);
+        let last_id = first_id + amount - 1;
+
+        for token_id in first_id..=last_id {
+            T::before_update(e, None, Some(to), token_id);
+        }
 
         Base::increase_balance(e, to, amount);
-        let last_id = first_id + amount - 1;
 
         Self::set_ownership_in_bucket(e, last_id);
         e.storage().persistent().set(&NFTConsecutiveStorageKey::Owner(last_id), &to);
 
+        for token_id in first_id..=last_id {
+            T::after_update(e, None, Some(to), token_id);
+        }
+
         emit_consecutive_mint(e, to, first_id, last_id);
 
         // return the last minted id
@@ -242,10 +258,7 @@ impl Consecutive {
     ///
     /// Authorization for `from` is required.
     pub fn burn(e: &Env, from: &Address, token_id: u32) {
-        from.require_auth();
-
-        Consecutive::update(e, Some(from), None, token_id);
-        emit_burn(e, from, token_id);
+        <Consecutive as ContractOverrides>::burn(e, from, token_id);
     }
 
     /// Destroys the token with `token_id` from `from`, ensuring ownership
@@ -273,12 +286,7 @@ impl Consecutive {
     ///
     /// Authorization for `spender` is required.
     pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
-        spender.require_auth();
-
-        Base::check_spender_approval(e, spender, from, token_id);
-
-        Consecutive::update(e, Some(from), None, token_id);
-        emit_burn(e, from, token_id);
+        <Consecutive as ContractOverrides>::burn_from(e, spender, from, token_id);
     }
 
     /// Transfers a non-fungible token (NFT), ensuring ownership checks.
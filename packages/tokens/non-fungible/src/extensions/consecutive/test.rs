@@ -2,7 +2,9 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env, String, Vec};
+use soroban_sdk::{
+    contract, symbol_short, testutils::Address as _, vec, Address, Env, String, Symbol, Vec,
+};
 use stellar_event_assertion::EventAssertion;
 
 use super::storage::{find_bit_in_bucket, find_bit_in_item, IDS_IN_BUCKET, MAX_TOKENS_IN_BATCH};
@@ -11,7 +13,7 @@ use crate::{
         storage::{NFTConsecutiveStorageKey, IDS_IN_ITEM},
         Consecutive,
     },
-    sequential, Base,
+    sequential, Base, ContractOverrides,
 };
 
 #[contract]
@@ -191,7 +193,7 @@ fn consecutive_batch_mint_works() {
     let amount = MAX_TOKENS_IN_BATCH as u32;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
 
         let mut event_assert = EventAssertion::new(&e, address.clone());
         event_assert.assert_event_count(1);
@@ -211,7 +213,7 @@ fn consecutive_batch_mint_works() {
         assert_eq!(Consecutive::owner_of(&e, 31_999), owner);
 
         // new mint
-        let last_id = Consecutive::batch_mint(&e, &owner, amount);
+        let last_id = Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
         assert_eq!(last_id, 2 * amount - 1);
         assert_eq!(Base::balance(&e, &owner), 2 * amount);
         assert_eq!(Consecutive::owner_of(&e, 2 * amount - 1), owner);
@@ -228,7 +230,7 @@ fn consecutive_batch_mint_amount_0_fails() {
     let amount = 0;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
     });
 }
 
@@ -242,7 +244,20 @@ fn consecutive_batch_mint_amount_max_fails() {
     let amount = MAX_TOKENS_IN_BATCH as u32 + 1;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn consecutive_mint_single_token_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::mint(&e, &owner, 0);
     });
 }
 
@@ -265,7 +280,7 @@ fn consecutive_owner_of_on_nonexistent_token_fails() {
     let user = Address::generate(&e);
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &user, 5);
+        Consecutive::batch_mint::<Consecutive>(&e, &user, 5);
         // token 5 is out of range
         Consecutive::owner_of(&e, 5);
     });
@@ -280,7 +295,7 @@ fn consecutive_owner_of_panics_on_burnt_token_fails() {
     let user = Address::generate(&e);
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &user, 10);
+        Consecutive::batch_mint::<Consecutive>(&e, &user, 10);
         Consecutive::burn(&e, &user, 2);
         Consecutive::owner_of(&e, 2);
     });
@@ -297,7 +312,7 @@ fn consecutive_transfer_works() {
     let amount = 100;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
         assert_eq!(Base::balance(&e, &owner), amount);
 
         Consecutive::transfer(&e, &owner, &recipient, 50);
@@ -331,7 +346,7 @@ fn consecutive_transfer_edge_works() {
     let amount = 100;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
 
         let mut event_assert = EventAssertion::new(&e, address.clone());
         event_assert.assert_event_count(1);
@@ -363,7 +378,7 @@ fn consecutive_transfer_from_works() {
     let token_id = 50;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
         assert_eq!(Base::balance(&e, &owner), amount);
 
         Consecutive::approve(&e, &owner, &spender, token_id, 100);
@@ -392,7 +407,7 @@ fn consecutive_burn_works() {
     let token_id = 50;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
         assert_eq!(Base::balance(&e, &owner), amount);
 
         Consecutive::burn(&e, &owner, token_id);
@@ -437,7 +452,7 @@ fn consecutive_burn_from_works() {
     let token_id = 42;
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, amount);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, amount);
         Consecutive::approve(&e, &owner, &spender, token_id, 100);
         Consecutive::burn_from(&e, &spender, &owner, token_id);
 
@@ -469,7 +484,7 @@ fn consecutive_set_owner_for_previous_token_works() {
     let user3 = Address::generate(&e);
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &user1, 5); // 0,1,2,3,4
+        Consecutive::batch_mint::<Consecutive>(&e, &user1, 5); // 0,1,2,3,4
 
         // existing ID
         Consecutive::set_owner_for_previous_token(&e, &user2, 3);
@@ -547,8 +562,63 @@ fn consecutive_token_uri_panics_for_burned_id_fails() {
     let owner = Address::generate(&e);
 
     e.as_contract(&address, || {
-        Consecutive::batch_mint(&e, &owner, 1);
+        Consecutive::batch_mint::<Consecutive>(&e, &owner, 1);
         Consecutive::burn(&e, &owner, 0);
         Consecutive::token_uri(&e, 0);
     });
 }
+
+const HOOK_COUNT: Symbol = symbol_short!("HOOK_CNT");
+
+fn hook_count(e: &Env) -> (u32, u32) {
+    e.storage().instance().get(&HOOK_COUNT).unwrap_or((0, 0))
+}
+
+struct ConsecutiveHookCounter;
+
+impl ContractOverrides for ConsecutiveHookCounter {
+    fn before_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {
+        let (before, after) = hook_count(e);
+        e.storage().instance().set(&HOOK_COUNT, &(before + 1, after));
+    }
+
+    fn after_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {
+        let (before, after) = hook_count(e);
+        e.storage().instance().set(&HOOK_COUNT, &(before, after + 1));
+    }
+
+    fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, token_id: u32) {
+        Self::before_update(e, from, to, token_id);
+        Consecutive::update(e, from, to, token_id);
+        Self::after_update(e, from, to, token_id);
+    }
+}
+
+#[test]
+fn consecutive_update_hooks_run_for_batch_mint_and_burns() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint::<ConsecutiveHookCounter>(&e, &owner, 5);
+        // the hooks run once per minted token
+        assert_eq!(hook_count(&e), (5, 5));
+
+        Base::approve_for_all(&e, &owner, &spender, 1000);
+    });
+
+    e.as_contract(&address, || {
+        ConsecutiveHookCounter::burn(&e, &owner, 0);
+    });
+
+    e.as_contract(&address, || {
+        ConsecutiveHookCounter::burn_from(&e, &spender, &owner, 1);
+
+        assert_eq!(hook_count(&e), (7, 7));
+        assert_eq!(Base::balance(&e, &owner), 3);
+        assert_eq!(Consecutive::owner_of(&e, 2), owner);
+    });
+}
@@ -3,17 +3,21 @@ use stellar_constants::{
     OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
 
-use crate::{non_fungible::emit_mint, Base, ContractOverrides, NonFungibleTokenError};
+use crate::{Base, ContractOverrides, NonFungibleTokenError};
 
 pub struct Enumerable;
 
 impl ContractOverrides for Enumerable {
-    fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
-        Enumerable::transfer(e, from, to, token_id);
-    }
-
-    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
-        Enumerable::transfer_from(e, spender, from, to, token_id);
+    fn after_update(e: &Env, from: Option<&Address>, to: Option<&Address>, token_id: u32) {
+        match (from, to) {
+            (None, Some(to)) => Enumerable::add_to_enumerations(e, to, token_id),
+            (Some(from), None) => Enumerable::remove_from_enumerations(e, from, token_id),
+            (Some(from), Some(to)) if from != to => {
+                Enumerable::remove_from_owner_enumeration(e, from, token_id);
+                Enumerable::add_to_owner_enumeration(e, to, token_id);
+            }
+            _ => {}
+        }
     }
 }
 
@@ -117,8 +121,8 @@ impl Enumerable {
     ///
     /// # Notes
     ///
-    /// This is a wrapper around [`Base::sequential_mint()`], that
-    /// also handles the storage updates for:
+    /// This is a wrapper around [`ContractOverrides::sequential_mint()`],
+    /// whose `after_update` hook also handles the storage updates for:
     /// * total supply
     /// * global token list
     /// * owner token list
@@ -145,11 +149,7 @@ impl Enumerable {
     /// minting tokens and generating `token_id`s, they should ensure that
     /// the `token_id` is unique and not already in use.
     pub fn sequential_mint(e: &Env, to: &Address) -> u32 {
-        let token_id = Base::sequential_mint(e, to);
-
-        Enumerable::add_to_enumerations(e, to, token_id);
-
-        token_id
+        <Enumerable as ContractOverrides>::sequential_mint(e, to)
     }
 
     /// Creates a token with the provided `token_id` and assigns it to `to`.
@@ -198,10 +198,7 @@ impl Enumerable {
     /// strategy for generating `token_id`s varies by project and must be
     /// implemented accordingly.
     pub fn non_sequential_mint(e: &Env, to: &Address, token_id: u32) {
-        Base::update(e, None, Some(to), token_id);
-        emit_mint(e, to, token_id);
-
-        Enumerable::add_to_enumerations(e, to, token_id);
+        <Enumerable as ContractOverrides>::mint(e, to, token_id);
     }
 
     /// Destroys the token with `token_id` from `from`.
@@ -231,9 +228,7 @@ impl Enumerable {
     /// * owner_tokens enumeration
     /// * global_tokens enumeration
     pub fn burn(e: &Env, from: &Address, token_id: u32) {
-        Base::burn(e, from, token_id);
-
-        Enumerable::remove_from_enumerations(e, from, token_id);
+        <Enumerable as ContractOverrides>::burn(e, from, token_id);
     }
 
     /// Destroys the token with `token_id` from `from`, by using `spender`s
@@ -266,9 +261,7 @@ impl Enumerable {
     /// * owner_tokens enumeration
     /// * global_tokens enumeration
     pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
-        Base::burn_from(e, spender, from, token_id);
-
-        Enumerable::remove_from_enumerations(e, from, token_id);
+        <Enumerable as ContractOverrides>::burn_from(e, spender, from, token_id);
     }
 
     /// Transfers a non-fungible token (NFT), ensuring ownership checks.
@@ -296,12 +289,7 @@ impl Enumerable {
     /// handles the storage updates for:
     /// * owner_tokens enumeration
     pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
-        Base::transfer(e, from, to, token_id);
-
-        if from != to {
-            Enumerable::remove_from_owner_enumeration(e, from, token_id);
-            Enumerable::add_to_owner_enumeration(e, to, token_id);
-        }
+        <Enumerable as ContractOverrides>::transfer(e, from, to, token_id);
     }
 
     /// Transfers a non-fungible token (NFT), ensuring ownership and approval
@@ -331,12 +319,7 @@ impl Enumerable {
     /// handles the storage updates for:
     /// * owner_tokens enumeration
     pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
-        Base::transfer_from(e, spender, from, to, token_id);
-
-        if from != to {
-            Enumerable::remove_from_owner_enumeration(e, from, token_id);
-            Enumerable::add_to_owner_enumeration(e, to, token_id);
-        }
+        <Enumerable as ContractOverrides>::transfer_from(e, spender, from, to, token_id);
     }
 
     // ################## LOW-LEVEL HELPERS ##################
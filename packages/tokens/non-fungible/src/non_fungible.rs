@@ -302,6 +302,9 @@ pub enum NonFungibleTokenError {
     BaseUriMaxLenExceeded = 211,
     /// Indicates the royalty amount is higher than 10_000 (100%) basis points.
     InvalidRoyaltyAmount = 212,
+    /// Indicates a mint of a single `token_id` with a contract type that only
+    /// mints in batches, e.g. `Consecutive`.
+    MintNotSupported = 213,
 }
 
 // ################## EVENTS ##################
@@ -1,5 +1,11 @@
 use soroban_sdk::{Address, Env, String};
 
+use crate::{
+    burnable::emit_burn,
+    non_fungible::{emit_mint, emit_transfer},
+    sequential::increment_token_id,
+};
+
 /// Based on the extension, some default behavior of [`crate::NonFungibleToken`]
 /// might have to be overridden. This is a helper trait that allows us this
 /// override mechanism that favors the DevX.
@@ -74,7 +80,52 @@ use soroban_sdk::{Address, Env, String};
 ///     /* and so on */
 /// }
 /// ```
+///
+/// # Update Hooks
+///
+/// Every ownership change of the default implementations (`transfer`,
+/// `transfer_from`, `burn`, `burn_from`, `mint` and `sequential_mint`) goes
+/// through
+/// [`ContractOverrides::update`], which calls `before_update`, then
+/// [`Base::update`] and finally `after_update`. Extensions that have to keep
+/// additional accounting in sync with the ownership records (e.g.
+/// [`crate::enumerable::Enumerable`]) should override these hooks, so that the
+/// accounting applies to all the ownership changing functions at once.
 pub trait ContractOverrides {
+    /// Called before every ownership change made by
+    /// [`ContractOverrides::update`]. `from` is `None` for mints and `to` is
+    /// `None` for burns.
+    fn before_update(_e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {}
+
+    /// Called after every ownership change made by
+    /// [`ContractOverrides::update`]. `from` is `None` for mints and `to` is
+    /// `None` for burns.
+    fn after_update(_e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {}
+
+    /// Wraps [`Base::update`] with the `before_update` and `after_update`
+    /// hooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address of the current token owner, or `None` for mints.
+    /// * `to` - The address of the token recipient, or `None` for burns.
+    /// * `token_id` - The identifier of the token to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, token_id: u32) {
+        Self::before_update(e, from, to, token_id);
+        Base::update(e, from, to, token_id);
+        Self::after_update(e, from, to, token_id);
+    }
+
     fn balance(e: &Env, owner: &Address) -> u32 {
         Base::balance(e, owner)
     }
@@ -84,11 +135,42 @@ pub trait ContractOverrides {
     }
 
     fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
-        Base::transfer(e, from, to, token_id);
+        from.require_auth();
+        Self::update(e, Some(from), Some(to), token_id);
+        emit_transfer(e, from, to, token_id);
     }
 
     fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
-        Base::transfer_from(e, spender, from, to, token_id);
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from, token_id);
+        Self::update(e, Some(from), Some(to), token_id);
+        emit_transfer(e, from, to, token_id);
+    }
+
+    fn burn(e: &Env, from: &Address, token_id: u32) {
+        from.require_auth();
+        Self::update(e, Some(from), None, token_id);
+        emit_burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from, token_id);
+        Self::update(e, Some(from), None, token_id);
+        emit_burn(e, from, token_id);
+    }
+
+    fn mint(e: &Env, to: &Address, token_id: u32) {
+        Self::update(e, None, Some(to), token_id);
+        emit_mint(e, to, token_id);
+    }
+
+    /// Mints the token with the next available `token_id` to `to` with
+    /// [`ContractOverrides::mint`] and returns its `token_id`.
+    fn sequential_mint(e: &Env, to: &Address) -> u32 {
+        let token_id = increment_token_id(e, 1);
+        Self::mint(e, to, token_id);
+        token_id
     }
 
     fn approve(
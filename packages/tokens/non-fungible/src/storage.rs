@@ -8,8 +8,7 @@ use crate::{
         emit_approve, emit_approve_for_all, emit_mint, emit_transfer, NonFungibleTokenError,
         MAX_BASE_URI_LEN, MAX_NUM_DIGITS,
     },
-    sequential::increment_token_id,
-    Base,
+    Base, ContractOverrides,
 };
 
 /// Storage container for the token for which an approval is granted
@@ -633,7 +632,7 @@ impl Base {
     ///
     /// # Errors
     ///
-    /// * refer to [`increment_token_id`] errors.
+    /// * refer to [`crate::sequential::increment_token_id`] errors.
     /// * refer to [`update`] errors.
     ///
     /// # Events
@@ -657,17 +656,17 @@ impl Base {
     /// admin.require_auth();
     /// ```
     ///
-    /// **IMPORTANT**: This function utilizes [`increment_token_id()`] to
+    /// **IMPORTANT**: This function utilizes
+    /// [`crate::sequential::increment_token_id()`] to
     /// determine the next `token_id`, but it does NOT check if that
     /// `token_id` is already in use. If the developer has other means of
     /// minting tokens and generating `token_id`s, they should ensure that
     /// the `token_id` is unique and not already in use.
+    ///
+    /// The update hooks of other contract types do NOT run, use
+    /// [`ContractOverrides::sequential_mint`] of the contract type instead.
     pub fn sequential_mint(e: &Env, to: &Address) -> u32 {
-        let token_id = increment_token_id(e, 1);
-        Base::update(e, None, Some(to), token_id);
-        emit_mint(e, to, token_id);
-
-        token_id
+        <Base as ContractOverrides>::sequential_mint(e, to)
     }
 
     /// Creates a token with the provided `token_id` and assigns it to
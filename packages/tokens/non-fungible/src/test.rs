@@ -3,13 +3,13 @@
 extern crate std;
 
 use soroban_sdk::{
-    contract,
+    contract, symbol_short,
     testutils::{Address as _, Ledger as _},
-    Address, Env, String,
+    Address, Env, String, Symbol,
 };
 use stellar_event_assertion::EventAssertion;
 
-use crate::{Base, NFTStorageKey};
+use crate::{Base, ContractOverrides, NFTStorageKey};
 
 #[contract]
 struct MockContract;
@@ -503,3 +503,80 @@ fn mint_base_implementation_has_no_auth() {
         assert_eq!(Base::balance(&e, &account), 1);
     });
 }
+
+const HOOK_COUNT: Symbol = symbol_short!("HOOK_CNT");
+
+fn hook_count(e: &Env) -> (u32, u32) {
+    e.storage().instance().get(&HOOK_COUNT).unwrap_or((0, 0))
+}
+
+struct HookCounter;
+
+impl ContractOverrides for HookCounter {
+    fn before_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {
+        let (before, after) = hook_count(e);
+        assert_eq!(before, after);
+        e.storage().instance().set(&HOOK_COUNT, &(before + 1, after));
+    }
+
+    fn after_update(e: &Env, _from: Option<&Address>, _to: Option<&Address>, _token_id: u32) {
+        let (before, after) = hook_count(e);
+        e.storage().instance().set(&HOOK_COUNT, &(before, after + 1));
+    }
+}
+
+#[test]
+fn update_hooks_run_for_all_ownership_changes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        HookCounter::mint(&e, &owner, 1);
+        HookCounter::mint(&e, &owner, 2);
+        HookCounter::mint(&e, &owner, 3);
+        HookCounter::approve_for_all(&e, &owner, &spender, 1000);
+        // approvals do not change ownership
+        assert_eq!(hook_count(&e), (3, 3));
+    });
+
+    e.as_contract(&address, || {
+        HookCounter::transfer(&e, &owner, &recipient, 1);
+        HookCounter::transfer_from(&e, &spender, &owner, &recipient, 2);
+    });
+
+    e.as_contract(&address, || {
+        HookCounter::burn_from(&e, &spender, &owner, 3);
+    });
+
+    e.as_contract(&address, || {
+        HookCounter::burn(&e, &recipient, 1);
+
+        assert_eq!(hook_count(&e), (7, 7));
+        assert_eq!(Base::balance(&e, &owner), 0);
+        assert_eq!(Base::balance(&e, &recipient), 1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_non_fungible_burn(&recipient, 1);
+    });
+}
+
+#[test]
+fn sequential_mint_runs_update_hooks() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = HookCounter::sequential_mint(&e, &owner);
+
+        assert_eq!(hook_count(&e), (1, 1));
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+    });
+}
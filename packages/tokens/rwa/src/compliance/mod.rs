@@ -0,0 +1,237 @@
+//! # Compliance
+//!
+//! The `compliance` module implements the compliance contract of a regulated
+//! token: a standalone contract, bound to a single token, that decides whether
+//! a transfer or a mint is permitted, and keeps track of the state required by
+//! its rules.
+//!
+//! ## Implementation Notes
+//!
+//! - **Pluggable rules**: The rules are implemented as compliance modules
+//!   ([`ComplianceModule`]) and combined with [`Stack`], in the same way as the
+//!   contract types of `stellar_fungible`. The following modules are provided:
+//!   - [`CountryRestrictions`]: rejects receivers from restricted countries.
+//!   - [`MaxHolders`]: limits the number of accounts holding tokens.
+//!   - [`TransferLimit`]: limits the amount an account can send within a window
+//!     of time.
+//! - **Hooks**: The token notifies the compliance contract after every
+//!   transfer, mint and burn, so that stateful modules stay in sync. Only the
+//!   bound token is allowed to call the hooks.
+//! - **Balances**: Soroban does not allow a contract to be re-entered, so the
+//!   compliance contract cannot query the balances of the token while handling
+//!   a transfer. Modules that depend on the balances (e.g. [`MaxHolders`]) keep
+//!   track of them from the hooks, so the compliance contract must be bound to
+//!   the token before any token is minted.
+//! - **Identities**: Modules that depend on the identity of the holders (e.g.
+//!   [`CountryRestrictions`]) query the identity registry set with
+//!   [`set_identity_registry`].
+pub mod modules;
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+pub use modules::{
+    ComplianceModule, CountryRestrictions, MaxHolders, Stack, TransferLimit, TransferLimitData,
+};
+use soroban_sdk::{contractclient, contracterror, Address, Env, Symbol};
+pub use storage::{
+    can_create, can_transfer, created, destroyed, identity_registry, set_identity_registry,
+    set_token, token, transferred, ComplianceStorageKey,
+};
+
+/// Compliance Trait
+///
+/// The `Compliance` trait defines the interface of a compliance contract.
+/// Regulated tokens interact with it through the generated
+/// [`ComplianceClient`].
+///
+/// The functions of this trait are meant to be implemented with the functions
+/// of this module, e.g. `can_transfer::<Modules>(e, &from, &to, amount)`,
+/// where `Modules` is the [`ComplianceModule`] (or [`Stack`] of modules)
+/// enforced by the contract. The functions configuring the modules are left
+/// to the implementor, so that they can be guarded by the access control of
+/// their choice.
+#[contractclient(name = "ComplianceClient")]
+pub trait Compliance {
+    /// Returns the address of the token bound to the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+    fn token(e: &Env) -> Address;
+
+    /// Returns whether `amount` of tokens can be transferred from `from` to
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens to be transferred.
+    fn can_transfer(e: &Env, from: Address, to: Address, amount: i128) -> bool;
+
+    /// Returns whether `amount` of tokens can be minted to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens to be minted.
+    fn can_create(e: &Env, to: Address, amount: i128) -> bool;
+
+    /// Notifies the compliance contract of a transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the bound token is required.
+    fn transferred(e: &Env, from: Address, to: Address, amount: i128);
+
+    /// Notifies the compliance contract of a mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The amount of tokens minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the bound token is required.
+    fn created(e: &Env, to: Address, amount: i128);
+
+    /// Notifies the compliance contract of a burn.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `amount` - The amount of tokens burned.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the bound token is required.
+    fn destroyed(e: &Env, from: Address, amount: i128);
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ComplianceError {
+    /// Indicates that no token is bound to the compliance contract.
+    TokenNotBound = 1810,
+    /// Indicates that no identity registry is set.
+    IdentityRegistryNotSet = 1811,
+    /// Indicates a transfer limit that is negative or has an empty window.
+    InvalidTransferLimit = 1812,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a token is bound to the compliance contract.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token` - The address of the token.
+///
+/// # Events
+///
+/// * topics - `["token_bound", token: Address]`
+/// * data - `[]`
+pub fn emit_token_bound(e: &Env, token: &Address) {
+    let topics = (Symbol::new(e, "token_bound"), token);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when the identity registry is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `identity_registry` - The address of the identity registry.
+///
+/// # Events
+///
+/// * topics - `["identity_registry_set", identity_registry: Address]`
+/// * data - `[]`
+pub fn emit_identity_registry_set(e: &Env, identity_registry: &Address) {
+    let topics = (Symbol::new(e, "identity_registry_set"), identity_registry);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when a country is restricted or unrestricted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `country` - The ISO 3166-1 numeric code of the country.
+/// * `restricted` - Whether the country is restricted.
+///
+/// # Events
+///
+/// * topics - `["country_restricted", country: u32]`
+/// * data - `[restricted: bool]`
+pub fn emit_country_restricted(e: &Env, country: u32, restricted: bool) {
+    let topics = (Symbol::new(e, "country_restricted"), country);
+    e.events().publish(topics, restricted)
+}
+
+/// Emits an event when the maximum number of holders is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `max_holders` - The maximum number of holders.
+///
+/// # Events
+///
+/// * topics - `["max_holders_set"]`
+/// * data - `[max_holders: u32]`
+pub fn emit_max_holders_set(e: &Env, max_holders: u32) {
+    let topics = (Symbol::new(e, "max_holders_set"),);
+    e.events().publish(topics, max_holders)
+}
+
+/// Emits an event when the transfer limit is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `limit` - The amount an account can send within a window.
+/// * `window` - The duration of a window, in seconds.
+///
+/// # Events
+///
+/// * topics - `["transfer_limit_set"]`
+/// * data - `[limit: i128, window: u64]`
+pub fn emit_transfer_limit_set(e: &Env, limit: i128, window: u64) {
+    let topics = (Symbol::new(e, "transfer_limit_set"),);
+    e.events().publish(topics, (limit, window))
+}
@@ -0,0 +1,387 @@
+use core::marker::PhantomData;
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::{
+    compliance::{
+        emit_country_restricted, emit_max_holders_set, emit_transfer_limit_set, identity_registry,
+        ComplianceError, ComplianceStorageKey,
+    },
+    identity_registry::IdentityRegistryClient,
+};
+
+/// A rule of a compliance contract.
+///
+/// The `can_*` functions decide whether an operation is permitted, and are
+/// called by the token before the operation. The hooks are called by the
+/// token after the operation, so that the module can keep track of the state
+/// it depends on. All functions have a default implementation that permits
+/// everything and does nothing, so a module only implements the ones it
+/// needs.
+pub trait ComplianceModule {
+    /// Returns whether `amount` of tokens can be transferred from `from` to
+    /// `to`.
+    fn can_transfer(_e: &Env, _from: &Address, _to: &Address, _amount: i128) -> bool {
+        true
+    }
+
+    /// Returns whether `amount` of tokens can be minted to `to`.
+    fn can_create(_e: &Env, _to: &Address, _amount: i128) -> bool {
+        true
+    }
+
+    /// Called after `amount` of tokens are transferred from `from` to `to`.
+    fn transferred(_e: &Env, _from: &Address, _to: &Address, _amount: i128) {}
+
+    /// Called after `amount` of tokens are minted to `to`.
+    fn created(_e: &Env, _to: &Address, _amount: i128) {}
+
+    /// Called after `amount` of tokens of `from` are burned.
+    fn destroyed(_e: &Env, _from: &Address, _amount: i128) {}
+}
+
+/// Combines two compliance modules. An operation is permitted only if it is
+/// permitted by both `Top` and `Rest`, and the hooks of both are called.
+/// Longer stacks are built by nesting, for example
+/// `Stack<CountryRestrictions, Stack<MaxHolders, TransferLimit>>`.
+pub struct Stack<Top, Rest>(PhantomData<(Top, Rest)>);
+
+impl<Top: ComplianceModule, Rest: ComplianceModule> ComplianceModule for Stack<Top, Rest> {
+    fn can_transfer(e: &Env, from: &Address, to: &Address, amount: i128) -> bool {
+        Top::can_transfer(e, from, to, amount) && Rest::can_transfer(e, from, to, amount)
+    }
+
+    fn can_create(e: &Env, to: &Address, amount: i128) -> bool {
+        Top::can_create(e, to, amount) && Rest::can_create(e, to, amount)
+    }
+
+    fn transferred(e: &Env, from: &Address, to: &Address, amount: i128) {
+        Top::transferred(e, from, to, amount);
+        Rest::transferred(e, from, to, amount);
+    }
+
+    fn created(e: &Env, to: &Address, amount: i128) {
+        Top::created(e, to, amount);
+        Rest::created(e, to, amount);
+    }
+
+    fn destroyed(e: &Env, from: &Address, amount: i128) {
+        Top::destroyed(e, from, amount);
+        Rest::destroyed(e, from, amount);
+    }
+}
+
+/// Rejects transfers and mints to accounts whose holder is from a restricted
+/// country, or that are not registered in the identity registry.
+pub struct CountryRestrictions;
+
+impl ComplianceModule for CountryRestrictions {
+    fn can_transfer(e: &Env, _from: &Address, to: &Address, _amount: i128) -> bool {
+        CountryRestrictions::is_allowed(e, to)
+    }
+
+    fn can_create(e: &Env, to: &Address, _amount: i128) -> bool {
+        CountryRestrictions::is_allowed(e, to)
+    }
+}
+
+impl CountryRestrictions {
+    /// Returns whether `country` is restricted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `country` - The ISO 3166-1 numeric code of the country.
+    pub fn is_country_restricted(e: &Env, country: u32) -> bool {
+        e.storage().instance().has(&ComplianceStorageKey::RestrictedCountry(country))
+    }
+
+    /// Returns whether `account` is registered in the identity registry and
+    /// its holder is not from a restricted country.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::IdentityRegistryNotSet`] - When no identity
+    ///   registry is set.
+    pub fn is_allowed(e: &Env, account: &Address) -> bool {
+        IdentityRegistryClient::new(e, &identity_registry(e)).identity(account).is_some_and(
+            |identity| !CountryRestrictions::is_country_restricted(e, identity.country),
+        )
+    }
+
+    /// Sets whether `country` is restricted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `country` - The ISO 3166-1 numeric code of the country.
+    /// * `restricted` - Whether the country is restricted.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["country_restricted", country: u32]`
+    /// * data - `[restricted: bool]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_country_restricted(e: &Env, country: u32, restricted: bool) {
+        let key = ComplianceStorageKey::RestrictedCountry(country);
+
+        // only emit an event if the restriction changes
+        if e.storage().instance().has(&key) != restricted {
+            if restricted {
+                e.storage().instance().set(&key, &());
+            } else {
+                e.storage().instance().remove(&key);
+            }
+
+            emit_country_restricted(e, country, restricted);
+        }
+    }
+}
+
+/// Limits the number of accounts holding tokens.
+///
+/// The balances are tracked from the hooks, see the module documentation.
+pub struct MaxHolders;
+
+impl ComplianceModule for MaxHolders {
+    fn can_transfer(e: &Env, from: &Address, to: &Address, amount: i128) -> bool {
+        if from == to || amount <= 0 {
+            return true;
+        }
+        let new_holder = MaxHolders::balance(e, to) == 0;
+        let leaving_holder = MaxHolders::balance(e, from) == amount;
+        MaxHolders::is_within_limit(e, new_holder, leaving_holder)
+    }
+
+    fn can_create(e: &Env, to: &Address, amount: i128) -> bool {
+        let new_holder = amount > 0 && MaxHolders::balance(e, to) == 0;
+        MaxHolders::is_within_limit(e, new_holder, false)
+    }
+
+    fn transferred(e: &Env, from: &Address, to: &Address, amount: i128) {
+        MaxHolders::update_balance(e, from, -amount);
+        MaxHolders::update_balance(e, to, amount);
+    }
+
+    fn created(e: &Env, to: &Address, amount: i128) {
+        MaxHolders::update_balance(e, to, amount);
+    }
+
+    fn destroyed(e: &Env, from: &Address, amount: i128) {
+        MaxHolders::update_balance(e, from, -amount);
+    }
+}
+
+impl MaxHolders {
+    /// Returns the maximum number of holders, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn max_holders(e: &Env) -> Option<u32> {
+        e.storage().instance().get(&ComplianceStorageKey::MaxHolders)
+    }
+
+    /// Returns the number of accounts holding tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn holder_count(e: &Env) -> u32 {
+        e.storage().instance().get(&ComplianceStorageKey::HolderCount).unwrap_or(0)
+    }
+
+    /// Returns the balance of `account`, as notified by the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to query the balance of.
+    pub fn balance(e: &Env, account: &Address) -> i128 {
+        let key = ComplianceStorageKey::HolderBalance(account.clone());
+        if let Some(balance) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_EXTEND_AMOUNT,
+            );
+            balance
+        } else {
+            0
+        }
+    }
+
+    /// Sets the maximum number of holders. Lowering it below the current
+    /// number of holders does not affect the existing holders, but no new
+    /// holder is accepted until the number of holders is below the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `max_holders` - The maximum number of holders.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_holders_set"]`
+    /// * data - `[max_holders: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_max_holders(e: &Env, max_holders: u32) {
+        e.storage().instance().set(&ComplianceStorageKey::MaxHolders, &max_holders);
+        emit_max_holders_set(e, max_holders);
+    }
+
+    // ################## HELPERS ##################
+
+    fn is_within_limit(e: &Env, new_holder: bool, leaving_holder: bool) -> bool {
+        let Some(max_holders) = MaxHolders::max_holders(e) else {
+            return true;
+        };
+        !new_holder || leaving_holder || MaxHolders::holder_count(e) < max_holders
+    }
+
+    fn update_balance(e: &Env, account: &Address, delta: i128) {
+        let previous = MaxHolders::balance(e, account);
+        let balance = (previous + delta).max(0);
+        let key = ComplianceStorageKey::HolderBalance(account.clone());
+        if balance == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &balance);
+        }
+
+        let count = MaxHolders::holder_count(e);
+        if previous == 0 && balance > 0 {
+            e.storage().instance().set(&ComplianceStorageKey::HolderCount, &(count + 1));
+        } else if previous > 0 && balance == 0 {
+            e.storage().instance().set(&ComplianceStorageKey::HolderCount, &(count - 1));
+        }
+    }
+}
+
+/// Storage container for the transfer limit.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TransferLimitData {
+    /// The amount an account can send within a window.
+    pub limit: i128,
+    /// The duration of a window, in seconds.
+    pub window: u64,
+}
+
+/// Limits the amount of tokens an account can send within a window of time.
+/// The windows are consecutive periods of `window` seconds starting at the
+/// ledger timestamp `0`. Mints and burns are not limited.
+pub struct TransferLimit;
+
+impl ComplianceModule for TransferLimit {
+    fn can_transfer(e: &Env, from: &Address, _to: &Address, amount: i128) -> bool {
+        let Some(transfer_limit) = TransferLimit::transfer_limit(e) else {
+            return true;
+        };
+        TransferLimit::sent(e, from)
+            .checked_add(amount)
+            .is_some_and(|total| total <= transfer_limit.limit)
+    }
+
+    fn transferred(e: &Env, from: &Address, _to: &Address, amount: i128) {
+        let Some(transfer_limit) = TransferLimit::transfer_limit(e) else {
+            return;
+        };
+        let window_index = e.ledger().timestamp() / transfer_limit.window;
+        let sent = TransferLimit::sent(e, from) + amount;
+        e.storage()
+            .persistent()
+            .set(&ComplianceStorageKey::TransferUsage(from.clone()), &(window_index, sent));
+    }
+}
+
+impl TransferLimit {
+    /// Returns the transfer limit, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn transfer_limit(e: &Env) -> Option<TransferLimitData> {
+        e.storage().instance().get(&ComplianceStorageKey::TransferLimit)
+    }
+
+    /// Returns the amount of tokens sent by `account` in the current window.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to query the amount of.
+    pub fn sent(e: &Env, account: &Address) -> i128 {
+        let Some(transfer_limit) = TransferLimit::transfer_limit(e) else {
+            return 0;
+        };
+        let key = ComplianceStorageKey::TransferUsage(account.clone());
+        let Some((window_index, sent)) = e.storage().persistent().get::<_, (u64, i128)>(&key)
+        else {
+            return 0;
+        };
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+        if window_index == e.ledger().timestamp() / transfer_limit.window {
+            sent
+        } else {
+            0
+        }
+    }
+
+    /// Sets the amount of tokens an account can send within a window of
+    /// `window` seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `limit` - The amount an account can send within a window.
+    /// * `window` - The duration of a window, in seconds.
+    ///
+    /// # Errors
+    ///
+    /// * [`ComplianceError::InvalidTransferLimit`] - When `limit < 0` or
+    ///   `window == 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_limit_set"]`
+    /// * data - `[limit: i128, window: u64]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_transfer_limit(e: &Env, limit: i128, window: u64) {
+        if limit < 0 || window == 0 {
+            panic_with_error!(e, ComplianceError::InvalidTransferLimit);
+        }
+        e.storage()
+            .instance()
+            .set(&ComplianceStorageKey::TransferLimit, &TransferLimitData { limit, window });
+        emit_transfer_limit_set(e, limit, window);
+    }
+}
@@ -0,0 +1,202 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::compliance::{
+    emit_identity_registry_set, emit_token_bound, ComplianceError, ComplianceModule,
+};
+
+/// Storage keys for the data associated with the compliance contract and its
+/// modules
+#[contracttype]
+pub enum ComplianceStorageKey {
+    /// Stores the address of the bound token
+    Token,
+    /// Stores the address of the identity registry
+    IdentityRegistry,
+    /// Stores the restriction of a country
+    RestrictedCountry(u32),
+    /// Stores the maximum number of holders
+    MaxHolders,
+    /// Stores the number of holders
+    HolderCount,
+    /// Stores the balance of a holder, as notified by the token
+    HolderBalance(Address),
+    /// Stores the transfer limit
+    TransferLimit,
+    /// Stores the amount sent by an account in its last window
+    TransferUsage(Address),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the address of the token bound to the compliance contract.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+pub fn token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&ComplianceStorageKey::Token)
+        .unwrap_or_else(|| panic_with_error!(e, ComplianceError::TokenNotBound))
+}
+
+/// Returns the address of the identity registry.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`ComplianceError::IdentityRegistryNotSet`] - When no identity registry is
+///   set.
+pub fn identity_registry(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&ComplianceStorageKey::IdentityRegistry)
+        .unwrap_or_else(|| panic_with_error!(e, ComplianceError::IdentityRegistryNotSet))
+}
+
+/// Returns whether `amount` of tokens can be transferred from `from` to `to`
+/// according to the modules `M`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens to be transferred.
+pub fn can_transfer<M: ComplianceModule>(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> bool {
+    M::can_transfer(e, from, to, amount)
+}
+
+/// Returns whether `amount` of tokens can be minted to `to` according to the
+/// modules `M`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens to be minted.
+pub fn can_create<M: ComplianceModule>(e: &Env, to: &Address, amount: i128) -> bool {
+    M::can_create(e, to, amount)
+}
+
+// ################## CHANGE STATE ##################
+
+/// Notifies the modules `M` of a transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens transferred.
+///
+/// # Errors
+///
+/// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+///
+/// # Notes
+///
+/// Authorization for the bound token is required.
+pub fn transferred<M: ComplianceModule>(e: &Env, from: &Address, to: &Address, amount: i128) {
+    token(e).require_auth();
+    M::transferred(e, from, to, amount);
+}
+
+/// Notifies the modules `M` of a mint.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the tokens.
+/// * `amount` - The amount of tokens minted.
+///
+/// # Errors
+///
+/// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+///
+/// # Notes
+///
+/// Authorization for the bound token is required.
+pub fn created<M: ComplianceModule>(e: &Env, to: &Address, amount: i128) {
+    token(e).require_auth();
+    M::created(e, to, amount);
+}
+
+/// Notifies the modules `M` of a burn.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `amount` - The amount of tokens burned.
+///
+/// # Errors
+///
+/// * [`ComplianceError::TokenNotBound`] - When no token is bound.
+///
+/// # Notes
+///
+/// Authorization for the bound token is required.
+pub fn destroyed<M: ComplianceModule>(e: &Env, from: &Address, amount: i128) {
+    token(e).require_auth();
+    M::destroyed(e, from, amount);
+}
+
+/// Binds `token` to the compliance contract, replacing the bound token, if
+/// any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+///
+/// # Events
+///
+/// * topics - `["token_bound", token: Address]`
+/// * data - `[]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used:
+/// - During contract initialization/construction
+/// - In admin functions that implement their own authorization logic
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage().instance().set(&ComplianceStorageKey::Token, token);
+    emit_token_bound(e, token);
+}
+
+/// Sets the identity registry queried by the modules.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `identity_registry` - The address of the identity registry.
+///
+/// # Events
+///
+/// * topics - `["identity_registry_set", identity_registry: Address]`
+/// * data - `[]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used:
+/// - During contract initialization/construction
+/// - In admin functions that implement their own authorization logic
+pub fn set_identity_registry(e: &Env, identity_registry: &Address) {
+    e.storage().instance().set(&ComplianceStorageKey::IdentityRegistry, identity_registry);
+    emit_identity_registry_set(e, identity_registry);
+}
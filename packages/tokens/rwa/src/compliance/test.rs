@@ -0,0 +1,193 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    compliance::{
+        can_create, can_transfer, created, destroyed, set_identity_registry, set_token,
+        transferred, CountryRestrictions, MaxHolders, Stack, TransferLimit,
+    },
+    identity_registry::{self, IdentityData},
+};
+
+#[contract]
+struct MockContract;
+
+#[contract]
+struct MockIdentityRegistry;
+
+#[contractimpl]
+impl MockIdentityRegistry {
+    pub fn register(e: &Env, account: Address, country: u32) {
+        identity_registry::register_identity(e, &account, country);
+    }
+
+    pub fn identity(e: &Env, account: Address) -> Option<IdentityData> {
+        identity_registry::identity(e, &account)
+    }
+}
+
+fn setup(e: &Env) -> Address {
+    let address = e.register(MockContract, ());
+    let token = Address::generate(e);
+    e.as_contract(&address, || {
+        set_token(e, &token);
+    });
+    address
+}
+
+#[test]
+fn country_restrictions_reject_restricted_receivers() {
+    let e = Env::default();
+    let address = setup(&e);
+    let registry = e.register(MockIdentityRegistry, ());
+    let registry_client = MockIdentityRegistryClient::new(&e, &registry);
+    let from = Address::generate(&e);
+    let french = Address::generate(&e);
+    let german = Address::generate(&e);
+    let unregistered = Address::generate(&e);
+    registry_client.register(&french, &250);
+    registry_client.register(&german, &276);
+
+    e.as_contract(&address, || {
+        set_identity_registry(&e, &registry);
+        CountryRestrictions::set_country_restricted(&e, 276, true);
+
+        assert!(can_transfer::<CountryRestrictions>(&e, &from, &french, 10));
+        assert!(!can_transfer::<CountryRestrictions>(&e, &from, &german, 10));
+        assert!(!can_transfer::<CountryRestrictions>(&e, &from, &unregistered, 10));
+        assert!(!can_create::<CountryRestrictions>(&e, &german, 10));
+
+        CountryRestrictions::set_country_restricted(&e, 276, false);
+        assert!(can_create::<CountryRestrictions>(&e, &german, 10));
+    });
+}
+
+#[test]
+fn max_holders_tracks_holders() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxHolders::set_max_holders(&e, 2);
+        assert!(can_create::<MaxHolders>(&e, &alice, 100));
+        created::<MaxHolders>(&e, &alice, 100);
+    });
+
+    e.as_contract(&address, || {
+        assert!(can_transfer::<MaxHolders>(&e, &alice, &bob, 40));
+        transferred::<MaxHolders>(&e, &alice, &bob, 40);
+        assert_eq!(MaxHolders::holder_count(&e), 2);
+
+        // a third holder is rejected
+        assert!(!can_create::<MaxHolders>(&e, &carol, 10));
+        assert!(!can_transfer::<MaxHolders>(&e, &alice, &carol, 10));
+        // existing holders can still receive tokens
+        assert!(can_transfer::<MaxHolders>(&e, &alice, &bob, 10));
+        // a holder leaving makes room for a new one
+        assert!(can_transfer::<MaxHolders>(&e, &bob, &carol, 40));
+    });
+
+    e.as_contract(&address, || {
+        destroyed::<MaxHolders>(&e, &bob, 40);
+        assert_eq!(MaxHolders::holder_count(&e), 1);
+        assert_eq!(MaxHolders::balance(&e, &bob), 0);
+        assert!(can_create::<MaxHolders>(&e, &carol, 10));
+    });
+}
+
+#[test]
+fn transfer_limit_resets_every_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = setup(&e);
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        TransferLimit::set_transfer_limit(&e, 100, 86_400);
+
+        assert!(can_transfer::<TransferLimit>(&e, &from, &to, 70));
+        transferred::<TransferLimit>(&e, &from, &to, 70);
+        assert_eq!(TransferLimit::sent(&e, &from), 70);
+        assert!(can_transfer::<TransferLimit>(&e, &from, &to, 30));
+        assert!(!can_transfer::<TransferLimit>(&e, &from, &to, 31));
+        // mints are not limited
+        assert!(can_create::<TransferLimit>(&e, &from, 1_000));
+    });
+
+    e.ledger().set_timestamp(86_400);
+
+    e.as_contract(&address, || {
+        assert_eq!(TransferLimit::sent(&e, &from), 0);
+        assert!(can_transfer::<TransferLimit>(&e, &from, &to, 100));
+    });
+}
+
+#[test]
+fn stacked_modules_must_all_permit() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = setup(&e);
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        MaxHolders::set_max_holders(&e, 2);
+        TransferLimit::set_transfer_limit(&e, 50, 86_400);
+        created::<Stack<MaxHolders, TransferLimit>>(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        assert!(can_transfer::<Stack<MaxHolders, TransferLimit>>(&e, &from, &to, 50));
+        assert!(!can_transfer::<Stack<MaxHolders, TransferLimit>>(&e, &from, &to, 51));
+
+        transferred::<Stack<MaxHolders, TransferLimit>>(&e, &from, &to, 50);
+        assert_eq!(MaxHolders::holder_count(&e), 2);
+        assert_eq!(TransferLimit::sent(&e, &from), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1812)")]
+fn set_transfer_limit_with_empty_window_panics() {
+    let e = Env::default();
+    let address = setup(&e);
+
+    e.as_contract(&address, || {
+        TransferLimit::set_transfer_limit(&e, 100, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1810)")]
+fn hooks_without_bound_token_panic() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        created::<MaxHolders>(&e, &to, 100);
+    });
+}
+
+#[test]
+#[should_panic]
+fn hooks_require_token_authorization() {
+    let e = Env::default();
+    let address = setup(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        created::<MaxHolders>(&e, &to, 100);
+    });
+}
@@ -0,0 +1,313 @@
+//! # Identity Registry
+//!
+//! The `identity_registry` module implements a registry of verified
+//! identities, to be deployed as a standalone contract and shared by any
+//! number of regulated tokens and compliance contracts.
+//!
+//! ## Implementation Notes
+//!
+//! - **Identities**: An address is registered with the country of its holder,
+//!   as an ISO 3166-1 numeric code.
+//! - **Claims**: Every identity carries a set of claims, identified by a topic
+//!   (e.g. KYC, AML or accredited investor) and valid until a ledger timestamp.
+//!   The registry acts as the trusted issuer of the claims, i.e. the claims are
+//!   added by the accounts authorized to manage the registry after the
+//!   off-chain verification of the holder.
+//! - **Verification**: An address is verified if it is registered and holds a
+//!   valid claim for every required claim topic.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractclient, contracterror, Address, Env, Symbol, Vec};
+pub use storage::{
+    add_claim, has_claim, identity, is_verified, register_identity, remove_claim, remove_identity,
+    required_claim_topics, set_required_claim_topics, update_country, IdentityData,
+    IdentityRegistryStorageKey,
+};
+
+/// Identity Registry Trait
+///
+/// The `IdentityRegistry` trait defines the interface of an identity registry
+/// contract. Regulated tokens and compliance contracts interact with the
+/// registry through the generated [`IdentityRegistryClient`].
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `IdentityRegistry` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+#[contractclient(name = "IdentityRegistryClient")]
+pub trait IdentityRegistry {
+    /// Returns the identity of `account`, if registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to query the identity of.
+    fn identity(e: &Env, account: Address) -> Option<IdentityData>;
+
+    /// Returns whether `account` is registered and holds a valid claim for
+    /// every required claim topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check.
+    fn is_verified(e: &Env, account: Address) -> bool;
+
+    /// Returns whether `account` holds a valid claim for `topic`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check.
+    /// * `topic` - The topic of the claim.
+    fn has_claim(e: &Env, account: Address, topic: u32) -> bool;
+
+    /// Returns the claim topics required to be verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn required_claim_topics(e: &Env) -> Vec<u32>;
+
+    /// Registers `account` with the country of its holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to register.
+    /// * `country` - The ISO 3166-1 numeric code of the country.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`IdentityRegistryError::IdentityAlreadyRegistered`] - When `account`
+    ///   is already registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["identity_registered", account: Address]`
+    /// * data - `[country: u32]`
+    fn register_identity(e: &Env, account: Address, country: u32, operator: Address);
+
+    /// Updates the country of the holder of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The registered address.
+    /// * `country` - The ISO 3166-1 numeric code of the country.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+    ///   registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["country_updated", account: Address]`
+    /// * data - `[country: u32]`
+    fn update_country(e: &Env, account: Address, country: u32, operator: Address);
+
+    /// Removes the identity of `account`, along with its claims.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The registered address.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+    ///   registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["identity_removed", account: Address]`
+    /// * data - `[]`
+    fn remove_identity(e: &Env, account: Address, operator: Address);
+
+    /// Adds a claim for `topic` to the identity of `account`, or replaces the
+    /// existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The registered address.
+    /// * `topic` - The topic of the claim.
+    /// * `valid_until` - The ledger timestamp until which the claim is valid.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+    ///   registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_added", account: Address, topic: u32]`
+    /// * data - `[valid_until: u64]`
+    fn add_claim(e: &Env, account: Address, topic: u32, valid_until: u64, operator: Address);
+
+    /// Removes the claim for `topic` from the identity of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The registered address.
+    /// * `topic` - The topic of the claim.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+    ///   registered.
+    /// * [`IdentityRegistryError::ClaimNotFound`] - When the identity has no
+    ///   claim for `topic`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_removed", account: Address, topic: u32]`
+    /// * data - `[]`
+    fn remove_claim(e: &Env, account: Address, topic: u32, operator: Address);
+
+    /// Sets the claim topics required to be verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `topics` - The required claim topics.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_topics_set"]`
+    /// * data - `[topics: Vec<u32>]`
+    fn set_required_claim_topics(e: &Env, topics: Vec<u32>, operator: Address);
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdentityRegistryError {
+    /// Indicates an address that is not registered.
+    IdentityNotFound = 1800,
+    /// Indicates an attempt to register an address twice.
+    IdentityAlreadyRegistered = 1801,
+    /// Indicates an identity without a claim for the given topic.
+    ClaimNotFound = 1802,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when an identity is registered.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The registered address.
+/// * `country` - The ISO 3166-1 numeric code of the country.
+///
+/// # Events
+///
+/// * topics - `["identity_registered", account: Address]`
+/// * data - `[country: u32]`
+pub fn emit_identity_registered(e: &Env, account: &Address, country: u32) {
+    let topics = (Symbol::new(e, "identity_registered"), account);
+    e.events().publish(topics, country)
+}
+
+/// Emits an event when the country of an identity is updated.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The registered address.
+/// * `country` - The ISO 3166-1 numeric code of the new country.
+///
+/// # Events
+///
+/// * topics - `["country_updated", account: Address]`
+/// * data - `[country: u32]`
+pub fn emit_country_updated(e: &Env, account: &Address, country: u32) {
+    let topics = (Symbol::new(e, "country_updated"), account);
+    e.events().publish(topics, country)
+}
+
+/// Emits an event when an identity is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose identity is removed.
+///
+/// # Events
+///
+/// * topics - `["identity_removed", account: Address]`
+/// * data - `[]`
+pub fn emit_identity_removed(e: &Env, account: &Address) {
+    let topics = (Symbol::new(e, "identity_removed"), account);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when a claim is added to an identity.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The registered address.
+/// * `topic` - The topic of the claim.
+/// * `valid_until` - The ledger timestamp until which the claim is valid.
+///
+/// # Events
+///
+/// * topics - `["claim_added", account: Address, topic: u32]`
+/// * data - `[valid_until: u64]`
+pub fn emit_claim_added(e: &Env, account: &Address, topic: u32, valid_until: u64) {
+    let topics = (Symbol::new(e, "claim_added"), account, topic);
+    e.events().publish(topics, valid_until)
+}
+
+/// Emits an event when a claim is removed from an identity.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The registered address.
+/// * `topic` - The topic of the claim.
+///
+/// # Events
+///
+/// * topics - `["claim_removed", account: Address, topic: u32]`
+/// * data - `[]`
+pub fn emit_claim_removed(e: &Env, account: &Address, topic: u32) {
+    let topics = (Symbol::new(e, "claim_removed"), account, topic);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when the required claim topics are set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `topics` - The required claim topics.
+///
+/// # Events
+///
+/// * topics - `["claim_topics_set"]`
+/// * data - `[topics: Vec<u32>]`
+pub fn emit_claim_topics_set(e: &Env, topics: &Vec<u32>) {
+    e.events().publish((Symbol::new(e, "claim_topics_set"),), topics.clone())
+}
@@ -0,0 +1,286 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, Vec};
+use stellar_constants::{PERSISTENT_EXTEND_AMOUNT, PERSISTENT_TTL_THRESHOLD};
+
+use crate::identity_registry::{
+    emit_claim_added, emit_claim_removed, emit_claim_topics_set, emit_country_updated,
+    emit_identity_registered, emit_identity_removed, IdentityRegistryError,
+};
+
+/// Storage container for the identity of an address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityData {
+    /// The ISO 3166-1 numeric code of the country of the holder.
+    pub country: u32,
+    /// The ledger timestamps until which the claims are valid, by topic.
+    pub claims: Map<u32, u64>,
+}
+
+/// Storage keys for the data associated with the identity registry
+#[contracttype]
+pub enum IdentityRegistryStorageKey {
+    /// Stores the identity of an address
+    Identity(Address),
+    /// Stores the claim topics required to be verified
+    RequiredClaimTopics,
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the identity of `account`, if registered.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address to query the identity of.
+pub fn identity(e: &Env, account: &Address) -> Option<IdentityData> {
+    let key = IdentityRegistryStorageKey::Identity(account.clone());
+    let identity = e.storage().persistent().get::<_, IdentityData>(&key);
+    if identity.is_some() {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
+        );
+    }
+    identity
+}
+
+/// Returns the claim topics required to be verified.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn required_claim_topics(e: &Env) -> Vec<u32> {
+    e.storage()
+        .instance()
+        .get(&IdentityRegistryStorageKey::RequiredClaimTopics)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Returns whether `account` holds a claim for `topic` that is valid at the
+/// current ledger timestamp.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address to check.
+/// * `topic` - The topic of the claim.
+pub fn has_claim(e: &Env, account: &Address, topic: u32) -> bool {
+    identity(e, account).is_some_and(|identity| is_claim_valid(e, &identity, topic))
+}
+
+/// Returns whether `account` is registered and holds a valid claim for every
+/// required claim topic.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address to check.
+pub fn is_verified(e: &Env, account: &Address) -> bool {
+    let Some(identity) = identity(e, account) else {
+        return false;
+    };
+    required_claim_topics(e).iter().all(|topic| is_claim_valid(e, &identity, topic))
+}
+
+// ################## CHANGE STATE ##################
+
+/// Registers `account` with the country of its holder.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address to register.
+/// * `country` - The ISO 3166-1 numeric code of the country.
+///
+/// # Errors
+///
+/// * [`IdentityRegistryError::IdentityAlreadyRegistered`] - When `account` is
+///   already registered.
+///
+/// # Events
+///
+/// * topics - `["identity_registered", account: Address]`
+/// * data - `[country: u32]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used:
+/// - During contract initialization/construction
+/// - In admin functions that implement their own authorization logic
+///
+/// Using this function in public-facing methods creates significant
+/// security risks as it could allow unauthorized registrations.
+pub fn register_identity(e: &Env, account: &Address, country: u32) {
+    if identity(e, account).is_some() {
+        panic_with_error!(e, IdentityRegistryError::IdentityAlreadyRegistered);
+    }
+    set_identity(e, account, &IdentityData { country, claims: Map::new(e) });
+    emit_identity_registered(e, account, country);
+}
+
+/// Updates the country of the holder of `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The registered address.
+/// * `country` - The ISO 3166-1 numeric code of the country.
+///
+/// # Errors
+///
+/// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+///   registered.
+///
+/// # Events
+///
+/// * topics - `["country_updated", account: Address]`
+/// * data - `[country: u32]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used in admin functions that implement their own authorization
+/// logic.
+pub fn update_country(e: &Env, account: &Address, country: u32) {
+    let mut identity = get_identity(e, account);
+    identity.country = country;
+    set_identity(e, account, &identity);
+    emit_country_updated(e, account, country);
+}
+
+/// Removes the identity of `account`, along with its claims.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The registered address.
+///
+/// # Errors
+///
+/// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+///   registered.
+///
+/// # Events
+///
+/// * topics - `["identity_removed", account: Address]`
+/// * data - `[]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used in admin functions that implement their own authorization
+/// logic.
+pub fn remove_identity(e: &Env, account: &Address) {
+    get_identity(e, account);
+    e.storage().persistent().remove(&IdentityRegistryStorageKey::Identity(account.clone()));
+    emit_identity_removed(e, account);
+}
+
+/// Adds a claim for `topic` to the identity of `account`, or replaces the
+/// existing one.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The registered address.
+/// * `topic` - The topic of the claim.
+/// * `valid_until` - The ledger timestamp until which the claim is valid.
+///
+/// # Errors
+///
+/// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+///   registered.
+///
+/// # Events
+///
+/// * topics - `["claim_added", account: Address, topic: u32]`
+/// * data - `[valid_until: u64]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used in admin functions that implement their own authorization
+/// logic.
+pub fn add_claim(e: &Env, account: &Address, topic: u32, valid_until: u64) {
+    let mut identity = get_identity(e, account);
+    identity.claims.set(topic, valid_until);
+    set_identity(e, account, &identity);
+    emit_claim_added(e, account, topic, valid_until);
+}
+
+/// Removes the claim for `topic` from the identity of `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The registered address.
+/// * `topic` - The topic of the claim.
+///
+/// # Errors
+///
+/// * [`IdentityRegistryError::IdentityNotFound`] - When `account` is not
+///   registered.
+/// * [`IdentityRegistryError::ClaimNotFound`] - When the identity has no claim
+///   for `topic`.
+///
+/// # Events
+///
+/// * topics - `["claim_removed", account: Address, topic: u32]`
+/// * data - `[]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used in admin functions that implement their own authorization
+/// logic.
+pub fn remove_claim(e: &Env, account: &Address, topic: u32) {
+    let mut identity = get_identity(e, account);
+    if identity.claims.remove(topic).is_none() {
+        panic_with_error!(e, IdentityRegistryError::ClaimNotFound);
+    }
+    set_identity(e, account, &identity);
+    emit_claim_removed(e, account, topic);
+}
+
+/// Sets the claim topics required to be verified.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `topics` - The required claim topics.
+///
+/// # Events
+///
+/// * topics - `["claim_topics_set"]`
+/// * data - `[topics: Vec<u32>]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should
+/// only be used:
+/// - During contract initialization/construction
+/// - In admin functions that implement their own authorization logic
+pub fn set_required_claim_topics(e: &Env, topics: &Vec<u32>) {
+    e.storage().instance().set(&IdentityRegistryStorageKey::RequiredClaimTopics, topics);
+    emit_claim_topics_set(e, topics);
+}
+
+// ################## HELPERS ##################
+
+fn get_identity(e: &Env, account: &Address) -> IdentityData {
+    let Some(identity) = identity(e, account) else {
+        panic_with_error!(e, IdentityRegistryError::IdentityNotFound);
+    };
+    identity
+}
+
+fn set_identity(e: &Env, account: &Address, identity: &IdentityData) {
+    e.storage().persistent().set(&IdentityRegistryStorageKey::Identity(account.clone()), identity);
+}
+
+fn is_claim_valid(e: &Env, identity: &IdentityData, topic: u32) -> bool {
+    identity.claims.get(topic).is_some_and(|valid_until| e.ledger().timestamp() <= valid_until)
+}
@@ -0,0 +1,142 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::identity_registry::{
+    add_claim, has_claim, identity, is_verified, register_identity, remove_claim, remove_identity,
+    set_required_claim_topics, update_country,
+};
+
+#[contract]
+struct MockContract;
+
+const KYC: u32 = 1;
+const ACCREDITED: u32 = 2;
+
+#[test]
+fn register_and_update_identity() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        assert_eq!(identity(&e, &account).unwrap().country, 250);
+
+        update_country(&e, &account, 276);
+        assert_eq!(identity(&e, &account).unwrap().country, 276);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn verification_requires_every_claim_topic() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let unregistered = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        // no required topics: registration is enough
+        assert!(is_verified(&e, &account));
+        assert!(!is_verified(&e, &unregistered));
+
+        set_required_claim_topics(&e, &vec![&e, KYC, ACCREDITED]);
+        assert!(!is_verified(&e, &account));
+
+        add_claim(&e, &account, KYC, u64::MAX);
+        assert!(!is_verified(&e, &account));
+
+        add_claim(&e, &account, ACCREDITED, u64::MAX);
+        assert!(is_verified(&e, &account));
+
+        remove_claim(&e, &account, ACCREDITED);
+        assert!(!is_verified(&e, &account));
+        assert!(has_claim(&e, &account, KYC));
+    });
+}
+
+#[test]
+fn expired_claims_are_not_valid() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        set_required_claim_topics(&e, &vec![&e, KYC]);
+        add_claim(&e, &account, KYC, 1_000);
+        assert!(is_verified(&e, &account));
+    });
+
+    e.ledger().set_timestamp(1_001);
+
+    e.as_contract(&address, || {
+        assert!(!has_claim(&e, &account, KYC));
+        assert!(!is_verified(&e, &account));
+    });
+}
+
+#[test]
+fn remove_identity_removes_claims() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        add_claim(&e, &account, KYC, u64::MAX);
+        remove_identity(&e, &account);
+        assert_eq!(identity(&e, &account), None);
+        assert!(!has_claim(&e, &account, KYC));
+
+        register_identity(&e, &account, 250);
+        assert!(!has_claim(&e, &account, KYC));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1801)")]
+fn register_twice_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        register_identity(&e, &account, 276);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1800)")]
+fn add_claim_to_unregistered_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        add_claim(&e, &account, KYC, u64::MAX);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1802)")]
+fn remove_missing_claim_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        register_identity(&e, &account, 250);
+        remove_claim(&e, &account, KYC);
+    });
+}
@@ -0,0 +1,33 @@
+//! # Real World Asset (RWA) Token Module.
+//!
+//! Implements a compliance layer for regulated fungible tokens, similar to
+//! ERC-3643 (T-REX), on top of `stellar_fungible`.
+//!
+//! ## Structure
+//!
+//! The module is split into three parts, which are usually deployed as three
+//! separate contracts:
+//!
+//! - [`identity_registry`]: A registry of verified identities, mapping the
+//!   addresses of the holders to their country and claims (e.g. KYC or
+//!   accredited investor). It can be shared by multiple tokens.
+//! - [`compliance`]: A compliance contract bound to a single token, enforcing
+//!   pluggable rules such as country restrictions, a maximum number of holders
+//!   or transfer limits.
+//! - [`token`]: A contract type of `stellar_fungible` that consults both of
+//!   them on every transfer and mint, and notifies the compliance contract of
+//!   every balance change.
+//!
+//! ## Notes
+//!
+//! - **Claims**: Unlike `stellar_fungible::allowlist`, which keeps a flat
+//!   boolean per address, the identity registry verifies an address against a
+//!   configurable set of claim topics, and the claims expire.
+//! - **Authorization**: The setter functions of this module do not enforce
+//!   authorization, which is left to the access control of the contracts, e.g.
+//!   the agents of the issuer managing the identities.
+#![no_std]
+
+pub mod compliance;
+pub mod identity_registry;
+pub mod token;
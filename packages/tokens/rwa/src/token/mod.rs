@@ -0,0 +1,152 @@
+//! # Regulated Token
+//!
+//! The `token` module turns a `stellar_fungible` token into a regulated token:
+//! every transfer and mint is checked against an identity registry
+//! ([`crate::identity_registry`]) and a compliance contract
+//! ([`crate::compliance`]).
+//!
+//! ## Implementation Notes
+//!
+//! - **Checks**: [`Rwa`] is a contract type of `stellar_fungible`, whose
+//!   `check_transfer` and `check_mint` reject receivers that are not verified
+//!   by the identity registry, and operations that are not permitted by the
+//!   compliance contract. As a contract type, it applies to every operation
+//!   going through the checks (e.g. `transfer_from` or `batch_transfer`), and
+//!   can be combined with other contract types with `stellar_fungible::Stack`
+//!   (e.g. `Freezable` or `WhenNotPaused`).
+//! - **Hooks**: The `after_update` hook of [`Rwa`] notifies the compliance
+//!   contract of every transfer, mint and burn, including forced transfers and
+//!   clawbacks, so that its modules stay in sync.
+//! - **Burns**: Burns are not checked, so that tokens can always be redeemed or
+//!   clawed back.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracterror, Address, Env, Symbol};
+use stellar_fungible::FungibleToken;
+pub use storage::{Rwa, RwaStorageKey};
+
+/// Regulated Token Trait
+///
+/// The `FungibleRwa` trait extends the `FungibleToken` trait to manage the
+/// identity registry and the compliance contract of a regulated token. This
+/// trait is designed to be used in conjunction with the `FungibleToken` trait.
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `FungibleRwa` trait, include an
+/// additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// The checks are enforced by [`Rwa`], so `ContractType` must be either
+/// [`Rwa`] or a `stellar_fungible::Stack` containing it.
+pub trait FungibleRwa: FungibleToken {
+    /// Returns the address of the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`RwaError::IdentityRegistryNotSet`] - When no identity registry is
+    ///   set.
+    fn identity_registry(e: &Env) -> Address;
+
+    /// Returns the address of the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`RwaError::ComplianceNotSet`] - When no compliance contract is set.
+    fn compliance(e: &Env) -> Address;
+
+    /// Sets the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `identity_registry` - The address of the identity registry.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["identity_registry_set", identity_registry: Address]`
+    /// * data - `[]`
+    fn set_identity_registry(e: &Env, identity_registry: Address, operator: Address);
+
+    /// Sets the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `compliance` - The address of the compliance contract.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["compliance_set", compliance: Address]`
+    /// * data - `[]`
+    fn set_compliance(e: &Env, compliance: Address, operator: Address);
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RwaError {
+    /// Indicates that no identity registry is set.
+    IdentityRegistryNotSet = 1820,
+    /// Indicates that no compliance contract is set.
+    ComplianceNotSet = 1821,
+    /// Indicates a receiver that is not verified by the identity registry.
+    IdentityNotVerified = 1822,
+    /// Indicates a transfer or mint that is not permitted by the compliance
+    /// contract.
+    NotCompliant = 1823,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when the identity registry is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `identity_registry` - The address of the identity registry.
+///
+/// # Events
+///
+/// * topics - `["identity_registry_set", identity_registry: Address]`
+/// * data - `[]`
+pub fn emit_identity_registry_set(e: &Env, identity_registry: &Address) {
+    let topics = (Symbol::new(e, "identity_registry_set"), identity_registry);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event when the compliance contract is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `compliance` - The address of the compliance contract.
+///
+/// # Events
+///
+/// * topics - `["compliance_set", compliance: Address]`
+/// * data - `[]`
+pub fn emit_compliance_set(e: &Env, compliance: &Address) {
+    let topics = (Symbol::new(e, "compliance_set"), compliance);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,148 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_fungible::ContractOverrides;
+
+use crate::{
+    compliance::ComplianceClient,
+    identity_registry::IdentityRegistryClient,
+    token::{emit_compliance_set, emit_identity_registry_set, RwaError},
+};
+
+pub struct Rwa;
+
+impl ContractOverrides for Rwa {
+    fn check_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        Rwa::check_verified(e, to);
+        if !ComplianceClient::new(e, &Rwa::compliance(e)).can_transfer(from, to, &amount) {
+            panic_with_error!(e, RwaError::NotCompliant);
+        }
+    }
+
+    fn check_mint(e: &Env, to: &Address, amount: i128) {
+        Rwa::check_verified(e, to);
+        if !ComplianceClient::new(e, &Rwa::compliance(e)).can_create(to, &amount) {
+            panic_with_error!(e, RwaError::NotCompliant);
+        }
+    }
+
+    fn after_update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        let compliance = ComplianceClient::new(e, &Rwa::compliance(e));
+        match (from, to) {
+            (Some(from), Some(to)) => compliance.transferred(from, to, &amount),
+            (None, Some(to)) => compliance.created(to, &amount),
+            (Some(from), None) => compliance.destroyed(from, &amount),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Storage keys for the data associated with the regulated token
+#[contracttype]
+pub enum RwaStorageKey {
+    /// Stores the address of the identity registry
+    IdentityRegistry,
+    /// Stores the address of the compliance contract
+    Compliance,
+}
+
+impl Rwa {
+    // ################## QUERY STATE ##################
+
+    /// Returns the address of the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`RwaError::IdentityRegistryNotSet`] - When no identity registry is
+    ///   set.
+    pub fn identity_registry(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&RwaStorageKey::IdentityRegistry)
+            .unwrap_or_else(|| panic_with_error!(e, RwaError::IdentityRegistryNotSet))
+    }
+
+    /// Returns the address of the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`RwaError::ComplianceNotSet`] - When no compliance contract is set.
+    pub fn compliance(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&RwaStorageKey::Compliance)
+            .unwrap_or_else(|| panic_with_error!(e, RwaError::ComplianceNotSet))
+    }
+
+    /// Panics if `account` is not verified by the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check.
+    ///
+    /// # Errors
+    ///
+    /// * [`RwaError::IdentityNotVerified`] - When `account` is not verified.
+    /// * [`RwaError::IdentityRegistryNotSet`] - When no identity registry is
+    ///   set.
+    pub fn check_verified(e: &Env, account: &Address) {
+        if !IdentityRegistryClient::new(e, &Rwa::identity_registry(e)).is_verified(account) {
+            panic_with_error!(e, RwaError::IdentityNotVerified);
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `identity_registry` - The address of the identity registry.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["identity_registry_set", identity_registry: Address]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_identity_registry(e: &Env, identity_registry: &Address) {
+        e.storage().instance().set(&RwaStorageKey::IdentityRegistry, identity_registry);
+        emit_identity_registry_set(e, identity_registry);
+    }
+
+    /// Sets the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `compliance` - The address of the compliance contract.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["compliance_set", compliance: Address]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_compliance(e: &Env, compliance: &Address) {
+        e.storage().instance().set(&RwaStorageKey::Compliance, compliance);
+        emit_compliance_set(e, compliance);
+    }
+}
@@ -0,0 +1,202 @@
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, Vec};
+use stellar_fungible::{Base, ContractOverrides};
+
+use crate::{
+    compliance::{self, Compliance, CountryRestrictions, MaxHolders, Stack},
+    identity_registry,
+    token::Rwa,
+};
+
+#[contract]
+struct MockContract;
+
+#[contract]
+struct MockIdentityRegistry;
+
+#[contractimpl]
+impl MockIdentityRegistry {
+    pub fn set_required_claim_topics(e: &Env, topics: Vec<u32>) {
+        identity_registry::set_required_claim_topics(e, &topics);
+    }
+
+    pub fn register(e: &Env, account: Address, country: u32) {
+        identity_registry::register_identity(e, &account, country);
+        identity_registry::add_claim(e, &account, KYC, u64::MAX);
+    }
+
+    pub fn identity(e: &Env, account: Address) -> Option<identity_registry::IdentityData> {
+        identity_registry::identity(e, &account)
+    }
+
+    pub fn is_verified(e: &Env, account: Address) -> bool {
+        identity_registry::is_verified(e, &account)
+    }
+}
+
+type Modules = Stack<CountryRestrictions, MaxHolders>;
+
+#[contract]
+struct MockCompliance;
+
+#[contractimpl]
+impl MockCompliance {
+    pub fn __constructor(e: &Env, token: Address, identity_registry: Address) {
+        compliance::set_token(e, &token);
+        compliance::set_identity_registry(e, &identity_registry);
+    }
+
+    pub fn set_country_restricted(e: &Env, country: u32, restricted: bool) {
+        CountryRestrictions::set_country_restricted(e, country, restricted);
+    }
+
+    pub fn set_max_holders(e: &Env, max_holders: u32) {
+        MaxHolders::set_max_holders(e, max_holders);
+    }
+
+    pub fn holder_count(e: &Env) -> u32 {
+        MaxHolders::holder_count(e)
+    }
+}
+
+#[contractimpl]
+impl Compliance for MockCompliance {
+    fn token(e: &Env) -> Address {
+        compliance::token(e)
+    }
+
+    fn can_transfer(e: &Env, from: Address, to: Address, amount: i128) -> bool {
+        compliance::can_transfer::<Modules>(e, &from, &to, amount)
+    }
+
+    fn can_create(e: &Env, to: Address, amount: i128) -> bool {
+        compliance::can_create::<Modules>(e, &to, amount)
+    }
+
+    fn transferred(e: &Env, from: Address, to: Address, amount: i128) {
+        compliance::transferred::<Modules>(e, &from, &to, amount);
+    }
+
+    fn created(e: &Env, to: Address, amount: i128) {
+        compliance::created::<Modules>(e, &to, amount);
+    }
+
+    fn destroyed(e: &Env, from: Address, amount: i128) {
+        compliance::destroyed::<Modules>(e, &from, amount);
+    }
+}
+
+const KYC: u32 = 1;
+
+struct Setup<'a> {
+    token: Address,
+    registry: MockIdentityRegistryClient<'a>,
+    compliance: MockComplianceClient<'a>,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    let token = e.register(MockContract, ());
+    let registry = e.register(MockIdentityRegistry, ());
+    let compliance = e.register(MockCompliance, (&token, &registry));
+    e.as_contract(&token, || {
+        Rwa::set_identity_registry(e, &registry);
+        Rwa::set_compliance(e, &compliance);
+    });
+    let registry = MockIdentityRegistryClient::new(e, &registry);
+    registry.set_required_claim_topics(&vec![e, KYC]);
+    Setup { token, registry, compliance: MockComplianceClient::new(e, &compliance) }
+}
+
+#[test]
+fn verified_accounts_can_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let setup = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    setup.registry.register(&alice, &250);
+    setup.registry.register(&bob, &276);
+
+    e.as_contract(&setup.token, || {
+        Rwa::mint(&e, &alice, 100);
+        Rwa::transfer(&e, &alice, &bob, 40);
+        assert_eq!(Base::balance(&e, &alice), 60);
+        assert_eq!(Base::balance(&e, &bob), 40);
+    });
+    assert_eq!(setup.compliance.holder_count(), 2);
+
+    e.as_contract(&setup.token, || {
+        Rwa::burn(&e, &bob, 40);
+    });
+    assert_eq!(setup.compliance.holder_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1822)")]
+fn transfer_to_unverified_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let setup = setup(&e);
+    let alice = Address::generate(&e);
+    let unverified = Address::generate(&e);
+    setup.registry.register(&alice, &250);
+
+    e.as_contract(&setup.token, || {
+        Rwa::mint(&e, &alice, 100);
+        Rwa::transfer(&e, &alice, &unverified, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1823)")]
+fn transfer_to_restricted_country_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let setup = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    setup.registry.register(&alice, &250);
+    setup.registry.register(&bob, &276);
+    setup.compliance.set_country_restricted(&276, &true);
+
+    e.as_contract(&setup.token, || {
+        Rwa::mint(&e, &alice, 100);
+        Rwa::transfer(&e, &alice, &bob, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1823)")]
+fn mint_above_max_holders_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let setup = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    setup.registry.register(&alice, &250);
+    setup.registry.register(&bob, &250);
+    setup.compliance.set_max_holders(&1);
+
+    e.as_contract(&setup.token, || {
+        Rwa::mint(&e, &alice, 100);
+        Rwa::mint(&e, &bob, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1821)")]
+fn transfer_without_compliance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token = e.register(MockContract, ());
+    let registry = e.register(MockIdentityRegistry, ());
+    let registry_client = MockIdentityRegistryClient::new(&e, &registry);
+    let alice = Address::generate(&e);
+    registry_client.register(&alice, &250);
+
+    e.as_contract(&token, || {
+        Rwa::set_identity_registry(&e, &registry);
+        Rwa::mint(&e, &alice, 100);
+    });
+}